@@ -47,13 +47,29 @@ You can use the --ignore-cluster flag if you just want to generate the installat
 
 var (
 	templatesCrdFiles = []string{
+		"templates/gateway/grpcroute.yaml",
+		"templates/gateway/referencegrant.yaml",
+		"templates/gateway/tcproute.yaml",
+		"templates/gateway/tlsroute.yaml",
 		"templates/policy/authorization-policy.yaml",
+		"templates/policy/egress-network.yaml",
+		"templates/policy/http-local-ratelimit-policy.yaml",
 		"templates/policy/httproute.yaml",
 		"templates/policy/meshtls-authentication.yaml",
 		"templates/policy/network-authentication.yaml",
 		"templates/policy/server-authorization.yaml",
 		"templates/policy/server.yaml",
 		"templates/serviceprofile.yaml",
+		"templates/workload/external-workload.yaml",
+	}
+
+	// gatewayAPICRDs are the Gateway API CRDs that the CRD chart installs
+	// when installGatewayAPI is set.
+	gatewayAPICRDs = []string{
+		"grpcroutes.gateway.networking.k8s.io",
+		"referencegrants.gateway.networking.k8s.io",
+		"tcproutes.gateway.networking.k8s.io",
+		"tlsroutes.gateway.networking.k8s.io",
 	}
 
 	templatesControlPlane = []string{
@@ -142,8 +158,8 @@ A full list of configurable values can be found at https://artifacthub.io/packag
 			}
 
 			if crds {
-				// The CRD chart is not configurable.
-				// TODO(ver): Error if values have been configured?
+				// The CRD chart is only configured by installGatewayAPI.
+				// TODO(ver): Error if other values have been configured?
 				if err = installCRDs(cmd.Context(), k8sAPI, os.Stdout, options); err != nil {
 					return err
 				}
@@ -194,9 +210,49 @@ func installCRDs(ctx context.Context, k8sAPI *k8s.KubernetesAPI, w io.Writer, op
 		return err
 	}
 
+	options, err := withGatewayAPIDefault(ctx, k8sAPI, options)
+	if err != nil {
+		return err
+	}
+
 	return renderCRDs(w, options)
 }
 
+// withGatewayAPIDefault disables the installation of the Gateway API CRDs if
+// any of them are already installed by something other than Linkerd, so that
+// they are not replaced. An explicit installGatewayAPI value is respected.
+func withGatewayAPIDefault(ctx context.Context, k8sAPI *k8s.KubernetesAPI, options valuespkg.Options) (valuespkg.Options, error) {
+	if k8sAPI == nil {
+		// When `ignoreCluster` is set, there is no k8sAPI.
+		return options, nil
+	}
+
+	overrides, err := options.MergeValues(nil)
+	if err != nil {
+		return options, err
+	}
+	if _, ok := overrides["installGatewayAPI"]; ok {
+		return options, nil
+	}
+
+	for _, name := range gatewayAPICRDs {
+		crd, err := k8sAPI.Apiextensions.ApiextensionsV1().CustomResourceDefinitions().Get(ctx, name, metav1.GetOptions{})
+		if kerrors.IsNotFound(err) {
+			continue
+		}
+		if err != nil {
+			return options, err
+		}
+		if _, ok := crd.Labels[k8s.ControllerNSLabel]; !ok {
+			fmt.Fprintf(os.Stderr, "The Gateway API CRDs are not installed because %s is not managed by Linkerd. Set installGatewayAPI=true to replace them.\n", name)
+			options.Values = append(append([]string{}, options.Values...), "installGatewayAPI=false")
+			return options, nil
+		}
+	}
+
+	return options, nil
+}
+
 func installControlPlane(ctx context.Context, k8sAPI *k8s.KubernetesAPI, w io.Writer, values *l5dcharts.Values, flags []flag.Flag, options valuespkg.Options) error {
 	err := flag.ApplySetFlags(values, flags)
 	if err != nil {
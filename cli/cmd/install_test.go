@@ -6,10 +6,13 @@ import (
 	"fmt"
 	"os"
 	"path/filepath"
+	"reflect"
+	"strings"
 	"testing"
 
 	"github.com/linkerd/linkerd2/cli/flag"
 	charts "github.com/linkerd/linkerd2/pkg/charts/linkerd2"
+	"github.com/linkerd/linkerd2/pkg/k8s"
 	"github.com/linkerd/linkerd2/pkg/tls"
 	"helm.sh/helm/v3/pkg/cli/values"
 	corev1 "k8s.io/api/core/v1"
@@ -289,6 +292,77 @@ func TestRenderCRDs(t *testing.T) {
 	}
 }
 
+func TestRenderCRDsWithoutGatewayAPI(t *testing.T) {
+	var buf bytes.Buffer
+	if err := renderCRDs(&buf, values.Options{Values: []string{"installGatewayAPI=false"}}); err != nil {
+		t.Fatalf("Failed to render templates: %v", err)
+	}
+	for _, name := range gatewayAPICRDs {
+		if strings.Contains(buf.String(), name) {
+			t.Errorf("Expected %s not to be rendered", name)
+		}
+	}
+	if !strings.Contains(buf.String(), "servers.policy.linkerd.io") {
+		t.Error("Expected Linkerd's CRDs to be rendered")
+	}
+}
+
+func TestWithGatewayAPIDefault(t *testing.T) {
+	crd := func(labels string) string {
+		return fmt.Sprintf(`
+apiVersion: apiextensions.k8s.io/v1
+kind: CustomResourceDefinition
+metadata:
+  name: grpcroutes.gateway.networking.k8s.io
+  labels:%s`, labels)
+	}
+
+	testCases := []struct {
+		name      string
+		manifests []string
+		options   values.Options
+		expected  []string
+	}{
+		{
+			name:     "installs the Gateway API CRDs if they do not exist",
+			expected: nil,
+		},
+		{
+			name:      "upgrades Gateway API CRDs managed by Linkerd",
+			manifests: []string{crd("\n    linkerd.io/control-plane-ns: linkerd")},
+			expected:  nil,
+		},
+		{
+			name:      "does not replace Gateway API CRDs managed by others",
+			manifests: []string{crd(" {}")},
+			expected:  []string{"installGatewayAPI=false"},
+		},
+		{
+			name:      "respects an explicit installGatewayAPI value",
+			manifests: []string{crd(" {}")},
+			options:   values.Options{Values: []string{"installGatewayAPI=true"}},
+			expected:  []string{"installGatewayAPI=true"},
+		},
+	}
+
+	for _, tc := range testCases {
+		tc := tc // pin
+		t.Run(tc.name, func(t *testing.T) {
+			k8sAPI, err := k8s.NewFakeAPI(tc.manifests...)
+			if err != nil {
+				t.Fatal(err)
+			}
+			options, err := withGatewayAPIDefault(context.Background(), k8sAPI, tc.options)
+			if err != nil {
+				t.Fatal(err)
+			}
+			if !reflect.DeepEqual(options.Values, tc.expected) {
+				t.Errorf("Expected values %v, got %v", tc.expected, options.Values)
+			}
+		})
+	}
+}
+
 func TestValidateAndBuild_Errors(t *testing.T) {
 	t.Run("Fails validation for invalid ignoreInboundPorts", func(t *testing.T) {
 		values, err := testInstallOptions()
@@ -591,7 +665,7 @@ func TestValidate(t *testing.T) {
 			t.Fatalf("Unexpected error: %v\n", err)
 		}
 		values.Proxy.DefaultInboundPolicy = "everybody"
-		expected := "--default-inbound-policy must be one of: all-authenticated, all-unauthenticated, cluster-authenticated, cluster-unauthenticated, deny (got everybody)"
+		expected := "--default-inbound-policy must be one of: all-authenticated, all-unauthenticated, cluster-authenticated, cluster-unauthenticated, deny, audit (got everybody)"
 
 		err = validateValues(context.Background(), nil, values)
 		if err == nil {
@@ -601,6 +675,18 @@ func TestValidate(t *testing.T) {
 			t.Fatalf("Expected error string \"%s\", got \"%s\"", expected, err)
 		}
 	})
+
+	t.Run("Accepts audit default-inbound-policy", func(t *testing.T) {
+		values, err := testInstallOptions()
+		if err != nil {
+			t.Fatalf("Unexpected error: %v\n", err)
+		}
+		values.Proxy.DefaultInboundPolicy = "audit"
+
+		if err := validatePolicy(values.Proxy.DefaultInboundPolicy); err != nil {
+			t.Fatalf("Expected no error but got \"%s\"", err)
+		}
+	})
 }
 
 func fakeHeartbeatSchedule() string {
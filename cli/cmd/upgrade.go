@@ -88,8 +88,17 @@ A full list of configurable values can be found at https://www.github.com/linker
   linkerd prune | kubectl delete -f -`,
 		RunE: func(cmd *cobra.Command, args []string) error {
 			if crds {
-				// The CRD chart is not configurable.
-				// TODO(ver): Error if values have been configured?
+				// The CRD chart is only configured by installGatewayAPI.
+				// TODO(ver): Error if other values have been configured?
+				k, err := k8s.NewAPI(kubeconfigPath, kubeContext, impersonate, impersonateGroup, 0)
+				if err != nil {
+					return fmt.Errorf("failed to create a kubernetes client: %w", err)
+				}
+				options, err := withGatewayAPIDefault(cmd.Context(), k, options)
+				if err != nil {
+					fmt.Fprintln(os.Stderr, err.Error())
+					os.Exit(1)
+				}
 				if _, err := upgradeCRDs(options).WriteTo(os.Stdout); err != nil {
 					fmt.Fprintln(os.Stderr, err.Error())
 					os.Exit(1)
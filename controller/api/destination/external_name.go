@@ -0,0 +1,124 @@
+package destination
+
+import (
+	"context"
+	"errors"
+	"net"
+	"time"
+
+	"github.com/linkerd/linkerd2/controller/api/destination/watcher"
+	logging "github.com/sirupsen/logrus"
+)
+
+// externalNameRefreshInterval is how often the DNS name aliased by an
+// ExternalName Service is resolved.
+const externalNameRefreshInterval = 30 * time.Second
+
+type (
+	// externalNameResolver publishes the addresses of the DNS name aliased by
+	// an ExternalName Service. These Services have no endpoints, so the name
+	// is resolved periodically and changes are published to the listener.
+	externalNameResolver struct {
+		service  watcher.ServiceID
+		host     string
+		port     watcher.Port
+		lookup   lookupIPAddrFunc
+		listener watcher.EndpointUpdateListener
+		log      *logging.Entry
+
+		resolved  bool
+		addresses map[watcher.ID]watcher.Address
+	}
+
+	lookupIPAddrFunc = func(ctx context.Context, host string) ([]net.IPAddr, error)
+)
+
+func newExternalNameResolver(
+	service watcher.ServiceID,
+	host string,
+	port watcher.Port,
+	lookup lookupIPAddrFunc,
+	listener watcher.EndpointUpdateListener,
+	log *logging.Entry,
+) *externalNameResolver {
+	return &externalNameResolver{
+		service:   service,
+		host:      host,
+		port:      port,
+		lookup:    lookup,
+		listener:  listener,
+		log:       log.WithField("external-name", host),
+		addresses: map[watcher.ID]watcher.Address{},
+	}
+}
+
+// run resolves the name every interval until the context is canceled.
+func (r *externalNameResolver) run(ctx context.Context, interval time.Duration) {
+	ticker := time.NewTicker(interval)
+	defer ticker.Stop()
+	for {
+		r.resolve(ctx)
+		select {
+		case <-ctx.Done():
+			return
+		case <-ticker.C:
+		}
+	}
+}
+
+// resolve looks up the name once and publishes any changes to its addresses.
+func (r *externalNameResolver) resolve(ctx context.Context) {
+	ips, err := r.lookup(ctx, r.host)
+	if err != nil {
+		var dnsErr *net.DNSError
+		if !errors.As(err, &dnsErr) || !dnsErr.IsNotFound {
+			// Keep the last known addresses if the lookup fails transiently.
+			if ctx.Err() == nil {
+				r.log.Warnf("Failed to resolve %s: %s", r.host, err)
+			}
+			return
+		}
+		ips = nil
+	}
+
+	current := map[watcher.ID]watcher.Address{}
+	for _, ip := range ips {
+		// The destination API only carries IPv4 addresses.
+		if ip.IP.To4() == nil {
+			continue
+		}
+		id := watcher.ID{Namespace: r.service.Namespace, Name: ip.IP.String()}
+		current[id] = watcher.Address{IP: ip.IP.String(), Port: r.port}
+	}
+
+	add := map[watcher.ID]watcher.Address{}
+	for id, address := range current {
+		if _, ok := r.addresses[id]; !ok {
+			add[id] = address
+		}
+	}
+	remove := map[watcher.ID]watcher.Address{}
+	for id, address := range r.addresses {
+		if _, ok := current[id]; !ok {
+			remove[id] = address
+		}
+	}
+	first := !r.resolved
+	r.resolved = true
+	r.addresses = current
+
+	if len(current) == 0 {
+		if first || len(remove) > 0 {
+			r.listener.NoEndpoints(true)
+		}
+		return
+	}
+
+	labels := map[string]string{"service": r.service.Name, "namespace": r.service.Namespace}
+	if len(add) > 0 {
+		r.listener.Add(watcher.AddressSet{Addresses: add, Labels: labels})
+	}
+	if len(remove) > 0 {
+		r.listener.Remove(watcher.AddressSet{Addresses: remove, Labels: labels})
+	}
+}
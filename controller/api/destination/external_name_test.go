@@ -0,0 +1,111 @@
+package destination
+
+import (
+	"context"
+	"errors"
+	"net"
+	"sort"
+	"testing"
+
+	"github.com/linkerd/linkerd2/controller/api/destination/watcher"
+	logging "github.com/sirupsen/logrus"
+)
+
+type bufferingExternalNameListener struct {
+	added       []string
+	removed     []string
+	noEndpoints int
+}
+
+func (l *bufferingExternalNameListener) Add(set watcher.AddressSet) {
+	for _, address := range set.Addresses {
+		l.added = append(l.added, address.IP)
+	}
+	sort.Strings(l.added)
+}
+
+func (l *bufferingExternalNameListener) Remove(set watcher.AddressSet) {
+	for _, address := range set.Addresses {
+		l.removed = append(l.removed, address.IP)
+	}
+	sort.Strings(l.removed)
+}
+
+func (l *bufferingExternalNameListener) NoEndpoints(_ bool) {
+	l.noEndpoints++
+}
+
+func lookupIPs(ips *[]string, err *error) lookupIPAddrFunc {
+	return func(_ context.Context, _ string) ([]net.IPAddr, error) {
+		if *err != nil {
+			return nil, *err
+		}
+		addrs := []net.IPAddr{}
+		for _, ip := range *ips {
+			addrs = append(addrs, net.IPAddr{IP: net.ParseIP(ip)})
+		}
+		return addrs, nil
+	}
+}
+
+func TestExternalNameResolver(t *testing.T) {
+	service := watcher.ServiceID{Namespace: "ns", Name: "external"}
+	log := logging.WithField("test", t.Name())
+
+	t.Run("Publishes resolved addresses", func(t *testing.T) {
+		ips := []string{"192.168.1.20", "192.168.1.21", "fd00::1"}
+		var lookupErr error
+		listener := &bufferingExternalNameListener{}
+		resolver := newExternalNameResolver(service, "example.com", 443, lookupIPs(&ips, &lookupErr), listener, log)
+
+		resolver.resolve(context.Background())
+		if len(listener.added) != 2 || listener.added[0] != "192.168.1.20" || listener.added[1] != "192.168.1.21" {
+			t.Fatalf("Expected IPv4 addresses to be added, got %v", listener.added)
+		}
+
+		ips = []string{"192.168.1.21", "192.168.1.22"}
+		resolver.resolve(context.Background())
+		if len(listener.added) != 3 || listener.added[2] != "192.168.1.22" {
+			t.Fatalf("Expected 192.168.1.22 to be added, got %v", listener.added)
+		}
+		if len(listener.removed) != 1 || listener.removed[0] != "192.168.1.20" {
+			t.Fatalf("Expected 192.168.1.20 to be removed, got %v", listener.removed)
+		}
+		if listener.noEndpoints != 0 {
+			t.Fatalf("Expected no NoEndpoints updates, got %d", listener.noEndpoints)
+		}
+	})
+
+	t.Run("Keeps addresses when a lookup fails", func(t *testing.T) {
+		ips := []string{"192.168.1.20"}
+		var lookupErr error
+		listener := &bufferingExternalNameListener{}
+		resolver := newExternalNameResolver(service, "example.com", 443, lookupIPs(&ips, &lookupErr), listener, log)
+
+		resolver.resolve(context.Background())
+		lookupErr = errors.New("timed out")
+		resolver.resolve(context.Background())
+		if len(listener.removed) != 0 || listener.noEndpoints != 0 {
+			t.Fatalf("Expected addresses to be kept, got removed=%v noEndpoints=%d", listener.removed, listener.noEndpoints)
+		}
+	})
+
+	t.Run("Sends NoEndpoints when the name does not exist", func(t *testing.T) {
+		ips := []string{"192.168.1.20"}
+		var lookupErr error
+		listener := &bufferingExternalNameListener{}
+		resolver := newExternalNameResolver(service, "example.com", 443, lookupIPs(&ips, &lookupErr), listener, log)
+
+		resolver.resolve(context.Background())
+		lookupErr = &net.DNSError{Err: "no such host", Name: "example.com", IsNotFound: true}
+		resolver.resolve(context.Background())
+		if listener.noEndpoints != 1 {
+			t.Fatalf("Expected 1 NoEndpoints update, got %d", listener.noEndpoints)
+		}
+
+		resolver.resolve(context.Background())
+		if listener.noEndpoints != 1 {
+			t.Fatalf("Expected NoEndpoints to be sent once, got %d", listener.noEndpoints)
+		}
+	})
+}
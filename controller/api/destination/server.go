@@ -165,6 +165,27 @@ func (s *server) Get(dest *pb.GetDestination, stream pb.Destination_GetServer) e
 		return status.Errorf(codes.InvalidArgument, "Invalid authority: %s", dest.GetPath())
 	}
 
+	// ExternalName Services have no endpoints, so the name they alias is
+	// resolved instead.
+	if instanceID == "" {
+		svc, err := s.k8sAPI.Svc().Lister().Services(service.Namespace).Get(service.Name)
+		if err == nil && svc.Spec.Type == corev1.ServiceTypeExternalName {
+			log.Debugf("Resolving ExternalName %s for %s", svc.Spec.ExternalName, dest.GetPath())
+			ctx, cancel := context.WithCancel(stream.Context())
+			defer cancel()
+			go func() {
+				select {
+				case <-s.shutdown:
+					cancel()
+				case <-ctx.Done():
+				}
+			}()
+			resolver := newExternalNameResolver(service, svc.Spec.ExternalName, port, net.DefaultResolver.LookupIPAddr, translator, log)
+			resolver.run(ctx, externalNameRefreshInterval)
+			return nil
+		}
+	}
+
 	err = s.endpoints.Subscribe(service, port, instanceID, translator)
 	if err != nil {
 		var ise watcher.InvalidService
@@ -1072,24 +1072,7 @@ func (pp *portPublisher) unsubscribe(listener EndpointUpdateListener) {
 func (pp *portPublisher) updateServer(server *v1beta1.Server, selector labels.Selector, isAdd bool) {
 	for id, address := range pp.addresses.Addresses {
 		if address.Pod != nil && selector.Matches(labels.Set(address.Pod.Labels)) {
-			var portMatch bool
-			switch server.Spec.Port.Type {
-			case intstr.Int:
-				if server.Spec.Port.IntVal == int32(address.Port) {
-					portMatch = true
-				}
-			case intstr.String:
-				for _, c := range address.Pod.Spec.Containers {
-					for _, p := range c.Ports {
-						if p.ContainerPort == int32(address.Port) && p.Name == server.Spec.Port.StrVal {
-							portMatch = true
-						}
-					}
-				}
-			default:
-				continue
-			}
-			if portMatch {
+			if serverSelectsPort(server, address.Pod, address.Port) {
 				if isAdd && server.Spec.ProxyProtocol == opaqueProtocol {
 					address.OpaqueProtocol = true
 				} else {
@@ -1260,24 +1243,7 @@ func SetToServerProtocol(k8sAPI *k8s.API, address *Address, port Port) error {
 			return fmt.Errorf("failed to create Selector: %w", err)
 		}
 		if server.Spec.ProxyProtocol == opaqueProtocol && selector.Matches(labels.Set(address.Pod.Labels)) {
-			var portMatch bool
-			switch server.Spec.Port.Type {
-			case intstr.Int:
-				if server.Spec.Port.IntVal == int32(port) {
-					portMatch = true
-				}
-			case intstr.String:
-				for _, c := range address.Pod.Spec.Containers {
-					for _, p := range c.Ports {
-						if p.ContainerPort == int32(port) && p.Name == server.Spec.Port.StrVal {
-							portMatch = true
-						}
-					}
-				}
-			default:
-				continue
-			}
-			if portMatch {
+			if serverSelectsPort(server, address.Pod, port) {
 				address.OpaqueProtocol = true
 				return nil
 			}
@@ -4,9 +4,11 @@ import (
 	"strconv"
 	"strings"
 	"sync"
+	"unicode"
 
 	"github.com/linkerd/linkerd2/controller/gen/apis/server/v1beta1"
 	"github.com/linkerd/linkerd2/controller/k8s"
+	"github.com/linkerd/linkerd2/pkg/util"
 	"github.com/prometheus/client_golang/prometheus"
 	"github.com/prometheus/client_golang/prometheus/promauto"
 	logging "github.com/sirupsen/logrus"
@@ -161,24 +163,7 @@ func (sw *ServerWatcher) updateServer(server *v1beta1.Server, selector labels.Se
 	defer sw.Unlock()
 	for pp, ppp := range sw.subscriptions {
 		if selector.Matches(labels.Set(ppp.pod.Labels)) {
-			var portMatch bool
-			switch server.Spec.Port.Type {
-			case intstr.Int:
-				if server.Spec.Port.IntVal == int32(pp.port) {
-					portMatch = true
-				}
-			case intstr.String:
-				for _, c := range ppp.pod.Spec.Containers {
-					for _, p := range c.Ports {
-						if p.ContainerPort == int32(pp.port) && p.Name == server.Spec.Port.StrVal {
-							portMatch = true
-						}
-					}
-				}
-			default:
-				continue
-			}
-			if portMatch {
+			if serverSelectsPort(server, ppp.pod, pp.port) {
 				var isOpaque bool
 				if isAdd && server.Spec.ProxyProtocol == opaqueProtocol {
 					isOpaque = true
@@ -193,6 +178,50 @@ func (sw *ServerWatcher) updateServer(server *v1beta1.Server, selector labels.Se
 	}
 }
 
+// serverSelectsPort returns true if the Server's port refers to the given
+// port of the pod. The Server's port may be a port number, the name of a
+// container port, or a set of port numbers and ranges such as
+// "8080,9000-9100". Port names must contain a letter, so strings without
+// letters are parsed as port sets.
+func serverSelectsPort(server *v1beta1.Server, pod *corev1.Pod, port Port) bool {
+	switch server.Spec.Port.Type {
+	case intstr.Int:
+		return server.Spec.Port.IntVal == int32(port)
+	case intstr.String:
+		if strings.IndexFunc(server.Spec.Port.StrVal, unicode.IsLetter) < 0 {
+			return portSetContains(server.Spec.Port.StrVal, port)
+		}
+		for _, c := range pod.Spec.Containers {
+			for _, p := range c.Ports {
+				if p.ContainerPort == int32(port) && p.Name == server.Spec.Port.StrVal {
+					return true
+				}
+			}
+		}
+	}
+	return false
+}
+
+// portSetContains returns true if the comma-separated set of ports and port
+// ranges contains the given port. Like the policy controller, an invalid set
+// contains no ports.
+func portSetContains(set string, port Port) bool {
+	contains := false
+	for _, pr := range util.GetPortRanges(set) {
+		if pr == "" {
+			continue
+		}
+		portRange, err := util.ParsePortRange(strings.ReplaceAll(pr, " ", ""))
+		if err != nil || portRange.LowerBound == 0 {
+			return false
+		}
+		if portRange.LowerBound <= int(port) && int(port) <= portRange.UpperBound {
+			contains = true
+		}
+	}
+	return contains
+}
+
 func serverMetricLabels(pod *corev1.Pod, port Port) prometheus.Labels {
 	podName, _, _ := strings.Cut(pod.Name, "-")
 	return prometheus.Labels{
@@ -0,0 +1,71 @@
+package watcher
+
+import (
+	"testing"
+
+	"github.com/linkerd/linkerd2/controller/gen/apis/server/v1beta1"
+	corev1 "k8s.io/api/core/v1"
+	"k8s.io/apimachinery/pkg/util/intstr"
+)
+
+func TestServerSelectsPort(t *testing.T) {
+	pod := &corev1.Pod{
+		Spec: corev1.PodSpec{
+			Containers: []corev1.Container{
+				{
+					Ports: []corev1.ContainerPort{
+						{Name: "http", ContainerPort: 8080},
+					},
+				},
+			},
+		},
+	}
+
+	testCases := []struct {
+		name     string
+		port     intstr.IntOrString
+		selected []Port
+		ignored  []Port
+	}{
+		{
+			name:     "port number",
+			port:     intstr.FromInt(8080),
+			selected: []Port{8080},
+			ignored:  []Port{8081},
+		},
+		{
+			name:     "port name",
+			port:     intstr.FromString("http"),
+			selected: []Port{8080},
+			ignored:  []Port{8081},
+		},
+		{
+			name:     "port set",
+			port:     intstr.FromString("8080, 9000-9100"),
+			selected: []Port{8080, 9000, 9050, 9100},
+			ignored:  []Port{8081, 8999, 9101},
+		},
+		{
+			name:    "invalid port set",
+			port:    intstr.FromString("8080,9100-9000"),
+			ignored: []Port{8080, 9000},
+		},
+	}
+
+	for _, tc := range testCases {
+		tc := tc // pin
+		t.Run(tc.name, func(t *testing.T) {
+			server := &v1beta1.Server{Spec: v1beta1.ServerSpec{Port: tc.port}}
+			for _, port := range tc.selected {
+				if !serverSelectsPort(server, pod, port) {
+					t.Errorf("Expected port %d to be selected", port)
+				}
+			}
+			for _, port := range tc.ignored {
+				if serverSelectsPort(server, pod, port) {
+					t.Errorf("Expected port %d not to be selected", port)
+				}
+			}
+		})
+	}
+}
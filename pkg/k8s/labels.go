@@ -283,6 +283,10 @@ const (
 	// Deny denies all connections.
 	Deny = "deny"
 
+	// Audit allows all connections, but the proxy flags those that would be
+	// denied by a deny policy.
+	Audit = "audit"
+
 	// ProxyShutdownGracePeriodAnnotation configures the grace period for
 	// graceful shutdowns in the proxy.
 	ProxyShutdownGracePeriodAnnotation = ProxyConfigAnnotationsPrefix + "/shutdown-grace-period"
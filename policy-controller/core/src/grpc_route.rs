@@ -0,0 +1,15 @@
+pub use crate::http_route::{HeaderMatch, HostMatch};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct GrpcRouteMatch {
+    pub method: Option<GrpcMethodMatch>,
+    pub headers: Vec<HeaderMatch>,
+}
+
+/// Matches a gRPC request by its service and/or method name. An unset field
+/// matches any value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GrpcMethodMatch {
+    pub service: Option<String>,
+    pub method: Option<String>,
+}
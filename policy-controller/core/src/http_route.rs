@@ -5,7 +5,7 @@ pub use http::{
     Method, StatusCode,
 };
 use regex::Regex;
-use std::num::NonZeroU16;
+use std::{num::NonZeroU16, time};
 
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum HostMatch {
@@ -14,7 +14,7 @@ pub enum HostMatch {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
-pub struct RequestHeaderModifierFilter {
+pub struct HeaderModifierFilter {
     pub add: Vec<(HeaderName, HeaderValue)>,
     pub set: Vec<(HeaderName, HeaderValue)>,
     pub remove: Vec<HeaderName>,
@@ -48,6 +48,16 @@ pub struct Ratio {
     pub denominator: u32,
 }
 
+/// Bounds the time spent handling a request that matches a route rule.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RouteTimeouts {
+    /// Limits the total time spent handling a request, including retries.
+    pub request: Option<time::Duration>,
+
+    /// Limits the time spent on each individual request to a backend.
+    pub backend_request: Option<time::Duration>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct HttpRouteMatch {
     pub path: Option<PathMatch>,
@@ -1,6 +1,7 @@
 use crate::{
+    grpc_route::GrpcRouteMatch,
     http_route::{
-        FailureInjectorFilter, HostMatch, HttpRouteMatch, PathMatch, RequestHeaderModifierFilter,
+        FailureInjectorFilter, HeaderModifierFilter, HostMatch, HttpRouteMatch, PathMatch,
         RequestRedirectFilter,
     },
     identity_match::IdentityMatch,
@@ -12,6 +13,14 @@ use chrono::{offset::Utc, DateTime};
 use futures::prelude::*;
 use std::{pin::Pin, time::Duration};
 
+/// Identifies a workload for which inbound policy is discovered.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WorkloadRef {
+    Pod(String),
+    /// A meshed workload that runs outside of the cluster.
+    External(String),
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum ServerRef {
     Default(&'static str),
@@ -31,6 +40,12 @@ pub enum HttpRouteRef {
     Linkerd(String),
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum GrpcRouteRef {
+    Default(&'static str),
+    Gateway(String),
+}
+
 /// Describes how a proxy should handle inbound connections.
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum ProxyProtocol {
@@ -58,6 +73,20 @@ pub struct ClientAuthorization {
 
     /// Describes the client's authentication requirements.
     pub authentication: ClientAuthentication,
+
+    /// Determines whether matching clients are allowed or only audited.
+    pub action: AuthorizationAction,
+}
+
+/// Determines how a proxy handles clients that match an authorization.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum AuthorizationAction {
+    #[default]
+    Allow,
+
+    /// Matching clients are allowed, but the proxy flags their requests as
+    /// ones that would otherwise have been denied.
+    Audit,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -89,7 +118,36 @@ pub struct InboundServer {
 
     pub protocol: ProxyProtocol,
     pub authorizations: HashMap<AuthorizationRef, ClientAuthorization>,
+    pub ratelimit: Option<RateLimit>,
     pub http_routes: HashMap<HttpRouteRef, HttpRoute>,
+    pub grpc_routes: HashMap<GrpcRouteRef, GrpcRoute>,
+}
+
+/// Describes local rate limits enforced by a proxy on HTTP requests.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RateLimit {
+    /// The name of the `HTTPLocalRateLimitPolicy` that configures this limit.
+    pub name: String,
+
+    /// Limits the overall rate of requests.
+    pub total: Option<Limit>,
+
+    /// Limits the rate of requests for each distinct client identity.
+    pub identity: Option<Limit>,
+
+    /// Overrides the per-identity limit for specific clients.
+    pub overrides: Vec<RateLimitOverride>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Limit {
+    pub requests_per_second: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RateLimitOverride {
+    pub requests_per_second: u32,
+    pub client_identities: Vec<String>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -109,9 +167,27 @@ pub struct HttpRouteRule {
     pub filters: Vec<Filter>,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GrpcRoute {
+    pub hostnames: Vec<HostMatch>,
+    pub rules: Vec<GrpcRouteRule>,
+    pub authorizations: HashMap<AuthorizationRef, ClientAuthorization>,
+
+    /// This is required for ordering returned `GrpcRoute`s by their creation
+    /// timestamp.
+    pub creation_timestamp: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GrpcRouteRule {
+    pub matches: Vec<GrpcRouteMatch>,
+    pub filters: Vec<Filter>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Filter {
-    RequestHeaderModifier(RequestHeaderModifierFilter),
+    RequestHeaderModifier(HeaderModifierFilter),
+    ResponseHeaderModifier(HeaderModifierFilter),
     RequestRedirect(RequestRedirectFilter),
     FailureInjector(FailureInjectorFilter),
 }
@@ -142,6 +218,27 @@ impl Default for HttpRoute {
     }
 }
 
+// === impl GrpcRoute ===
+
+/// The default `GrpcRoute` used for any `InboundServer` that does not have
+/// gRPC routes.
+impl Default for GrpcRoute {
+    fn default() -> Self {
+        Self {
+            hostnames: vec![],
+            rules: vec![GrpcRouteRule {
+                matches: vec![GrpcRouteMatch::default()],
+                filters: vec![],
+            }],
+            // Default routes do not have authorizations; the default policy's
+            // authzs will be configured by the default `InboundServer`, not by
+            // the route.
+            authorizations: HashMap::new(),
+            creation_timestamp: None,
+        }
+    }
+}
+
 // === impl InboundHttpRouteRef ===
 
 impl Ord for HttpRouteRef {
@@ -162,3 +259,24 @@ impl PartialOrd for HttpRouteRef {
         Some(self.cmp(other))
     }
 }
+
+// === impl GrpcRouteRef ===
+
+impl Ord for GrpcRouteRef {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (Self::Default(a), Self::Default(b)) => a.cmp(b),
+            (Self::Gateway(a), Self::Gateway(b)) => a.cmp(b),
+            // Route resources are always preferred over default resources, so they should sort
+            // first in a list.
+            (Self::Gateway(_), Self::Default(_)) => std::cmp::Ordering::Less,
+            (Self::Default(_), Self::Gateway(_)) => std::cmp::Ordering::Greater,
+        }
+    }
+}
+
+impl PartialOrd for GrpcRouteRef {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
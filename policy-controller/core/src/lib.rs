@@ -1,6 +1,7 @@
 #![deny(warnings, rust_2018_idioms)]
 #![forbid(unsafe_code)]
 
+pub mod grpc_route;
 pub mod http_route;
 mod identity_match;
 pub mod inbound;
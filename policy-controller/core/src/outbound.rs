@@ -1,9 +1,20 @@
-use crate::http_route::{HostMatch, HttpRouteMatch};
+use crate::{
+    grpc_route::GrpcRouteMatch,
+    http_route::{
+        FailureInjectorFilter, HeaderModifierFilter, HostMatch, HttpRouteMatch, RouteTimeouts,
+    },
+};
 use ahash::AHashMap as HashMap;
 use anyhow::Result;
 use chrono::{offset::Utc, DateTime};
 use futures::prelude::*;
-use std::{net::IpAddr, num::NonZeroU16, pin::Pin, time};
+use std::{
+    net::{IpAddr, SocketAddr},
+    num::NonZeroU16,
+    ops::RangeInclusive,
+    pin::Pin,
+    time,
+};
 
 /// Models outbound policy discovery.
 #[async_trait::async_trait]
@@ -12,23 +23,105 @@ pub trait DiscoverOutboundPolicy<T> {
 
     async fn watch_outbound_policy(&self, target: T) -> Result<Option<OutboundPolicyStream>>;
 
-    fn lookup_ip(&self, addr: IpAddr, port: NonZeroU16) -> Option<T>;
+    /// Finds the target for an IP address, which may be a Service's cluster
+    /// IP or one of its endpoints. Egress networks are only considered when
+    /// the client's namespace is known.
+    fn lookup_ip(
+        &self,
+        addr: IpAddr,
+        port: NonZeroU16,
+        source_namespace: Option<&str>,
+    ) -> Option<T>;
+
+    /// Finds the target for a DNS name outside of the cluster. ExternalName
+    /// Services that alias the name take precedence over egress networks,
+    /// which are only considered when the client's namespace is known.
+    fn lookup_hostname(
+        &self,
+        host: &str,
+        port: NonZeroU16,
+        source_namespace: Option<&str>,
+    ) -> Option<T>;
 }
 
 pub type OutboundPolicyStream = Pin<Box<dyn Stream<Item = OutboundPolicy> + Send + Sync + 'static>>;
 
+/// Identifies the resource that configures an outbound target.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum OutboundDiscoverTarget {
+    Service {
+        namespace: String,
+        name: String,
+        port: NonZeroU16,
+    },
+    /// An endpoint of a Service, e.g. a pod of a headless Service, that the
+    /// client addresses directly. The Service's policy applies, but traffic
+    /// is forwarded to the endpoint.
+    Endpoint {
+        namespace: String,
+        name: String,
+        /// The Service port that the endpoint serves.
+        port: NonZeroU16,
+        addr: SocketAddr,
+    },
+    Egress {
+        namespace: String,
+        name: String,
+        port: NonZeroU16,
+
+        /// The destination that the client is connecting to.
+        original_dst: EgressDst,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EgressDst {
+    Addr(SocketAddr),
+    Authority(String),
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct OutboundPolicy {
     pub http_routes: HashMap<String, HttpRoute>,
+    pub grpc_routes: HashMap<String, GrpcRoute>,
+    pub tcp_routes: HashMap<String, TcpRoute>,
+    pub tls_routes: HashMap<String, TlsRoute>,
     pub authority: String,
     pub name: String,
     pub namespace: String,
     pub port: NonZeroU16,
     pub opaque: bool,
     pub accrual: Option<FailureAccrual>,
+
+    /// Limits the load that each client may place on the service.
+    pub circuit_breaker: Option<CircuitBreaker>,
+
+    /// The retry policy applied to routes that do not configure their own.
+    pub retry: Option<RetryPolicy>,
+
+    /// The kind of resource to which this policy applies.
+    pub parent: ParentKind,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum ParentKind {
+    #[default]
+    Service,
+
+    /// An `EgressNetwork`, describing destinations outside of the cluster.
+    Egress { traffic_policy: TrafficPolicy },
+}
+
+/// Determines how traffic to an egress network that is not matched by any
+/// route is handled.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum TrafficPolicy {
+    #[default]
+    Allow,
+    Deny,
+}
+
+#[derive(Clone, Debug, PartialEq)]
 pub struct HttpRoute {
     pub hostnames: Vec<HostMatch>,
     pub rules: Vec<HttpRouteRule>,
@@ -38,10 +131,65 @@ pub struct HttpRoute {
     pub creation_timestamp: Option<DateTime<Utc>>,
 }
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct HttpRouteRule {
     pub matches: Vec<HttpRouteMatch>,
     pub backends: Vec<Backend>,
+    pub filters: Vec<Filter>,
+    pub timeouts: RouteTimeouts,
+    pub retry: Option<RetryPolicy>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Filter {
+    RequestHeaderModifier(HeaderModifierFilter),
+    ResponseHeaderModifier(HeaderModifierFilter),
+    FailureInjector(FailureInjectorFilter),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GrpcRoute {
+    pub hostnames: Vec<HostMatch>,
+    pub rules: Vec<GrpcRouteRule>,
+
+    /// This is required for ordering returned `GrpcRoute`s by their creation
+    /// timestamp.
+    pub creation_timestamp: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GrpcRouteRule {
+    pub matches: Vec<GrpcRouteMatch>,
+    pub backends: Vec<Backend>,
+    pub filters: Vec<Filter>,
+    pub retry: Option<RetryPolicy>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TcpRoute {
+    pub rules: Vec<TcpRouteRule>,
+
+    /// This is required for ordering returned `TcpRoute`s by their creation
+    /// timestamp.
+    pub creation_timestamp: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TcpRouteRule {
+    pub backends: Vec<Backend>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TlsRoute {
+    /// SNI values matched by this route.
+    pub hostnames: Vec<HostMatch>,
+
+    /// TLS routes forward connections in the same way as TCP routes.
+    pub rules: Vec<TcpRouteRule>,
+
+    /// This is required for ordering returned `TlsRoute`s by their creation
+    /// timestamp.
+    pub creation_timestamp: Option<DateTime<Utc>>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -65,6 +213,7 @@ pub struct WeightedService {
     pub name: String,
     pub namespace: String,
     pub port: NonZeroU16,
+    pub circuit_breaker: Option<CircuitBreaker>,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq)]
@@ -72,9 +221,41 @@ pub enum FailureAccrual {
     Consecutive { max_failures: u32, backoff: Backoff },
 }
 
+/// Bounds the load that a client may place on a service, so that a slow
+/// service cannot exhaust its clients.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CircuitBreaker {
+    /// The maximum number of requests that may wait for an endpoint to
+    /// become available.
+    pub max_pending_requests: u32,
+}
+
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct Backoff {
     pub min_penalty: time::Duration,
     pub max_penalty: time::Duration,
     pub jitter: f32,
 }
+
+/// Describes how failed requests may be retried.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+    /// The maximum number of times a single request may be retried.
+    pub max_retries: u32,
+
+    /// Response statuses that are considered retryable.
+    pub status_ranges: Vec<RangeInclusive<u16>>,
+
+    /// gRPC statuses that are considered retryable.
+    pub grpc_statuses: Vec<GrpcRetryStatus>,
+}
+
+/// The gRPC statuses that may be retried.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum GrpcRetryStatus {
+    Cancelled,
+    DeadlineExceeded,
+    Internal,
+    ResourceExhausted,
+    Unavailable,
+}
@@ -0,0 +1,17 @@
+use crate::http_route::convert_header_match;
+use linkerd2_proxy_api::grpc_route as proto;
+use linkerd_policy_controller_core::grpc_route::{GrpcMethodMatch, GrpcRouteMatch};
+
+pub(crate) fn convert_match(
+    GrpcRouteMatch { headers, method }: GrpcRouteMatch,
+) -> proto::GrpcRouteMatch {
+    let headers = headers.into_iter().map(convert_header_match).collect();
+
+    // An empty service or method matches any value.
+    let rpc = method.map(|GrpcMethodMatch { service, method }| proto::GrpcRpcMatch {
+        service: service.unwrap_or_default(),
+        method: method.unwrap_or_default(),
+    });
+
+    proto::GrpcRouteMatch { rpc, headers }
+}
@@ -1,7 +1,7 @@
 use linkerd2_proxy_api::{http_route as proto, http_types};
 use linkerd_policy_controller_core::http_route::{
-    FailureInjectorFilter, HeaderMatch, HostMatch, HttpRouteMatch, PathMatch, PathModifier,
-    QueryParamMatch, RequestHeaderModifierFilter, RequestRedirectFilter,
+    FailureInjectorFilter, HeaderMatch, HeaderModifierFilter, HeaderName, HeaderValue, HostMatch,
+    HttpRouteMatch, PathMatch, PathModifier, QueryParamMatch, RequestRedirectFilter, RouteTimeouts,
 };
 
 pub(crate) fn convert_host_match(h: HostMatch) -> proto::HostMatch {
@@ -25,19 +25,7 @@ pub(crate) fn convert_match(
         method,
     }: HttpRouteMatch,
 ) -> proto::HttpRouteMatch {
-    let headers = headers
-        .into_iter()
-        .map(|hm| match hm {
-            HeaderMatch::Exact(name, value) => proto::HeaderMatch {
-                name: name.to_string(),
-                value: Some(proto::header_match::Value::Exact(value.as_bytes().to_vec())),
-            },
-            HeaderMatch::Regex(name, re) => proto::HeaderMatch {
-                name: name.to_string(),
-                value: Some(proto::header_match::Value::Regex(re.to_string())),
-            },
-        })
-        .collect();
+    let headers = headers.into_iter().map(convert_header_match).collect();
 
     let path = path.map(|path| proto::PathMatch {
         kind: Some(match path {
@@ -69,6 +57,19 @@ pub(crate) fn convert_match(
     }
 }
 
+pub(crate) fn convert_header_match(hm: HeaderMatch) -> proto::HeaderMatch {
+    match hm {
+        HeaderMatch::Exact(name, value) => proto::HeaderMatch {
+            name: name.to_string(),
+            value: Some(proto::header_match::Value::Exact(value.as_bytes().to_vec())),
+        },
+        HeaderMatch::Regex(name, re) => proto::HeaderMatch {
+            name: name.to_string(),
+            value: Some(proto::header_match::Value::Regex(re.to_string())),
+        },
+    }
+}
+
 pub(crate) fn convert_failure_injector_filter(
     FailureInjectorFilter {
         status,
@@ -87,31 +88,37 @@ pub(crate) fn convert_failure_injector_filter(
 }
 
 pub(crate) fn convert_header_modifier_filter(
-    RequestHeaderModifierFilter { add, set, remove }: RequestHeaderModifierFilter,
+    HeaderModifierFilter { add, set, remove }: HeaderModifierFilter,
 ) -> proto::RequestHeaderModifier {
     proto::RequestHeaderModifier {
-        add: Some(http_types::Headers {
-            headers: add
-                .into_iter()
-                .map(|(n, v)| http_types::headers::Header {
-                    name: n.to_string(),
-                    value: v.as_bytes().to_owned(),
-                })
-                .collect(),
-        }),
-        set: Some(http_types::Headers {
-            headers: set
-                .into_iter()
-                .map(|(n, v)| http_types::headers::Header {
-                    name: n.to_string(),
-                    value: v.as_bytes().to_owned(),
-                })
-                .collect(),
-        }),
+        add: Some(convert_headers(add)),
+        set: Some(convert_headers(set)),
         remove: remove.into_iter().map(|n| n.to_string()).collect(),
     }
 }
 
+pub(crate) fn convert_response_header_modifier_filter(
+    HeaderModifierFilter { add, set, remove }: HeaderModifierFilter,
+) -> proto::ResponseHeaderModifier {
+    proto::ResponseHeaderModifier {
+        add: Some(convert_headers(add)),
+        set: Some(convert_headers(set)),
+        remove: remove.into_iter().map(|n| n.to_string()).collect(),
+    }
+}
+
+fn convert_headers(headers: Vec<(HeaderName, HeaderValue)>) -> http_types::Headers {
+    http_types::Headers {
+        headers: headers
+            .into_iter()
+            .map(|(n, v)| http_types::headers::Header {
+                name: n.to_string(),
+                value: v.as_bytes().to_owned(),
+            })
+            .collect(),
+    }
+}
+
 pub(crate) fn convert_redirect_filter(
     RequestRedirectFilter {
         scheme,
@@ -134,3 +141,26 @@ pub(crate) fn convert_redirect_filter(
         status: u32::from(status.unwrap_or_default().as_u16()),
     }
 }
+
+pub(crate) fn convert_timeouts(
+    RouteTimeouts {
+        request,
+        backend_request,
+    }: RouteTimeouts,
+) -> proto::Timeouts {
+    proto::Timeouts {
+        request: request.and_then(|d| {
+            d.try_into()
+                .map_err(|error| tracing::error!(?error, "invalid request timeout"))
+                .ok()
+        }),
+        // The proxy applies the response timeout to each request sent to a
+        // backend.
+        response: backend_request.and_then(|d| {
+            d.try_into()
+                .map_err(|error| tracing::error!(?error, "invalid backend request timeout"))
+                .ok()
+        }),
+        idle: None,
+    }
+}
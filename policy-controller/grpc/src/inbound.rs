@@ -1,4 +1,4 @@
-use crate::http_route;
+use crate::{grpc_route, http_route};
 use futures::prelude::*;
 use linkerd2_proxy_api::{
     self as api,
@@ -10,9 +10,10 @@ use linkerd2_proxy_api::{
 };
 use linkerd_policy_controller_core::{
     inbound::{
-        AuthorizationRef, ClientAuthentication, ClientAuthorization, DiscoverInboundServer, Filter,
-        HttpRoute, HttpRouteRef, HttpRouteRule, InboundServer, InboundServerStream, ProxyProtocol,
-        ServerRef,
+        AuthorizationAction, AuthorizationRef, ClientAuthentication, ClientAuthorization,
+        DiscoverInboundServer, Filter, GrpcRoute, GrpcRouteRef, GrpcRouteRule, HttpRoute,
+        HttpRouteRef, HttpRouteRule, InboundServer, InboundServerStream, Limit, ProxyProtocol,
+        RateLimit, ServerRef, WorkloadRef,
     },
     IdentityMatch, IpNet, NetworkMatch,
 };
@@ -31,7 +32,7 @@ pub struct InboundPolicyServer<T> {
 
 impl<T> InboundPolicyServer<T>
 where
-    T: DiscoverInboundServer<(String, String, NonZeroU16)> + Send + Sync + 'static,
+    T: DiscoverInboundServer<(String, WorkloadRef, NonZeroU16)> + Send + Sync + 'static,
 {
     pub fn new(discover: T, cluster_networks: Vec<IpNet>, drain: drain::Watch) -> Self {
         Self {
@@ -48,8 +49,9 @@ where
     fn check_target(
         &self,
         proto::PortSpec { workload, port }: proto::PortSpec,
-    ) -> Result<(String, String, NonZeroU16), tonic::Status> {
-        // Parse a workload name in the form namespace:name.
+    ) -> Result<(String, WorkloadRef, NonZeroU16), tonic::Status> {
+        // Parse a workload name in the form namespace:name. External workloads
+        // are named in the form namespace:externalworkload/name.
         let (ns, name) = match workload.split_once(':') {
             None => {
                 return Err(tonic::Status::invalid_argument(format!(
@@ -71,14 +73,27 @@ where
             .and_then(NonZeroU16::try_from)
             .map_err(|_| tonic::Status::invalid_argument(format!("Invalid port: {port}")))?;
 
-        Ok((ns.to_string(), name.to_string(), port))
+        let workload = match name.split_once('/') {
+            None => WorkloadRef::Pod(name.to_string()),
+            Some(("externalworkload", name)) if !name.is_empty() => {
+                WorkloadRef::External(name.to_string())
+            }
+            Some(_) => {
+                return Err(tonic::Status::invalid_argument(format!(
+                    "Invalid workload: {}",
+                    workload
+                )));
+            }
+        };
+
+        Ok((ns.to_string(), workload, port))
     }
 }
 
 #[async_trait::async_trait]
 impl<T> InboundServerPolicies for InboundPolicyServer<T>
 where
-    T: DiscoverInboundServer<(String, String, NonZeroU16)> + Send + Sync + 'static,
+    T: DiscoverInboundServer<(String, WorkloadRef, NonZeroU16)> + Send + Sync + 'static,
 {
     async fn get_port(
         &self,
@@ -161,20 +176,37 @@ fn to_server(srv: &InboundServer, cluster_networks: &[IpNet]) -> proto::Server {
                 proto::proxy_protocol::Detect {
                     timeout: timeout.try_into().map_err(|error| tracing::warn!(%error, "failed to convert protocol detect timeout to protobuf")).ok(),
                     http_routes: to_http_route_list(&srv.http_routes, cluster_networks),
+                    http_local_rate_limit: srv.ratelimit.as_ref().map(to_ratelimit),
                 },
             )),
             ProxyProtocol::Http1 => Some(proto::proxy_protocol::Kind::Http1(
                 proto::proxy_protocol::Http1 {
                     routes: to_http_route_list(&srv.http_routes, cluster_networks),
+                    local_rate_limit: srv.ratelimit.as_ref().map(to_ratelimit),
                 },
             )),
             ProxyProtocol::Http2 => Some(proto::proxy_protocol::Kind::Http2(
                 proto::proxy_protocol::Http2 {
                     routes: to_http_route_list(&srv.http_routes, cluster_networks),
+                    local_rate_limit: srv.ratelimit.as_ref().map(to_ratelimit),
                 },
             )),
-            ProxyProtocol::Grpc => Some(proto::proxy_protocol::Kind::Grpc(
-                proto::proxy_protocol::Grpc::default(),
+            // The proxy API cannot express rate limits for gRPC servers, so
+            // admission rejects them and they are never sent.
+            //
+            // gRPC servers are only described as such once a GRPCRoute is
+            // attached. Until then, they are served as HTTP/2 so that their
+            // HTTPRoutes continue to apply.
+            ProxyProtocol::Grpc if has_grpc_routes(srv) => Some(
+                proto::proxy_protocol::Kind::Grpc(proto::proxy_protocol::Grpc {
+                    routes: to_grpc_route_list(&srv.grpc_routes, cluster_networks),
+                }),
+            ),
+            ProxyProtocol::Grpc => Some(proto::proxy_protocol::Kind::Http2(
+                proto::proxy_protocol::Http2 {
+                    routes: to_http_route_list(&srv.http_routes, cluster_networks),
+                    local_rate_limit: None,
+                },
             )),
             ProxyProtocol::Opaque => Some(proto::proxy_protocol::Kind::Opaque(
                 proto::proxy_protocol::Opaque {},
@@ -186,11 +218,7 @@ fn to_server(srv: &InboundServer, cluster_networks: &[IpNet]) -> proto::Server {
     };
     trace!(?protocol);
 
-    let authorizations = srv
-        .authorizations
-        .iter()
-        .map(|(n, c)| to_authz(n, c, cluster_networks))
-        .collect();
+    let authorizations = to_authz_list(&srv.authorizations, cluster_networks);
     trace!(?authorizations);
 
     let labels = match &srv.reference {
@@ -215,11 +243,90 @@ fn to_server(srv: &InboundServer, cluster_networks: &[IpNet]) -> proto::Server {
     }
 }
 
+/// Returns true if any GRPCRoutes, rather than only the default route, are
+/// attached to the server.
+fn has_grpc_routes(srv: &InboundServer) -> bool {
+    srv.grpc_routes
+        .keys()
+        .any(|r| matches!(r, GrpcRouteRef::Gateway(_)))
+}
+
+fn to_ratelimit(
+    RateLimit {
+        name,
+        total,
+        identity,
+        overrides,
+    }: &RateLimit,
+) -> proto::HttpLocalRateLimit {
+    use proto::http_local_rate_limit as rl;
+
+    fn to_limit(
+        Limit {
+            requests_per_second,
+        }: &Limit,
+    ) -> rl::Limit {
+        rl::Limit {
+            requests_per_second: *requests_per_second,
+        }
+    }
+
+    let metadata = Metadata {
+        kind: Some(metadata::Kind::Resource(api::meta::Resource {
+            group: "policy.linkerd.io".to_string(),
+            kind: "HTTPLocalRateLimitPolicy".to_string(),
+            name: name.clone(),
+            ..Default::default()
+        })),
+    };
+
+    let overrides = overrides
+        .iter()
+        .map(|ovr| rl::Override {
+            limit: Some(rl::Limit {
+                requests_per_second: ovr.requests_per_second,
+            }),
+            clients: Some(rl::r#override::ClientIdentities {
+                identities: ovr
+                    .client_identities
+                    .iter()
+                    .map(|name| proto::Identity { name: name.clone() })
+                    .collect(),
+            }),
+        })
+        .collect();
+
+    proto::HttpLocalRateLimit {
+        metadata: Some(metadata),
+        total: total.as_ref().map(to_limit),
+        identity: identity.as_ref().map(to_limit),
+        overrides,
+    }
+}
+
+/// Converts authorizations so that audits follow all allowances. The proxy
+/// uses the first authorization that permits a request, so audits only flag
+/// requests that would otherwise be denied.
+fn to_authz_list<'a>(
+    authorizations: impl IntoIterator<Item = (&'a AuthorizationRef, &'a ClientAuthorization)>,
+    cluster_networks: &[IpNet],
+) -> Vec<proto::Authz> {
+    let (audits, allows): (Vec<_>, Vec<_>) = authorizations
+        .into_iter()
+        .partition(|(_, authz)| authz.action == AuthorizationAction::Audit);
+    allows
+        .into_iter()
+        .chain(audits)
+        .map(|(n, c)| to_authz(n, c, cluster_networks))
+        .collect()
+}
+
 fn to_authz(
     reference: &AuthorizationRef,
     ClientAuthorization {
         networks,
         authentication,
+        action,
     }: &ClientAuthorization,
     cluster_networks: &[IpNet],
 ) -> proto::Authz {
@@ -247,7 +354,7 @@ fn to_authz(
 
     // TODO labels are deprecated, but we want to continue to support them for older proxies. This
     // can be removed in 2.13.
-    let labels = match reference {
+    let mut labels = match reference {
         AuthorizationRef::Default(name) => convert_args!(hashmap!(
             "group" => "",
             "kind" => "default",
@@ -264,6 +371,11 @@ fn to_authz(
             "name" => name,
         )),
     };
+    // The proxy API has no field for an authorization's action, so audits are
+    // labeled. Allowances keep their existing labels.
+    if let AuthorizationAction::Audit = action {
+        labels.insert("action".to_string(), "audit".to_string());
+    }
 
     let networks = if networks.is_empty() {
         cluster_networks
@@ -406,15 +518,12 @@ fn to_http_route(
         .map(
             |HttpRouteRule { matches, filters }| proto::http_route::Rule {
                 matches: matches.into_iter().map(http_route::convert_match).collect(),
-                filters: filters.into_iter().map(convert_filter).collect(),
+                filters: filters.into_iter().filter_map(convert_filter).collect(),
             },
         )
         .collect();
 
-    let authorizations = authorizations
-        .iter()
-        .map(|(n, c)| to_authz(n, c, cluster_networks))
-        .collect();
+    let authorizations = to_authz_list(authorizations, cluster_networks);
 
     proto::HttpRoute {
         metadata: Some(metadata),
@@ -424,18 +533,202 @@ fn to_http_route(
     }
 }
 
-fn convert_filter(filter: Filter) -> proto::http_route::Filter {
+fn convert_filter(filter: Filter) -> Option<proto::http_route::Filter> {
     use proto::http_route::filter::Kind;
 
-    proto::http_route::Filter {
-        kind: Some(match filter {
-            Filter::FailureInjector(f) => {
-                Kind::FailureInjector(http_route::convert_failure_injector_filter(f))
+    let kind = match filter {
+        Filter::FailureInjector(f) => {
+            Kind::FailureInjector(http_route::convert_failure_injector_filter(f))
+        }
+        Filter::RequestHeaderModifier(f) => {
+            Kind::RequestHeaderModifier(http_route::convert_header_modifier_filter(f))
+        }
+        Filter::RequestRedirect(f) => Kind::Redirect(http_route::convert_redirect_filter(f)),
+        // The inbound proxy API cannot express response header modifiers.
+        // Admission rejects them on routes that attach to Servers.
+        Filter::ResponseHeaderModifier(_) => return None,
+    };
+    Some(proto::http_route::Filter { kind: Some(kind) })
+}
+
+fn to_grpc_route_list<'r>(
+    routes: impl IntoIterator<Item = (&'r GrpcRouteRef, &'r GrpcRoute)>,
+    cluster_networks: &[IpNet],
+) -> Vec<proto::GrpcRoute> {
+    // Routes are ordered in the same way as HTTP routes: oldest first, then by
+    // name.
+    let mut route_list = routes.into_iter().collect::<Vec<_>>();
+    route_list.sort_by(|(a_ref, a), (b_ref, b)| {
+        let by_ts = match (&a.creation_timestamp, &b.creation_timestamp) {
+            (Some(a_ts), Some(b_ts)) => a_ts.cmp(b_ts),
+            (None, None) => std::cmp::Ordering::Equal,
+            // Routes with timestamps are preferred over routes without.
+            (Some(_), None) => return std::cmp::Ordering::Less,
+            (None, Some(_)) => return std::cmp::Ordering::Greater,
+        };
+        by_ts.then_with(|| a_ref.cmp(b_ref))
+    });
+
+    route_list
+        .into_iter()
+        .map(|(route_ref, route)| to_grpc_route(route_ref, route.clone(), cluster_networks))
+        .collect()
+}
+
+fn to_grpc_route(
+    reference: &GrpcRouteRef,
+    GrpcRoute {
+        hostnames,
+        rules,
+        authorizations,
+        creation_timestamp: _,
+    }: GrpcRoute,
+    cluster_networks: &[IpNet],
+) -> proto::GrpcRoute {
+    let metadata = Metadata {
+        kind: Some(match reference {
+            GrpcRouteRef::Default(name) => metadata::Kind::Default(name.to_string()),
+            GrpcRouteRef::Gateway(name) => metadata::Kind::Resource(api::meta::Resource {
+                group: "gateway.networking.k8s.io".to_string(),
+                kind: "GRPCRoute".to_string(),
+                name: name.to_string(),
+                ..Default::default()
+            }),
+        }),
+    };
+
+    let hosts = hostnames
+        .into_iter()
+        .map(http_route::convert_host_match)
+        .collect();
+
+    let rules = rules
+        .into_iter()
+        .map(
+            |GrpcRouteRule { matches, filters }| proto::grpc_route::Rule {
+                matches: matches.into_iter().map(grpc_route::convert_match).collect(),
+                filters: filters
+                    .into_iter()
+                    .filter_map(convert_grpc_filter)
+                    .collect(),
+            },
+        )
+        .collect();
+
+    let authorizations = to_authz_list(authorizations, cluster_networks);
+
+    proto::GrpcRoute {
+        metadata: Some(metadata),
+        hosts,
+        rules,
+        authorizations,
+    }
+}
+
+fn convert_grpc_filter(filter: Filter) -> Option<proto::grpc_route::Filter> {
+    use proto::grpc_route::filter::Kind;
+
+    let kind = match filter {
+        Filter::RequestHeaderModifier(f) => {
+            Kind::RequestHeaderModifier(http_route::convert_header_modifier_filter(f))
+        }
+        // HTTP-only filters are never produced for gRPC routes.
+        Filter::FailureInjector(_)
+        | Filter::ResponseHeaderModifier(_)
+        | Filter::RequestRedirect(_) => return None,
+    };
+    Some(proto::grpc_route::Filter { kind: Some(kind) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mk_server(
+        grpc_routes: impl IntoIterator<Item = (GrpcRouteRef, GrpcRoute)>,
+    ) -> InboundServer {
+        InboundServer {
+            reference: ServerRef::Server("srv".to_string()),
+            protocol: ProxyProtocol::Grpc,
+            authorizations: Default::default(),
+            ratelimit: Some(RateLimit {
+                name: "rl".to_string(),
+                total: Some(Limit {
+                    requests_per_second: 100,
+                }),
+                identity: None,
+                overrides: vec![],
+            }),
+            http_routes: std::iter::once((
+                HttpRouteRef::Linkerd("route".to_string()),
+                HttpRoute::default(),
+            ))
+            .collect(),
+            grpc_routes: grpc_routes.into_iter().collect(),
+        }
+    }
+
+    #[test]
+    fn grpc_server_without_grpc_routes_is_http2() {
+        let server = to_server(
+            &mk_server([(GrpcRouteRef::Default("default"), GrpcRoute::default())]),
+            &[],
+        );
+        match server.protocol.and_then(|p| p.kind) {
+            Some(proto::proxy_protocol::Kind::Http2(http2)) => {
+                assert_eq!(http2.routes.len(), 1);
+                assert!(
+                    http2.local_rate_limit.is_none(),
+                    "gRPC servers must not be rate limited"
+                );
             }
-            Filter::RequestHeaderModifier(f) => {
-                Kind::RequestHeaderModifier(http_route::convert_header_modifier_filter(f))
+            kind => panic!("expected HTTP/2 protocol, got {kind:?}"),
+        }
+    }
+
+    #[test]
+    fn grpc_server_with_grpc_routes_is_grpc() {
+        let server = to_server(
+            &mk_server([(
+                GrpcRouteRef::Gateway("route".to_string()),
+                GrpcRoute::default(),
+            )]),
+            &[],
+        );
+        match server.protocol.and_then(|p| p.kind) {
+            Some(proto::proxy_protocol::Kind::Grpc(grpc)) => {
+                assert_eq!(grpc.routes.len(), 1);
             }
-            Filter::RequestRedirect(f) => Kind::Redirect(http_route::convert_redirect_filter(f)),
-        }),
+            kind => panic!("expected gRPC protocol, got {kind:?}"),
+        }
+    }
+
+    #[test]
+    fn audit_authorizations_follow_allowances() {
+        let authz = |action| ClientAuthorization {
+            networks: vec![],
+            authentication: ClientAuthentication::Unauthenticated,
+            action,
+        };
+        let authorizations = [
+            (
+                AuthorizationRef::Default("audit"),
+                authz(AuthorizationAction::Audit),
+            ),
+            (
+                AuthorizationRef::AuthorizationPolicy("authz".to_string()),
+                authz(AuthorizationAction::Allow),
+            ),
+        ];
+
+        let authzs = to_authz_list(authorizations.iter().map(|(n, c)| (n, c)), &[]);
+        let labels = authzs
+            .iter()
+            .map(|authz| {
+                let action = authz.labels.get("action").map(String::as_str);
+                (authz.labels["name"].as_str(), action)
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(labels, [("authz", None), ("audit", Some("audit"))]);
     }
 }
@@ -1,6 +1,7 @@
 #![deny(warnings, rust_2018_idioms)]
 #![forbid(unsafe_code)]
 
+mod grpc_route;
 mod http_route;
 
 pub mod inbound;
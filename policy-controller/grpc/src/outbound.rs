@@ -1,18 +1,33 @@
-use crate::http_route;
+use crate::{grpc_route, http_route};
 use futures::prelude::*;
 use linkerd2_proxy_api::{
     self as api, destination,
     meta::{metadata, Metadata},
     outbound::{
         self,
+        opaque_route::filter as opaque_filter,
         outbound_policies_server::{OutboundPolicies, OutboundPoliciesServer},
+        tls_route::filter as tls_filter,
     },
 };
-use linkerd_policy_controller_core::outbound::{
-    Backend, DiscoverOutboundPolicy, HttpRoute, HttpRouteRule, OutboundPolicy, OutboundPolicyStream,
+use linkerd_policy_controller_core::{
+    http_route::HostMatch,
+    outbound::{
+        Backend, CircuitBreaker, DiscoverOutboundPolicy, EgressDst, FailureAccrual, Filter,
+        GrpcRetryStatus, GrpcRoute, GrpcRouteRule, HttpRoute, HttpRouteRule,
+        OutboundDiscoverTarget, OutboundPolicy, OutboundPolicyStream, ParentKind, RetryPolicy,
+        TcpRoute, TcpRouteRule, TlsRoute, TrafficPolicy,
+    },
 };
 use std::{net::SocketAddr, num::NonZeroU16, sync::Arc, time};
 
+/// Requests with bodies larger than this are not retried, since their bodies
+/// must be buffered in order to be replayed.
+const RETRY_MAX_REQUEST_BYTES: u32 = 64 * 1024;
+
+/// How often egress hostnames are resolved while a client watches them.
+const EGRESS_RESOLVE_INTERVAL: time::Duration = time::Duration::from_secs(30);
+
 #[derive(Clone, Debug)]
 pub struct OutboundPolicyServer<T> {
     index: T,
@@ -23,7 +38,7 @@ pub struct OutboundPolicyServer<T> {
 
 impl<T> OutboundPolicyServer<T>
 where
-    T: DiscoverOutboundPolicy<(String, String, NonZeroU16)> + Send + Sync + 'static,
+    T: DiscoverOutboundPolicy<OutboundDiscoverTarget> + Send + Sync + 'static,
 {
     pub fn new(discover: T, cluster_domain: impl Into<Arc<str>>, drain: drain::Watch) -> Self {
         Self {
@@ -37,16 +52,23 @@ where
         OutboundPoliciesServer::new(self)
     }
 
-    fn lookup(
-        &self,
-        spec: outbound::TrafficSpec,
-    ) -> Result<(String, String, NonZeroU16), tonic::Status> {
+    fn lookup(&self, spec: outbound::TrafficSpec) -> Result<OutboundDiscoverTarget, tonic::Status> {
+        // Clients identify themselves as `<namespace>:<name>`. The namespace
+        // determines which egress networks apply to the client.
+        let source_namespace = spec
+            .source_workload
+            .split_once(':')
+            .map(|(ns, _)| ns)
+            .filter(|ns| !ns.is_empty());
+
         let target = spec
             .target
             .ok_or_else(|| tonic::Status::invalid_argument("target is required"))?;
         let target = match target {
             outbound::traffic_spec::Target::Addr(target) => target,
-            outbound::traffic_spec::Target::Authority(auth) => return self.lookup_authority(&auth),
+            outbound::traffic_spec::Target::Authority(auth) => {
+                return self.lookup_authority(&auth, source_namespace)
+            }
         };
 
         let port = target
@@ -65,74 +87,91 @@ where
             })?;
 
         self.index
-            .lookup_ip(addr, port)
+            .lookup_ip(addr, port, source_namespace)
             .ok_or_else(|| tonic::Status::not_found("No such service"))
     }
 
     fn lookup_authority(
         &self,
         authority: &str,
-    ) -> Result<(String, String, NonZeroU16), tonic::Status> {
+        source_namespace: Option<&str>,
+    ) -> Result<OutboundDiscoverTarget, tonic::Status> {
         let auth = authority
             .parse::<http::uri::Authority>()
             .map_err(|_| tonic::Status::invalid_argument("invalid authority"))?;
 
-        let mut host = auth.host();
+        let host = auth.host();
         if host.is_empty() {
             return Err(tonic::Status::invalid_argument(
                 "authority must have a host",
             ));
         }
 
-        host = host
+        let port = auth
+            .port_u16()
+            .and_then(|p| NonZeroU16::try_from(p).ok())
+            .unwrap_or_else(|| 80.try_into().unwrap());
+
+        if let Some((namespace, name)) = self.parse_service_host(host) {
+            return Ok(OutboundDiscoverTarget::Service {
+                namespace: namespace.to_string(),
+                name: name.to_string(),
+                port,
+            });
+        }
+
+        // Names outside of the cluster may be aliased by an ExternalName
+        // Service or matched by an egress network.
+        self.index
+            .lookup_hostname(host.trim_end_matches('.'), port, source_namespace)
+            .ok_or_else(|| {
+                tonic::Status::not_found(format!(
+                    "authority must be of the form <name>.<namespace>.svc.{}",
+                    self.cluster_domain,
+                ))
+            })
+    }
+
+    /// Parses a host of the form `<name>.<namespace>.svc.<cluster-domain>`,
+    /// returning the namespace and name.
+    fn parse_service_host<'h>(&self, host: &'h str) -> Option<(&'h str, &'h str)> {
+        let host = host
             .trim_end_matches('.')
             .trim_end_matches(&*self.cluster_domain);
 
         let mut parts = host.split('.');
-        let invalid = {
-            let domain = &self.cluster_domain;
-            move || {
-                tonic::Status::not_found(format!(
-                    "authority must be of the form <name>.<namespace>.svc.{domain}",
-                ))
-            }
-        };
-        let name = parts.next().ok_or_else(invalid)?;
-        let namespace = parts.next().ok_or_else(invalid)?;
+        let name = parts.next()?;
+        let namespace = parts.next()?;
         if parts.next() != Some("svc") {
-            return Err(invalid());
+            return None;
         };
 
-        let port = auth
-            .port_u16()
-            .and_then(|p| NonZeroU16::try_from(p).ok())
-            .unwrap_or_else(|| 80.try_into().unwrap());
-
-        Ok((namespace.to_string(), name.to_string(), port))
+        Some((namespace, name))
     }
 }
 
 #[async_trait::async_trait]
 impl<T> OutboundPolicies for OutboundPolicyServer<T>
 where
-    T: DiscoverOutboundPolicy<(String, String, NonZeroU16)> + Send + Sync + 'static,
+    T: DiscoverOutboundPolicy<OutboundDiscoverTarget> + Send + Sync + 'static,
 {
     async fn get(
         &self,
         req: tonic::Request<outbound::TrafficSpec>,
     ) -> Result<tonic::Response<outbound::OutboundPolicy>, tonic::Status> {
-        let service = self.lookup(req.into_inner())?;
+        let target = self.lookup(req.into_inner())?;
+        let original_dst = resolve_original_dst(original_dst(&target).as_ref()).await?;
 
         let policy = self
             .index
-            .get_outbound_policy(service)
+            .get_outbound_policy(target)
             .await
             .map_err(|error| {
                 tonic::Status::internal(format!("failed to get outbound policy: {error}"))
             })?;
 
         if let Some(policy) = policy {
-            Ok(tonic::Response::new(to_service(policy)))
+            Ok(tonic::Response::new(to_service(policy, original_dst)))
         } else {
             Err(tonic::Status::not_found("No such policy"))
         }
@@ -144,16 +183,23 @@ where
         &self,
         req: tonic::Request<outbound::TrafficSpec>,
     ) -> Result<tonic::Response<BoxWatchStream>, tonic::Status> {
-        let service = self.lookup(req.into_inner())?;
+        let target = self.lookup(req.into_inner())?;
+        let original_dst = original_dst(&target);
+        let resolved_dst = resolve_original_dst(original_dst.as_ref()).await?;
         let drain = self.drain.clone();
 
         let rx = self
             .index
-            .watch_outbound_policy(service)
+            .watch_outbound_policy(target)
             .await
             .map_err(|e| tonic::Status::internal(format!("lookup failed: {e}")))?
             .ok_or_else(|| tonic::Status::not_found("unknown server"))?;
-        Ok(tonic::Response::new(response_stream(drain, rx)))
+        Ok(tonic::Response::new(response_stream(
+            drain,
+            rx,
+            original_dst,
+            resolved_dst,
+        )))
     }
 }
 
@@ -161,22 +207,57 @@ type BoxWatchStream = std::pin::Pin<
     Box<dyn Stream<Item = Result<outbound::OutboundPolicy, tonic::Status>> + Send + Sync>,
 >;
 
-fn response_stream(drain: drain::Watch, mut rx: OutboundPolicyStream) -> BoxWatchStream {
+fn response_stream(
+    drain: drain::Watch,
+    mut rx: OutboundPolicyStream,
+    original_dst: Option<EgressDst>,
+    mut resolved_dst: Option<SocketAddr>,
+) -> BoxWatchStream {
     Box::pin(async_stream::try_stream! {
         tokio::pin! {
             let shutdown = drain.signaled();
         }
 
+        // Egress hostnames are resolved again periodically so that clients
+        // follow DNS changes.
+        let authority = match original_dst {
+            Some(EgressDst::Authority(authority)) => Some(authority),
+            _ => None,
+        };
+        let mut resolve = tokio::time::interval_at(
+            tokio::time::Instant::now() + EGRESS_RESOLVE_INTERVAL,
+            EGRESS_RESOLVE_INTERVAL,
+        );
+        let mut latest = None;
+
         loop {
             tokio::select! {
                 // When the port is updated with a new server, update the server watch.
                 res = rx.next() => match res {
                     Some(policy) => {
-                        yield to_service(policy);
+                        yield to_service(policy.clone(), resolved_dst);
+                        latest = Some(policy);
                     }
                     None => return,
                 },
 
+                _ = resolve.tick(), if authority.is_some() => {
+                    let authority = authority.as_deref().expect("authority must be set");
+                    match resolve_authority(authority).await {
+                        // The last known address is kept if resolution fails.
+                        Err(error) => {
+                            tracing::warn!(%error, authority, "failed to resolve egress authority");
+                        }
+                        Ok(addr) if Some(addr) == resolved_dst => {}
+                        Ok(addr) => {
+                            resolved_dst = Some(addr);
+                            if let Some(policy) = latest.clone() {
+                                yield to_service(policy, resolved_dst);
+                            }
+                        }
+                    }
+                },
+
                 // If the server starts shutting down, close the stream so that it doesn't hold the
                 // server open.
                 _ = (&mut shutdown) => {
@@ -187,29 +268,135 @@ fn response_stream(drain: drain::Watch, mut rx: OutboundPolicyStream) -> BoxWatc
     })
 }
 
-fn to_service(outbound: OutboundPolicy) -> outbound::OutboundPolicy {
-    let backend = default_backend(&outbound);
+fn original_dst(target: &OutboundDiscoverTarget) -> Option<EgressDst> {
+    match target {
+        OutboundDiscoverTarget::Egress { original_dst, .. } => Some(original_dst.clone()),
+        OutboundDiscoverTarget::Endpoint { addr, .. } => Some(EgressDst::Addr(*addr)),
+        OutboundDiscoverTarget::Service { .. } => None,
+    }
+}
+
+/// Resolves the address that egress traffic is forwarded to. The proxy can
+/// only forward to an address, so hostnames are resolved here.
+async fn resolve_original_dst(
+    original_dst: Option<&EgressDst>,
+) -> Result<Option<SocketAddr>, tonic::Status> {
+    match original_dst {
+        None => Ok(None),
+        Some(EgressDst::Addr(addr)) => Ok(Some(*addr)),
+        Some(EgressDst::Authority(authority)) => resolve_authority(authority)
+            .await
+            .map(Some)
+            .map_err(|error| {
+                tonic::Status::unavailable(format!("failed to resolve {authority}: {error}"))
+            }),
+    }
+}
 
-    let kind = if outbound.opaque {
+async fn resolve_authority(authority: &str) -> std::io::Result<SocketAddr> {
+    // The lowest address is used so that clients are not updated when a DNS
+    // server merely rotates its answers.
+    tokio::net::lookup_host(authority)
+        .await?
+        .min()
+        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::NotFound, "no addresses found"))
+}
+
+fn to_service(
+    outbound: OutboundPolicy,
+    original_dst: Option<SocketAddr>,
+) -> outbound::OutboundPolicy {
+    let backend = match original_dst {
+        Some(addr) if matches!(outbound.parent, ParentKind::Egress { .. }) => egress_backend(addr),
+        // Service endpoints that are addressed directly are not balanced over
+        // the Service's other endpoints.
+        Some(addr) => endpoint_backend(&outbound, addr),
+        None => default_backend(&outbound),
+    };
+
+    // Egress traffic that is not matched by a route is subject to the egress
+    // network's traffic policy.
+    let egress_policy = match outbound.parent {
+        ParentKind::Egress { traffic_policy } => Some(traffic_policy),
+        ParentKind::Service => None,
+    };
+    let denied = egress_policy == Some(TrafficPolicy::Deny);
+
+    // TCPRoutes are used for opaque traffic, whether the port is marked as
+    // opaque or protocol detection fails.
+    let tcp_routes = sort_routes(outbound.tcp_routes, |r| r.creation_timestamp);
+
+    let kind = if outbound.opaque && !outbound.tls_routes.is_empty() {
+        // TLSRoutes match on the SNI of the connection, so they take
+        // precedence over TCPRoutes on opaque ports.
+        let mut routes: Vec<_> = sort_routes(outbound.tls_routes, |r| r.creation_timestamp)
+            .into_iter()
+            .map(|(name, route)| {
+                let metadata = route_metadata("TLSRoute", outbound.namespace.clone(), name);
+                convert_outbound_tls_route(metadata, route, backend.clone())
+            })
+            .collect();
+
+        // Connections whose SNI is not matched by a TLSRoute are handled as
+        // opaque traffic. TCPRoutes don't match on SNI, so they are encoded
+        // as TLSRoutes that match all connections.
+        if tcp_routes.is_empty() {
+            let mut route = default_outbound_tls_route(backend.clone());
+            if denied {
+                for rule in route.rules.iter_mut() {
+                    rule.filters.push(outbound::tls_route::Filter {
+                        kind: Some(tls_filter::Kind::Forbidden(egress_forbidden())),
+                    });
+                }
+            }
+            routes.push(route);
+        }
+        routes.extend(tcp_routes.into_iter().map(|(name, route)| {
+            let metadata = route_metadata("TCPRoute", outbound.namespace.clone(), name);
+            let route = TlsRoute {
+                hostnames: vec![],
+                rules: route.rules,
+                creation_timestamp: route.creation_timestamp,
+            };
+            convert_outbound_tls_route(metadata, route, backend.clone())
+        }));
+
+        linkerd2_proxy_api::outbound::proxy_protocol::Kind::Tls(outbound::proxy_protocol::Tls {
+            routes,
+        })
+    } else if outbound.opaque {
         linkerd2_proxy_api::outbound::proxy_protocol::Kind::Opaque(
             outbound::proxy_protocol::Opaque {
-                routes: vec![default_outbound_opaq_route(backend)],
+                routes: convert_outbound_tcp_routes(
+                    &outbound.namespace,
+                    tcp_routes,
+                    &backend,
+                    denied,
+                ),
             },
         )
-    } else {
-        let mut http_routes = outbound.http_routes.into_iter().collect::<Vec<_>>();
-        http_routes.sort_by(|(a_name, a_route), (b_name, b_route)| {
-            let by_ts = match (&a_route.creation_timestamp, &b_route.creation_timestamp) {
-                (Some(a_ts), Some(b_ts)) => a_ts.cmp(b_ts),
-                (None, None) => std::cmp::Ordering::Equal,
-                // Routes with timestamps are preferred over routes without.
-                (Some(_), None) => return std::cmp::Ordering::Less,
-                (None, Some(_)) => return std::cmp::Ordering::Greater,
-            };
-            by_ts.then_with(|| a_name.cmp(b_name))
-        });
+    } else if !outbound.grpc_routes.is_empty() {
+        // When GRPCRoutes are attached to the service, the proxy need not
+        // perform protocol detection.
+        let routes = sort_routes(outbound.grpc_routes, |r| r.creation_timestamp)
+            .into_iter()
+            .map(|(name, route)| {
+                convert_outbound_grpc_route(
+                    outbound.namespace.clone(),
+                    name,
+                    route,
+                    backend.clone(),
+                    outbound.retry.as_ref(),
+                )
+            })
+            .collect();
 
-        let mut http_routes: Vec<_> = http_routes
+        linkerd2_proxy_api::outbound::proxy_protocol::Kind::Grpc(outbound::proxy_protocol::Grpc {
+            routes,
+            failure_accrual: outbound.accrual.map(convert_accrual),
+        })
+    } else {
+        let mut http_routes: Vec<_> = sort_routes(outbound.http_routes, |r| r.creation_timestamp)
             .into_iter()
             .map(|(name, route)| {
                 convert_outbound_http_route(
@@ -217,46 +404,22 @@ fn to_service(outbound: OutboundPolicy) -> outbound::OutboundPolicy {
                     name,
                     route,
                     backend.clone(),
+                    outbound.retry.as_ref(),
                 )
             })
             .collect();
 
-        if http_routes.is_empty() {
-            http_routes = vec![default_outbound_http_route(backend.clone())];
+        if http_routes.is_empty() || egress_policy.is_some() {
+            let mut route = default_outbound_http_route(backend.clone(), outbound.retry.as_ref());
+            if denied {
+                for rule in route.rules.iter_mut() {
+                    rule.filters.push(egress_denied_filter());
+                }
+            }
+            http_routes.push(route);
         }
 
-        let accrual =
-            outbound
-                .accrual
-                .map(|accrual| linkerd2_proxy_api::outbound::FailureAccrual {
-                    kind: Some(match accrual {
-                        linkerd_policy_controller_core::outbound::FailureAccrual::Consecutive {
-                            max_failures,
-                            backoff,
-                        } => outbound::failure_accrual::Kind::ConsecutiveFailures(
-                            outbound::failure_accrual::ConsecutiveFailures {
-                                max_failures,
-                                backoff: Some(outbound::ExponentialBackoff {
-                                    min_backoff: backoff
-                                        .min_penalty
-                                        .try_into()
-                                        .map_err(|error| {
-                                            tracing::error!(?error, "invalid min_backoff")
-                                        })
-                                        .ok(),
-                                    max_backoff: backoff
-                                        .max_penalty
-                                        .try_into()
-                                        .map_err(|error| {
-                                            tracing::error!(?error, "invalid max_backoff")
-                                        })
-                                        .ok(),
-                                    jitter_ratio: backoff.jitter,
-                                }),
-                            },
-                        ),
-                    }),
-                });
+        let accrual = outbound.accrual.map(convert_accrual);
 
         linkerd2_proxy_api::outbound::proxy_protocol::Kind::Detect(
             outbound::proxy_protocol::Detect {
@@ -266,7 +429,12 @@ fn to_service(outbound: OutboundPolicy) -> outbound::OutboundPolicy {
                         .expect("failed to convert detect timeout to protobuf"),
                 ),
                 opaque: Some(outbound::proxy_protocol::Opaque {
-                    routes: vec![default_outbound_opaq_route(backend)],
+                    routes: convert_outbound_tcp_routes(
+                        &outbound.namespace,
+                        tcp_routes,
+                        &backend,
+                        denied,
+                    ),
                 }),
                 http1: Some(outbound::proxy_protocol::Http1 {
                     routes: http_routes.clone(),
@@ -280,10 +448,14 @@ fn to_service(outbound: OutboundPolicy) -> outbound::OutboundPolicy {
         )
     };
 
+    let (group, parent_kind) = match outbound.parent {
+        ParentKind::Service => ("core", "Service"),
+        ParentKind::Egress { .. } => ("policy.linkerd.io", "EgressNetwork"),
+    };
     let metadata = Metadata {
         kind: Some(metadata::Kind::Resource(api::meta::Resource {
-            group: "core".to_string(),
-            kind: "Service".to_string(),
+            group: group.to_string(),
+            kind: parent_kind.to_string(),
             namespace: outbound.namespace,
             name: outbound.name,
             port: u16::from(outbound.port).into(),
@@ -306,6 +478,7 @@ fn convert_outbound_http_route(
         creation_timestamp: _,
     }: HttpRoute,
     backend: outbound::Backend,
+    default_retry: Option<&RetryPolicy>,
 ) -> outbound::HttpRoute {
     let metadata = Some(Metadata {
         kind: Some(metadata::Kind::Resource(api::meta::Resource {
@@ -324,37 +497,405 @@ fn convert_outbound_http_route(
 
     let rules = rules
         .into_iter()
-        .map(|HttpRouteRule { matches, backends }| {
-            let backends = backends
-                .into_iter()
-                .map(convert_http_backend)
-                .collect::<Vec<_>>();
+        .map(
+            |HttpRouteRule {
+                 matches,
+                 backends,
+                 filters,
+                 timeouts,
+                 retry,
+             }| {
+                let backends = backends
+                    .into_iter()
+                    .map(convert_http_backend)
+                    .collect::<Vec<_>>();
+                let dist = if backends.is_empty() {
+                    outbound::http_route::distribution::Kind::FirstAvailable(
+                        outbound::http_route::distribution::FirstAvailable {
+                            backends: vec![outbound::http_route::RouteBackend {
+                                backend: Some(backend.clone()),
+                                filters: vec![],
+                                ..Default::default()
+                            }],
+                        },
+                    )
+                } else {
+                    outbound::http_route::distribution::Kind::RandomAvailable(
+                        outbound::http_route::distribution::RandomAvailable { backends },
+                    )
+                };
+                outbound::http_route::Rule {
+                    matches: matches.into_iter().map(http_route::convert_match).collect(),
+                    backends: Some(outbound::http_route::Distribution { kind: Some(dist) }),
+                    filters: filters.into_iter().map(convert_outbound_filter).collect(),
+                    timeouts: Some(http_route::convert_timeouts(timeouts)),
+                    retry: retry.as_ref().or(default_retry).and_then(convert_retry),
+                    ..Default::default()
+                }
+            },
+        )
+        .collect();
+
+    outbound::HttpRoute {
+        metadata,
+        hosts,
+        rules,
+    }
+}
+
+fn convert_outbound_filter(filter: Filter) -> outbound::http_route::Filter {
+    use outbound::http_route::filter::Kind;
+
+    let kind = match filter {
+        Filter::RequestHeaderModifier(f) => {
+            Kind::RequestHeaderModifier(http_route::convert_header_modifier_filter(f))
+        }
+        Filter::ResponseHeaderModifier(f) => {
+            Kind::ResponseHeaderModifier(http_route::convert_response_header_modifier_filter(f))
+        }
+        Filter::FailureInjector(f) => {
+            Kind::FailureInjector(http_route::convert_failure_injector_filter(f))
+        }
+    };
+    outbound::http_route::Filter { kind: Some(kind) }
+}
+
+fn convert_outbound_grpc_route(
+    namespace: String,
+    name: String,
+    GrpcRoute {
+        hostnames,
+        rules,
+        creation_timestamp: _,
+    }: GrpcRoute,
+    backend: outbound::Backend,
+    default_retry: Option<&RetryPolicy>,
+) -> outbound::GrpcRoute {
+    let metadata = Some(Metadata {
+        kind: Some(metadata::Kind::Resource(api::meta::Resource {
+            group: "gateway.networking.k8s.io".to_string(),
+            kind: "GRPCRoute".to_string(),
+            namespace,
+            name,
+            ..Default::default()
+        })),
+    });
+
+    let hosts = hostnames
+        .into_iter()
+        .map(http_route::convert_host_match)
+        .collect();
+
+    let rules = rules
+        .into_iter()
+        .map(
+            |GrpcRouteRule {
+                 matches,
+                 backends,
+                 filters,
+                 retry,
+             }| {
+                let backends = backends
+                    .into_iter()
+                    .map(convert_grpc_backend)
+                    .collect::<Vec<_>>();
+                let dist = if backends.is_empty() {
+                    outbound::grpc_route::distribution::Kind::FirstAvailable(
+                        outbound::grpc_route::distribution::FirstAvailable {
+                            backends: vec![outbound::grpc_route::RouteBackend {
+                                backend: Some(backend.clone()),
+                                filters: vec![],
+                                ..Default::default()
+                            }],
+                        },
+                    )
+                } else {
+                    outbound::grpc_route::distribution::Kind::RandomAvailable(
+                        outbound::grpc_route::distribution::RandomAvailable { backends },
+                    )
+                };
+                outbound::grpc_route::Rule {
+                    matches: matches.into_iter().map(grpc_route::convert_match).collect(),
+                    backends: Some(outbound::grpc_route::Distribution { kind: Some(dist) }),
+                    filters: filters
+                        .into_iter()
+                        .filter_map(convert_outbound_grpc_filter)
+                        .collect(),
+                    retry: retry
+                        .as_ref()
+                        .or(default_retry)
+                        .and_then(convert_grpc_retry),
+                    ..Default::default()
+                }
+            },
+        )
+        .collect();
+
+    outbound::GrpcRoute {
+        metadata,
+        hosts,
+        rules,
+    }
+}
+
+fn convert_outbound_grpc_filter(filter: Filter) -> Option<outbound::grpc_route::Filter> {
+    use outbound::grpc_route::filter::Kind;
+
+    let kind = match filter {
+        Filter::RequestHeaderModifier(f) => {
+            Kind::RequestHeaderModifier(http_route::convert_header_modifier_filter(f))
+        }
+        // HTTP-only filters are never produced for gRPC routes.
+        Filter::ResponseHeaderModifier(_) | Filter::FailureInjector(_) => return None,
+    };
+    Some(outbound::grpc_route::Filter { kind: Some(kind) })
+}
+
+fn convert_grpc_backend(backend: Backend) -> outbound::grpc_route::WeightedRouteBackend {
+    match backend {
+        Backend::Invalid { weight, message } => outbound::grpc_route::WeightedRouteBackend {
+            weight,
+            backend: Some(outbound::grpc_route::RouteBackend {
+                backend: Some(outbound::Backend {
+                    metadata: Some(Metadata {
+                        kind: Some(metadata::Kind::Default("invalid".to_string())),
+                    }),
+                    queue: Some(default_queue_config()),
+                    kind: None,
+                }),
+                filters: vec![outbound::grpc_route::Filter {
+                    kind: Some(outbound::grpc_route::filter::Kind::FailureInjector(
+                        api::grpc_route::GrpcFailureInjector {
+                            // gRPC status INTERNAL.
+                            code: 13,
+                            message,
+                            ratio: None,
+                        },
+                    )),
+                }],
+                ..Default::default()
+            }),
+        },
+        backend => {
+            // Valid backends are encoded identically for HTTP and gRPC routes.
+            let outbound::http_route::WeightedRouteBackend { weight, backend } =
+                convert_http_backend(backend);
+            outbound::grpc_route::WeightedRouteBackend {
+                weight,
+                backend: backend.map(|rb| outbound::grpc_route::RouteBackend {
+                    backend: rb.backend,
+                    filters: Default::default(),
+                    ..Default::default()
+                }),
+            }
+        }
+    }
+}
+
+/// Converts TCPRoutes to opaque routes, falling back to the default opaque
+/// route when no TCPRoutes apply. The default route forbids connections to
+/// denied egress networks.
+fn convert_outbound_tcp_routes(
+    namespace: &str,
+    routes: Vec<(String, TcpRoute)>,
+    backend: &outbound::Backend,
+    denied: bool,
+) -> Vec<outbound::OpaqueRoute> {
+    if routes.is_empty() {
+        let mut route = default_outbound_opaq_route(backend.clone());
+        if denied {
+            for rule in route.rules.iter_mut() {
+                rule.filters.push(outbound::opaque_route::Filter {
+                    kind: Some(opaque_filter::Kind::Forbidden(egress_forbidden())),
+                });
+            }
+        }
+        return vec![route];
+    }
+    routes
+        .into_iter()
+        .map(|(name, route)| {
+            let metadata = route_metadata("TCPRoute", namespace.to_string(), name);
+            convert_outbound_tcp_route(metadata, route, backend.clone())
+        })
+        .collect()
+}
+
+fn convert_outbound_tcp_route(
+    metadata: Metadata,
+    TcpRoute {
+        rules,
+        creation_timestamp: _,
+    }: TcpRoute,
+    backend: outbound::Backend,
+) -> outbound::OpaqueRoute {
+    let rules = rules
+        .into_iter()
+        .map(|TcpRouteRule { backends }| {
             let dist = if backends.is_empty() {
-                outbound::http_route::distribution::Kind::FirstAvailable(
-                    outbound::http_route::distribution::FirstAvailable {
-                        backends: vec![outbound::http_route::RouteBackend {
+                outbound::opaque_route::distribution::Kind::FirstAvailable(
+                    outbound::opaque_route::distribution::FirstAvailable {
+                        backends: vec![outbound::opaque_route::RouteBackend {
                             backend: Some(backend.clone()),
                             filters: vec![],
                         }],
                     },
                 )
             } else {
-                outbound::http_route::distribution::Kind::RandomAvailable(
-                    outbound::http_route::distribution::RandomAvailable { backends },
+                let backends = backends
+                    .into_iter()
+                    .map(|b| convert_opaque_backend(b, &backend))
+                    .collect();
+                outbound::opaque_route::distribution::Kind::RandomAvailable(
+                    outbound::opaque_route::distribution::RandomAvailable { backends },
                 )
             };
-            outbound::http_route::Rule {
-                matches: matches.into_iter().map(http_route::convert_match).collect(),
-                backends: Some(outbound::http_route::Distribution { kind: Some(dist) }),
-                filters: Default::default(),
+            outbound::opaque_route::Rule {
+                backends: Some(outbound::opaque_route::Distribution { kind: Some(dist) }),
+                filters: vec![],
             }
         })
         .collect();
 
-    outbound::HttpRoute {
-        metadata,
-        hosts,
+    outbound::OpaqueRoute {
+        metadata: Some(metadata),
+        rules,
+    }
+}
+
+fn convert_outbound_tls_route(
+    metadata: Metadata,
+    TlsRoute {
+        hostnames,
         rules,
+        creation_timestamp: _,
+    }: TlsRoute,
+    backend: outbound::Backend,
+) -> outbound::TlsRoute {
+    let snis = hostnames.into_iter().map(convert_sni_match).collect();
+
+    let rules = rules
+        .into_iter()
+        .map(|TcpRouteRule { backends }| {
+            let dist = if backends.is_empty() {
+                outbound::tls_route::distribution::Kind::FirstAvailable(
+                    outbound::tls_route::distribution::FirstAvailable {
+                        backends: vec![outbound::tls_route::RouteBackend {
+                            backend: Some(backend.clone()),
+                            filters: vec![],
+                        }],
+                    },
+                )
+            } else {
+                let backends = backends
+                    .into_iter()
+                    .map(|b| {
+                        let outbound::opaque_route::WeightedRouteBackend {
+                            weight,
+                            backend: rb,
+                        } = convert_opaque_backend(b, &backend);
+                        outbound::tls_route::WeightedRouteBackend {
+                            weight,
+                            backend: rb.map(|rb| outbound::tls_route::RouteBackend {
+                                backend: rb.backend,
+                                filters: rb
+                                    .filters
+                                    .into_iter()
+                                    .map(|f| outbound::tls_route::Filter {
+                                        kind: f.kind.map(|kind| match kind {
+                                            opaque_filter::Kind::Invalid(invalid) => {
+                                                tls_filter::Kind::Invalid(invalid)
+                                            }
+                                            opaque_filter::Kind::Forbidden(forbidden) => {
+                                                tls_filter::Kind::Forbidden(forbidden)
+                                            }
+                                        }),
+                                    })
+                                    .collect(),
+                            }),
+                        }
+                    })
+                    .collect();
+                outbound::tls_route::distribution::Kind::RandomAvailable(
+                    outbound::tls_route::distribution::RandomAvailable { backends },
+                )
+            };
+            outbound::tls_route::Rule {
+                backends: Some(outbound::tls_route::Distribution { kind: Some(dist) }),
+                filters: vec![],
+            }
+        })
+        .collect();
+
+    outbound::TlsRoute {
+        metadata: Some(metadata),
+        snis,
+        rules,
+    }
+}
+
+fn route_metadata(kind: &str, namespace: String, name: String) -> Metadata {
+    Metadata {
+        kind: Some(metadata::Kind::Resource(api::meta::Resource {
+            group: "gateway.networking.k8s.io".to_string(),
+            kind: kind.to_string(),
+            namespace,
+            name,
+            ..Default::default()
+        })),
+    }
+}
+
+fn convert_sni_match(h: HostMatch) -> api::tls_route::SniMatch {
+    use api::tls_route::sni_match;
+
+    api::tls_route::SniMatch {
+        r#match: Some(match h {
+            HostMatch::Exact(host) => sni_match::Match::Exact(host),
+            HostMatch::Suffix { reverse_labels } => sni_match::Match::Suffix(sni_match::Suffix {
+                reverse_labels: reverse_labels.to_vec(),
+            }),
+        }),
+    }
+}
+
+/// Converts a backend for use in an opaque route.
+///
+/// Connections routed to an invalid backend are failed by an `Invalid` filter.
+/// The route's default backend is used in its place so that the policy
+/// remains valid.
+fn convert_opaque_backend(
+    backend: Backend,
+    default_backend: &outbound::Backend,
+) -> outbound::opaque_route::WeightedRouteBackend {
+    match backend {
+        Backend::Invalid { weight, message } => outbound::opaque_route::WeightedRouteBackend {
+            weight,
+            backend: Some(outbound::opaque_route::RouteBackend {
+                backend: Some(outbound::Backend {
+                    metadata: Some(Metadata {
+                        kind: Some(metadata::Kind::Default("invalid".to_string())),
+                    }),
+                    ..default_backend.clone()
+                }),
+                filters: vec![outbound::opaque_route::Filter {
+                    kind: Some(opaque_filter::Kind::Invalid(api::opaque_route::Invalid {
+                        message,
+                    })),
+                }],
+            }),
+        },
+        backend => {
+            let outbound::http_route::WeightedRouteBackend { weight, backend } =
+                convert_http_backend(backend);
+            outbound::opaque_route::WeightedRouteBackend {
+                weight,
+                backend: Some(outbound::opaque_route::RouteBackend {
+                    backend: backend.and_then(|rb| rb.backend),
+                    filters: vec![],
+                }),
+            }
+        }
     }
 }
 
@@ -377,6 +918,7 @@ fn convert_http_backend(backend: Backend) -> outbound::http_route::WeightedRoute
                         )),
                     }),
                     filters: Default::default(),
+                    ..Default::default()
                 }),
             }
         }
@@ -394,7 +936,7 @@ fn convert_http_backend(backend: Backend) -> outbound::http_route::WeightedRoute
                             port: u16::from(svc.port).into(),
                         })),
                     }),
-                    queue: Some(default_queue_config()),
+                    queue: Some(queue_config(svc.circuit_breaker)),
                     kind: Some(outbound::backend::Kind::Balancer(
                         outbound::backend::BalanceP2c {
                             discovery: Some(outbound::backend::EndpointDiscovery {
@@ -409,6 +951,7 @@ fn convert_http_backend(backend: Backend) -> outbound::http_route::WeightedRoute
                     )),
                 }),
                 filters: Default::default(),
+                ..Default::default()
             }),
         },
         Backend::Invalid { weight, message } => outbound::http_route::WeightedRouteBackend {
@@ -430,6 +973,7 @@ fn convert_http_backend(backend: Backend) -> outbound::http_route::WeightedRoute
                         },
                     )),
                 }],
+                ..Default::default()
             }),
         },
     }
@@ -440,7 +984,7 @@ fn default_backend(outbound: &OutboundPolicy) -> outbound::Backend {
         metadata: Some(Metadata {
             kind: Some(metadata::Kind::Default("service".to_string())),
         }),
-        queue: Some(default_queue_config()),
+        queue: Some(queue_config(outbound.circuit_breaker)),
         kind: Some(outbound::backend::Kind::Balancer(
             outbound::backend::BalanceP2c {
                 discovery: Some(outbound::backend::EndpointDiscovery {
@@ -456,7 +1000,164 @@ fn default_backend(outbound: &OutboundPolicy) -> outbound::Backend {
     }
 }
 
-fn default_outbound_http_route(backend: outbound::Backend) -> outbound::HttpRoute {
+/// The queue for a Service's backend, which bounds the number of pending
+/// requests when the Service has a circuit breaker.
+fn queue_config(circuit_breaker: Option<CircuitBreaker>) -> outbound::Queue {
+    let mut queue = default_queue_config();
+    if let Some(CircuitBreaker {
+        max_pending_requests,
+    }) = circuit_breaker
+    {
+        queue.capacity = max_pending_requests;
+    }
+    queue
+}
+
+/// Forwards traffic to a Service endpoint that the client addressed directly.
+fn endpoint_backend(outbound: &OutboundPolicy, addr: SocketAddr) -> outbound::Backend {
+    outbound::Backend {
+        metadata: Some(Metadata {
+            kind: Some(metadata::Kind::Default("endpoint".to_string())),
+        }),
+        queue: Some(queue_config(outbound.circuit_breaker)),
+        kind: Some(outbound::backend::Kind::Forward(
+            destination::WeightedAddr {
+                addr: Some(addr.into()),
+                weight: 1,
+                ..Default::default()
+            },
+        )),
+    }
+}
+
+/// Forwards egress traffic to the client's original destination.
+fn egress_backend(addr: SocketAddr) -> outbound::Backend {
+    outbound::Backend {
+        metadata: Some(Metadata {
+            kind: Some(metadata::Kind::Default("egress".to_string())),
+        }),
+        queue: Some(default_queue_config()),
+        kind: Some(outbound::backend::Kind::Forward(
+            destination::WeightedAddr {
+                addr: Some(addr.into()),
+                weight: 1,
+                ..Default::default()
+            },
+        )),
+    }
+}
+
+fn egress_forbidden() -> api::opaque_route::Forbidden {
+    api::opaque_route::Forbidden {}
+}
+
+fn egress_denied_filter() -> outbound::http_route::Filter {
+    outbound::http_route::Filter {
+        kind: Some(outbound::http_route::filter::Kind::FailureInjector(
+            api::http_route::HttpFailureInjector {
+                status: 403,
+                message: "traffic to this egress network is not permitted".to_string(),
+                ratio: None,
+            },
+        )),
+    }
+}
+
+fn convert_accrual(accrual: FailureAccrual) -> outbound::FailureAccrual {
+    outbound::FailureAccrual {
+        kind: Some(match accrual {
+            FailureAccrual::Consecutive {
+                max_failures,
+                backoff,
+            } => outbound::failure_accrual::Kind::ConsecutiveFailures(
+                outbound::failure_accrual::ConsecutiveFailures {
+                    max_failures,
+                    backoff: Some(outbound::ExponentialBackoff {
+                        min_backoff: backoff
+                            .min_penalty
+                            .try_into()
+                            .map_err(|error| tracing::error!(?error, "invalid min_backoff"))
+                            .ok(),
+                        max_backoff: backoff
+                            .max_penalty
+                            .try_into()
+                            .map_err(|error| tracing::error!(?error, "invalid max_backoff"))
+                            .ok(),
+                        jitter_ratio: backoff.jitter,
+                    }),
+                },
+            ),
+        }),
+    }
+}
+
+/// Policies that only configure retryable gRPC statuses do not retry HTTP
+/// requests.
+fn convert_retry(
+    RetryPolicy {
+        max_retries,
+        status_ranges,
+        grpc_statuses: _,
+    }: &RetryPolicy,
+) -> Option<outbound::http_route::Retry> {
+    use outbound::http_route::retry;
+
+    if status_ranges.is_empty() {
+        return None;
+    }
+
+    let status_ranges = status_ranges
+        .iter()
+        .map(|range| retry::conditions::StatusRange {
+            start: (*range.start()).into(),
+            end: (*range.end()).into(),
+        })
+        .collect();
+
+    Some(outbound::http_route::Retry {
+        max_retries: *max_retries,
+        max_request_bytes: RETRY_MAX_REQUEST_BYTES,
+        conditions: Some(retry::Conditions { status_ranges }),
+        ..Default::default()
+    })
+}
+
+/// gRPC responses carry their status in trailers rather than in the HTTP
+/// status, so only the retryable gRPC statuses apply to gRPC routes.
+fn convert_grpc_retry(
+    RetryPolicy {
+        max_retries,
+        status_ranges: _,
+        grpc_statuses,
+    }: &RetryPolicy,
+) -> Option<outbound::grpc_route::Retry> {
+    if grpc_statuses.is_empty() {
+        return None;
+    }
+
+    let mut conditions = outbound::grpc_route::retry::Conditions::default();
+    for status in grpc_statuses {
+        match status {
+            GrpcRetryStatus::Cancelled => conditions.cancelled = true,
+            GrpcRetryStatus::DeadlineExceeded => conditions.deadline_exceeded = true,
+            GrpcRetryStatus::Internal => conditions.internal = true,
+            GrpcRetryStatus::ResourceExhausted => conditions.resource_exhausted = true,
+            GrpcRetryStatus::Unavailable => conditions.unavailable = true,
+        }
+    }
+
+    Some(outbound::grpc_route::Retry {
+        max_retries: *max_retries,
+        max_request_bytes: RETRY_MAX_REQUEST_BYTES,
+        conditions: Some(conditions),
+        ..Default::default()
+    })
+}
+
+fn default_outbound_http_route(
+    backend: outbound::Backend,
+    retry: Option<&RetryPolicy>,
+) -> outbound::HttpRoute {
     let metadata = Some(Metadata {
         kind: Some(metadata::Kind::Default("http".to_string())),
     });
@@ -473,11 +1174,14 @@ fn default_outbound_http_route(backend: outbound::Backend) -> outbound::HttpRout
                     backends: vec![outbound::http_route::RouteBackend {
                         backend: Some(backend),
                         filters: vec![],
+                        ..Default::default()
                     }],
                 },
             )),
         }),
         filters: Default::default(),
+        retry: retry.and_then(convert_retry),
+        ..Default::default()
     }];
     outbound::HttpRoute {
         metadata,
@@ -496,14 +1200,68 @@ fn default_outbound_opaq_route(backend: outbound::Backend) -> outbound::OpaqueRo
                 outbound::opaque_route::distribution::FirstAvailable {
                     backends: vec![outbound::opaque_route::RouteBackend {
                         backend: Some(backend),
+                        filters: vec![],
                     }],
                 },
             )),
         }),
+        filters: vec![],
     }];
     outbound::OpaqueRoute { metadata, rules }
 }
 
+fn default_outbound_tls_route(backend: outbound::Backend) -> outbound::TlsRoute {
+    let metadata = Some(Metadata {
+        kind: Some(metadata::Kind::Default("tls".to_string())),
+    });
+    let rules = vec![outbound::tls_route::Rule {
+        backends: Some(outbound::tls_route::Distribution {
+            kind: Some(outbound::tls_route::distribution::Kind::FirstAvailable(
+                outbound::tls_route::distribution::FirstAvailable {
+                    backends: vec![outbound::tls_route::RouteBackend {
+                        backend: Some(backend),
+                        filters: vec![],
+                    }],
+                },
+            )),
+        }),
+        filters: vec![],
+    }];
+    outbound::TlsRoute {
+        metadata,
+        rules,
+        ..Default::default()
+    }
+}
+
+/// Orders routes by their creation timestamps and then by name.
+///
+/// Per the Gateway API spec:
+///
+/// > If ties still exist across multiple Routes, matching precedence MUST be
+/// > determined in order of the following criteria, continuing on ties:
+/// >
+/// >    The oldest Route based on creation timestamp.
+/// >    The Route appearing first in alphabetical order by
+/// >   "{namespace}/{name}".
+fn sort_routes<R, T: Ord>(
+    routes: impl IntoIterator<Item = (String, R)>,
+    timestamp: impl Fn(&R) -> Option<T>,
+) -> Vec<(String, R)> {
+    let mut routes = routes.into_iter().collect::<Vec<_>>();
+    routes.sort_by(|(a_name, a_route), (b_name, b_route)| {
+        let by_ts = match (timestamp(a_route), timestamp(b_route)) {
+            (Some(a_ts), Some(b_ts)) => a_ts.cmp(&b_ts),
+            (None, None) => std::cmp::Ordering::Equal,
+            // Routes with timestamps are preferred over routes without.
+            (Some(_), None) => return std::cmp::Ordering::Less,
+            (None, Some(_)) => return std::cmp::Ordering::Greater,
+        };
+        by_ts.then_with(|| a_name.cmp(b_name))
+    });
+    routes
+}
+
 fn default_balancer_config() -> outbound::backend::balance_p2c::Load {
     outbound::backend::balance_p2c::Load::PeakEwma(outbound::backend::balance_p2c::PeakEwma {
         default_rtt: Some(
@@ -529,3 +1287,144 @@ fn default_queue_config() -> outbound::Queue {
         ),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mk_egress(traffic_policy: TrafficPolicy, opaque: bool) -> OutboundPolicy {
+        OutboundPolicy {
+            http_routes: Default::default(),
+            grpc_routes: Default::default(),
+            tcp_routes: Default::default(),
+            tls_routes: Default::default(),
+            authority: Default::default(),
+            name: "egress".to_string(),
+            namespace: "ns".to_string(),
+            port: 443.try_into().unwrap(),
+            opaque,
+            accrual: None,
+            circuit_breaker: None,
+            retry: None,
+            parent: ParentKind::Egress { traffic_policy },
+        }
+    }
+
+    fn opaque_routes(policy: outbound::OutboundPolicy) -> Vec<outbound::OpaqueRoute> {
+        match policy.protocol.and_then(|p| p.kind) {
+            Some(outbound::proxy_protocol::Kind::Opaque(opaque)) => opaque.routes,
+            kind => panic!("expected opaque protocol, got {kind:?}"),
+        }
+    }
+
+    #[test]
+    fn denied_egress_is_forbidden() {
+        let addr = "192.0.2.10:443".parse().unwrap();
+        let routes = opaque_routes(to_service(mk_egress(TrafficPolicy::Deny, true), Some(addr)));
+        assert_eq!(routes.len(), 1);
+        let rule = &routes[0].rules[0];
+        assert_eq!(
+            rule.filters,
+            vec![outbound::opaque_route::Filter {
+                kind: Some(opaque_filter::Kind::Forbidden(
+                    api::opaque_route::Forbidden {}
+                )),
+            }],
+        );
+
+        // The route still has a valid backend, so that the policy is valid.
+        match rule.backends.clone().and_then(|d| d.kind) {
+            Some(outbound::opaque_route::distribution::Kind::FirstAvailable(first)) => {
+                assert_eq!(first.backends[0].backend, Some(egress_backend(addr)));
+            }
+            kind => panic!("expected a single backend, got {kind:?}"),
+        }
+    }
+
+    #[test]
+    fn allowed_egress_is_forwarded() {
+        let addr = "192.0.2.10:443".parse().unwrap();
+        let routes = opaque_routes(to_service(
+            mk_egress(TrafficPolicy::Allow, true),
+            Some(addr),
+        ));
+        assert_eq!(routes.len(), 1);
+        assert!(routes[0].rules[0].filters.is_empty());
+    }
+
+    #[test]
+    fn service_retries_apply_to_grpc_routes() {
+        let rule = |retry| GrpcRouteRule {
+            matches: vec![],
+            backends: vec![],
+            filters: vec![],
+            retry,
+        };
+        let mut policy = OutboundPolicy {
+            parent: ParentKind::Service,
+            retry: Some(RetryPolicy {
+                max_retries: 2,
+                status_ranges: vec![500..=599],
+                grpc_statuses: vec![GrpcRetryStatus::Unavailable],
+            }),
+            ..mk_egress(TrafficPolicy::Allow, false)
+        };
+        policy.grpc_routes.insert(
+            "default".to_string(),
+            GrpcRoute {
+                hostnames: vec![],
+                rules: vec![rule(None)],
+                creation_timestamp: None,
+            },
+        );
+        policy.grpc_routes.insert(
+            "override".to_string(),
+            GrpcRoute {
+                hostnames: vec![],
+                rules: vec![rule(Some(RetryPolicy {
+                    max_retries: 1,
+                    status_ranges: vec![],
+                    grpc_statuses: vec![GrpcRetryStatus::DeadlineExceeded],
+                }))],
+                creation_timestamp: None,
+            },
+        );
+
+        let routes = match to_service(policy, None).protocol.and_then(|p| p.kind) {
+            Some(outbound::proxy_protocol::Kind::Grpc(grpc)) => grpc.routes,
+            kind => panic!("expected gRPC protocol, got {kind:?}"),
+        };
+        let retry = |name: &str| {
+            routes
+                .iter()
+                .find(|r| {
+                    matches!(
+                        r.metadata.as_ref().and_then(|m| m.kind.as_ref()),
+                        Some(metadata::Kind::Resource(r)) if r.name == name
+                    )
+                })
+                .and_then(|r| r.rules[0].retry.clone())
+                .expect("route must be retried")
+        };
+
+        let default = retry("default");
+        assert_eq!(default.max_retries, 2);
+        assert_eq!(
+            default.conditions,
+            Some(outbound::grpc_route::retry::Conditions {
+                unavailable: true,
+                ..Default::default()
+            })
+        );
+
+        let route = retry("override");
+        assert_eq!(route.max_retries, 1);
+        assert_eq!(
+            route.conditions,
+            Some(outbound::grpc_route::retry::Conditions {
+                deadline_exceeded: true,
+                ..Default::default()
+            })
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use std::{fmt, str::FromStr, time};
+
+/// A duration, as expressed by the Gateway API (GEP-2257).
+///
+/// Durations are strings composed of one to four `<number><unit>` parts,
+/// where the unit is one of `h`, `m`, `s`, or `ms` (e.g. `1h30m`, `500ms`).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Duration(time::Duration);
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum ParseDurationError {
+    #[error("duration must not be empty")]
+    Empty,
+
+    #[error("duration may include at most {MAX_PARTS} parts")]
+    TooManyParts,
+
+    #[error("duration value must have between 1 and {MAX_DIGITS} digits")]
+    InvalidValue,
+
+    #[error("invalid duration unit {0:?} (expected one of 'h', 'm', 's', or 'ms')")]
+    InvalidUnit(String),
+}
+
+const MAX_PARTS: usize = 4;
+const MAX_DIGITS: usize = 5;
+
+// === impl Duration ===
+
+impl Duration {
+    pub fn from_std(d: time::Duration) -> Self {
+        Self(d)
+    }
+
+    pub fn as_std(&self) -> time::Duration {
+        self.0
+    }
+}
+
+impl From<Duration> for time::Duration {
+    fn from(Duration(d): Duration) -> Self {
+        d
+    }
+}
+
+impl FromStr for Duration {
+    type Err = ParseDurationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseDurationError::Empty);
+        }
+
+        let mut total = time::Duration::ZERO;
+        let mut rest = s;
+        let mut parts = 0;
+        while !rest.is_empty() {
+            parts += 1;
+            if parts > MAX_PARTS {
+                return Err(ParseDurationError::TooManyParts);
+            }
+
+            let digits = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            if digits == 0 || digits > MAX_DIGITS {
+                return Err(ParseDurationError::InvalidValue);
+            }
+            let (value, tail) = rest.split_at(digits);
+            let value = value
+                .parse::<u64>()
+                .map_err(|_| ParseDurationError::InvalidValue)?;
+
+            let unit_len = tail
+                .find(|c: char| c.is_ascii_digit())
+                .unwrap_or(tail.len());
+            let (unit, tail) = tail.split_at(unit_len);
+            total += match unit {
+                "h" => time::Duration::from_secs(value * 60 * 60),
+                "m" => time::Duration::from_secs(value * 60),
+                "s" => time::Duration::from_secs(value),
+                "ms" => time::Duration::from_millis(value),
+                unit => return Err(ParseDurationError::InvalidUnit(unit.to_string())),
+            };
+            rest = tail;
+        }
+
+        Ok(Self(total))
+    }
+}
+
+impl fmt::Display for Duration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut ms = self.0.as_millis();
+        if ms == 0 {
+            return f.write_str("0s");
+        }
+
+        for (unit, scale) in [
+            ("h", 60 * 60 * 1000),
+            ("m", 60 * 1000),
+            ("s", 1000),
+            ("ms", 1),
+        ] {
+            if ms >= scale {
+                write!(f, "{}{}", ms / scale, unit)?;
+                ms %= scale;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl serde::Serialize for Duration {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Duration {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl schemars::JsonSchema for Duration {
+    fn schema_name() -> String {
+        "Duration".to_string()
+    }
+
+    fn json_schema(_: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        schemars::schema::SchemaObject {
+            instance_type: Some(schemars::schema::InstanceType::String.into()),
+            string: Some(Box::new(schemars::schema::StringValidation {
+                pattern: Some(r"^([0-9]{1,5}(h|m|s|ms)){1,4}$".to_string()),
+                ..Default::default()
+            })),
+            ..Default::default()
+        }
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_valid() {
+        for (s, expected) in [
+            ("0s", time::Duration::ZERO),
+            ("0h0m0s", time::Duration::ZERO),
+            ("500ms", time::Duration::from_millis(500)),
+            ("10s", time::Duration::from_secs(10)),
+            ("1h30m", time::Duration::from_secs(90 * 60)),
+            ("1h2m3s4ms", time::Duration::from_millis(3_723_004)),
+        ] {
+            assert_eq!(s.parse::<Duration>(), Ok(Duration(expected)), "{s}");
+        }
+    }
+
+    #[test]
+    fn rejects_invalid() {
+        for s in [
+            "",
+            "1",
+            "s",
+            "1d",
+            "1.5s",
+            "-1s",
+            "123456s",
+            "1h1m1s1ms1h",
+            "1 s",
+        ] {
+            assert!(s.parse::<Duration>().is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn roundtrips_display() {
+        for s in ["0s", "500ms", "10s", "1h30m", "1h2m3s4ms"] {
+            assert_eq!(s.parse::<Duration>().unwrap().to_string(), s);
+        }
+    }
+}
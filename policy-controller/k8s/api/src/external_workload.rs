@@ -0,0 +1,70 @@
+use std::num::NonZeroU16;
+
+/// Describes a meshed workload that runs outside of the cluster (e.g. on a
+/// VM).
+///
+/// An `ExternalWorkload`'s labels are selected by policy resources in the same
+/// way as a pod's.
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    kube::CustomResource,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+)]
+#[kube(
+    group = "workload.linkerd.io",
+    version = "v1beta1",
+    kind = "ExternalWorkload",
+    namespaced
+)]
+#[serde(rename_all = "camelCase")]
+pub struct ExternalWorkloadSpec {
+    /// The workload's mesh identity.
+    #[serde(rename = "meshTLS")]
+    pub mesh_tls: MeshTls,
+
+    /// The ports exposed by the workload.
+    pub ports: Option<Vec<PortSpec>>,
+
+    /// The addresses at which the workload may be reached.
+    #[serde(rename = "workloadIPs")]
+    pub workload_ips: Option<Vec<WorkloadIp>>,
+}
+
+#[derive(
+    Clone, Debug, Default, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct MeshTls {
+    /// The TLS identity presented by the workload's proxy.
+    pub identity: String,
+
+    /// The TLS server name that clients use to connect to the workload.
+    pub server_name: String,
+}
+
+/// Describes a port exposed by an external workload.
+#[derive(
+    Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+pub struct PortSpec {
+    /// A name that `Server` resources may use to select the port.
+    pub name: Option<String>,
+
+    pub port: NonZeroU16,
+
+    /// The port's protocol. Defaults to TCP.
+    pub protocol: Option<String>,
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+pub struct WorkloadIp {
+    pub ip: String,
+}
@@ -0,0 +1,20 @@
+//! Gateway API resources.
+//!
+//! Re-exports the types provided by the `k8s-gateway-api` crate, along with
+//! resources that the crate does not (yet) provide.
+
+pub mod grpcroute;
+pub mod referencegrant;
+pub mod tcproute;
+pub mod tlsroute;
+
+pub use self::grpcroute::{
+    GrpcBackendRef, GrpcHeaderMatch, GrpcMethodMatch, GrpcRoute, GrpcRouteFilter, GrpcRouteMatch,
+    GrpcRouteRule, GrpcRouteSpec, GrpcRouteStatus,
+};
+pub use self::referencegrant::{
+    ReferenceGrant, ReferenceGrantFrom, ReferenceGrantSpec, ReferenceGrantTo,
+};
+pub use self::tcproute::{TcpRoute, TcpRouteRule, TcpRouteSpec, TcpRouteStatus};
+pub use self::tlsroute::{TlsRoute, TlsRouteRule, TlsRouteSpec, TlsRouteStatus};
+pub use k8s_gateway_api::*;
@@ -0,0 +1,173 @@
+use k8s_gateway_api::{
+    BackendRef, CommonRouteSpec, Hostname, HttpHeaderMatch, HttpRequestHeaderFilter,
+    HttpRequestMirrorFilter, LocalObjectReference, RouteStatus,
+};
+
+/// GRPCRoute provides a way to route gRPC requests. This includes the
+/// capability to match requests by hostname, gRPC service, gRPC method, or
+/// HTTP/2 header. Filters can be used to specify additional processing steps.
+/// Backends specify where matching requests will be routed.
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    kube::CustomResource,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+)]
+#[kube(
+    group = "gateway.networking.k8s.io",
+    version = "v1alpha2",
+    kind = "GRPCRoute",
+    struct = "GrpcRoute",
+    status = "GrpcRouteStatus",
+    namespaced
+)]
+pub struct GrpcRouteSpec {
+    /// Common route information.
+    #[serde(flatten)]
+    pub inner: CommonRouteSpec,
+
+    /// Hostnames defines a set of hostnames to match against the GRPC Host
+    /// header to select a GRPCRoute to process the request. Hostnames follow
+    /// the same rules as HTTPRoute hostnames.
+    pub hostnames: Option<Vec<Hostname>>,
+
+    /// Rules are a list of gRPC matchers, filters and actions.
+    pub rules: Option<Vec<GrpcRouteRule>>,
+}
+
+/// GRPCRouteRule defines the semantics for matching a gRPC request based on
+/// conditions (matches), processing it (filters), and forwarding the request to
+/// an API object (backendRefs).
+#[derive(
+    Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GrpcRouteRule {
+    /// Matches define conditions used for matching the rule against incoming
+    /// gRPC requests. Each match is independent, i.e. this rule will be matched
+    /// if **any** one of the matches is satisfied.
+    ///
+    /// If no matches are specified, the implementation MUST match every gRPC
+    /// request.
+    pub matches: Option<Vec<GrpcRouteMatch>>,
+
+    /// Filters define the filters that are applied to requests that match this
+    /// rule.
+    pub filters: Option<Vec<GrpcRouteFilter>>,
+
+    /// BackendRefs defines the backend(s) where matching requests should be
+    /// sent.
+    pub backend_refs: Option<Vec<GrpcBackendRef>>,
+}
+
+/// GRPCRouteMatch defines the predicate used to match requests to a given
+/// action. Multiple match types are ANDed together, i.e. the match will
+/// evaluate to true only if all conditions are satisfied.
+#[derive(
+    Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GrpcRouteMatch {
+    /// Method specifies a gRPC request service/method matcher. If this field is
+    /// not specified, all services and methods will match.
+    pub method: Option<GrpcMethodMatch>,
+
+    /// Headers specifies gRPC request header matchers. Multiple match values
+    /// are ANDed together, meaning, a request MUST match all the specified
+    /// headers to select the route.
+    pub headers: Option<Vec<GrpcHeaderMatch>>,
+}
+
+/// GRPCMethodMatch describes how to select a gRPC route by matching the gRPC
+/// request service and/or method.
+///
+/// At least one of Service and Method MUST be a non-empty string.
+#[derive(
+    Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+#[serde(tag = "type", rename_all = "PascalCase")]
+pub enum GrpcMethodMatch {
+    #[serde(rename_all = "camelCase")]
+    Exact {
+        /// Value of the service to match against. If left empty or omitted,
+        /// will match any service.
+        service: Option<String>,
+
+        /// Value of the method to match against. If left empty or omitted,
+        /// will match all methods.
+        method: Option<String>,
+    },
+
+    #[serde(rename_all = "camelCase")]
+    RegularExpression {
+        /// Regular expression that the service must match.
+        service: Option<String>,
+
+        /// Regular expression that the method must match.
+        method: Option<String>,
+    },
+}
+
+/// GRPCHeaderMatch describes how to select a gRPC route by matching gRPC
+/// request headers.
+pub type GrpcHeaderMatch = HttpHeaderMatch;
+
+/// GRPCRouteFilter defines processing steps that must be completed during the
+/// request or response lifecycle.
+#[derive(
+    Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+#[serde(tag = "type", rename_all = "PascalCase")]
+pub enum GrpcRouteFilter {
+    /// RequestHeaderModifier defines a schema for a filter that modifies request
+    /// headers.
+    ///
+    /// Support: Core
+    #[serde(rename_all = "camelCase")]
+    RequestHeaderModifier {
+        request_header_modifier: HttpRequestHeaderFilter,
+    },
+
+    /// RequestMirror defines a schema for a filter that mirrors requests.
+    /// Requests are sent to the specified destination, but responses from that
+    /// destination are ignored.
+    ///
+    /// Support: Extended
+    #[serde(rename_all = "camelCase")]
+    RequestMirror {
+        request_mirror: HttpRequestMirrorFilter,
+    },
+
+    /// ExtensionRef is an optional, implementation-specific extension to the
+    /// "filter" behavior.
+    ///
+    /// Support: Implementation-specific
+    #[serde(rename_all = "camelCase")]
+    ExtensionRef { extension_ref: LocalObjectReference },
+}
+
+/// GRPCBackendRef defines how a GRPCRoute forwards a gRPC request.
+#[derive(
+    Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct GrpcBackendRef {
+    /// BackendRef is a reference to a backend to forward matched requests to.
+    #[serde(flatten)]
+    pub backend_ref: Option<BackendRef>,
+
+    /// Filters defined at this level MUST be executed if and only if the
+    /// request is being forwarded to the backend defined here.
+    pub filters: Option<Vec<GrpcRouteFilter>>,
+}
+
+/// GRPCRouteStatus defines the observed state of GRPCRoute.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub struct GrpcRouteStatus {
+    /// Common route status information.
+    #[serde(flatten)]
+    pub inner: RouteStatus,
+}
@@ -0,0 +1,51 @@
+use k8s_gateway_api::{BackendRef, CommonRouteSpec, RouteStatus};
+
+/// TCPRoute provides a way to route TCP requests. When combined with a Gateway
+/// listener, it can be used to forward connections on the port specified by
+/// the listener to a set of backends specified by the TCPRoute.
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    kube::CustomResource,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+)]
+#[kube(
+    group = "gateway.networking.k8s.io",
+    version = "v1alpha2",
+    kind = "TCPRoute",
+    struct = "TcpRoute",
+    status = "TcpRouteStatus",
+    namespaced
+)]
+pub struct TcpRouteSpec {
+    /// Common route information.
+    #[serde(flatten)]
+    pub inner: CommonRouteSpec,
+
+    /// Rules are a list of TCP matchers and actions.
+    pub rules: Vec<TcpRouteRule>,
+}
+
+/// TCPRouteRule is the configuration for a given rule.
+#[derive(
+    Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct TcpRouteRule {
+    /// BackendRefs defines the backend(s) where matching connections should
+    /// be sent. If unspecified or invalid (refers to a non-existent resource
+    /// or a Service with no endpoints), the underlying implementation MUST
+    /// actively reject connection attempts to this backend.
+    pub backend_refs: Vec<BackendRef>,
+}
+
+/// TCPRouteStatus defines the observed state of TCPRoute.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub struct TcpRouteStatus {
+    /// Common route status information.
+    #[serde(flatten)]
+    pub inner: RouteStatus,
+}
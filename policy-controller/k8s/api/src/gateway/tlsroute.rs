@@ -0,0 +1,59 @@
+use k8s_gateway_api::{BackendRef, CommonRouteSpec, Hostname, RouteStatus};
+
+/// The TLSRoute resource is similar to TCPRoute, but can be configured to
+/// match against TLS-specific metadata. This allows more flexibility in
+/// matching streams for a given TLS listener.
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    kube::CustomResource,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+)]
+#[kube(
+    group = "gateway.networking.k8s.io",
+    version = "v1alpha2",
+    kind = "TLSRoute",
+    struct = "TlsRoute",
+    status = "TlsRouteStatus",
+    namespaced
+)]
+pub struct TlsRouteSpec {
+    /// Common route information.
+    #[serde(flatten)]
+    pub inner: CommonRouteSpec,
+
+    /// Hostnames defines a set of SNI names that should match against the SNI
+    /// attribute of TLS ClientHello message in TLS handshake. This matches the
+    /// RFC 1123 definition of a hostname with 2 notable exceptions:
+    ///
+    /// 1. IPs are not allowed in SNI names per RFC 6066.
+    /// 2. A hostname may be prefixed with a wildcard label (`*.`). The wildcard
+    ///    label must appear by itself as the first label.
+    pub hostnames: Option<Vec<Hostname>>,
+
+    /// Rules are a list of TLS matchers and actions.
+    pub rules: Vec<TlsRouteRule>,
+}
+
+/// TLSRouteRule is the configuration for a given rule.
+#[derive(
+    Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct TlsRouteRule {
+    /// BackendRefs defines the backend(s) where matching connections should
+    /// be sent. If unspecified or invalid (refers to a non-existent resource
+    /// or a Service with no endpoints), the rule performs no forwarding.
+    pub backend_refs: Vec<BackendRef>,
+}
+
+/// TLSRouteStatus defines the observed state of TLSRoute.
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
+pub struct TlsRouteStatus {
+    /// Common route status information.
+    #[serde(flatten)]
+    pub inner: RouteStatus,
+}
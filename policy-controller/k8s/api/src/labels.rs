@@ -88,6 +88,68 @@ impl Selector {
 
         true
     }
+
+    /// Determines whether there can be a set of labels that is matched by both
+    /// this selector and `other`.
+    ///
+    /// Returns `None` if either selector includes an illegal expression (e.g.
+    /// an `In` expression without values), in which case overlap cannot be
+    /// determined from the selectors alone.
+    pub fn overlaps(&self, other: &Self) -> Option<bool> {
+        // Collects the requirements that both selectors place on each label.
+        #[derive(Default)]
+        struct Requirement<'s> {
+            exists: bool,
+            not_exists: bool,
+            in_values: Option<BTreeSet<&'s str>>,
+            not_in_values: BTreeSet<&'s str>,
+        }
+
+        impl<'s> Requirement<'s> {
+            fn restrict(&mut self, values: impl IntoIterator<Item = &'s str>) {
+                let values = values.into_iter().collect::<BTreeSet<_>>();
+                self.exists = true;
+                self.in_values = Some(match self.in_values.take() {
+                    None => values,
+                    Some(prior) => prior.intersection(&values).copied().collect(),
+                });
+            }
+
+            fn is_satisfiable(&self) -> bool {
+                if self.not_exists {
+                    return !self.exists;
+                }
+                match self.in_values.as_ref() {
+                    None => true,
+                    Some(values) => values.iter().any(|v| !self.not_in_values.contains(v)),
+                }
+            }
+        }
+
+        let mut reqs = BTreeMap::<&str, Requirement<'_>>::new();
+        for selector in [self, other] {
+            for (k, v) in selector.match_labels.iter().flatten() {
+                reqs.entry(k.as_str())
+                    .or_default()
+                    .restrict(Some(v.as_str()));
+            }
+
+            for expr in selector.match_expressions.iter().flatten() {
+                let req = reqs.entry(expr.key.as_str()).or_default();
+                match (expr.operator, expr.values.as_ref()) {
+                    (Operator::In, Some(values)) => req.restrict(values.iter().map(|v| v.as_str())),
+                    (Operator::NotIn, Some(values)) => {
+                        req.not_in_values.extend(values.iter().map(|v| v.as_str()))
+                    }
+                    (Operator::Exists, None) => req.exists = true,
+                    (Operator::DoesNotExist, None) => req.not_exists = true,
+                    _ => return None,
+                }
+            }
+        }
+
+        Some(reqs.values().all(Requirement::is_satisfiable))
+    }
 }
 
 impl std::iter::FromIterator<(String, String)> for Selector {
@@ -270,4 +332,96 @@ mod tests {
             assert_eq!(selector.matches(labels), *matches, "{}", msg);
         }
     }
+
+    #[test]
+    fn test_overlaps() {
+        fn expr(key: &str, operator: Operator, values: &[&str]) -> Expression {
+            Expression {
+                key: key.to_string(),
+                operator,
+                values: match operator {
+                    Operator::In | Operator::NotIn => {
+                        Some(values.iter().map(|v| v.to_string()).collect())
+                    }
+                    Operator::Exists | Operator::DoesNotExist => None,
+                },
+            }
+        }
+
+        for (left, right, overlaps, msg) in [
+            (
+                Selector::default(),
+                Selector::from_iter(Some(("foo", "bar"))),
+                Some(true),
+                "empty selector",
+            ),
+            (
+                Selector::from_iter(Some(("foo", "bar"))),
+                Selector::from_iter(Some(("bah", "baz"))),
+                Some(true),
+                "different labels",
+            ),
+            (
+                Selector::from_iter(Some(("foo", "bar"))),
+                Selector::from_iter(Some(("foo", "baz"))),
+                Some(false),
+                "conflicting label values",
+            ),
+            (
+                Selector::from_iter(Some(("foo", "bar"))),
+                Selector::from_iter(Some(expr("foo", Operator::In, &["bar", "baz"]))),
+                Some(true),
+                "label in values",
+            ),
+            (
+                Selector::from_iter(Some(("foo", "bar"))),
+                Selector::from_iter(Some(expr("foo", Operator::NotIn, &["bar"]))),
+                Some(false),
+                "label not in values",
+            ),
+            (
+                Selector::from_iter(Some(expr("foo", Operator::In, &["bar", "baz"]))),
+                Selector::from_iter(Some(expr("foo", Operator::NotIn, &["bar"]))),
+                Some(true),
+                "in and not in with remaining value",
+            ),
+            (
+                Selector::from_iter(Some(expr("foo", Operator::In, &["bar"]))),
+                Selector::from_iter(Some(expr("foo", Operator::In, &["baz"]))),
+                Some(false),
+                "disjoint in values",
+            ),
+            (
+                Selector::from_iter(Some(expr("foo", Operator::Exists, &[]))),
+                Selector::from_iter(Some(expr("foo", Operator::DoesNotExist, &[]))),
+                Some(false),
+                "exists and does not exist",
+            ),
+            (
+                Selector::from_iter(Some(("foo", "bar"))),
+                Selector::from_iter(Some(expr("foo", Operator::DoesNotExist, &[]))),
+                Some(false),
+                "label does not exist",
+            ),
+            (
+                Selector::from_iter(Some(expr("foo", Operator::NotIn, &["bar"]))),
+                Selector::from_iter(Some(expr("foo", Operator::DoesNotExist, &[]))),
+                Some(true),
+                "not in and does not exist",
+            ),
+            (
+                Selector::from_iter(Some(("foo", "bar"))),
+                Selector::from_iter(Some(Expression {
+                    key: "foo".to_string(),
+                    operator: Operator::In,
+                    values: None,
+                })),
+                None,
+                "illegal expression",
+            ),
+        ] {
+            assert_eq!(left.overlaps(&right), overlaps, "{}", msg);
+            assert_eq!(right.overlaps(&left), overlaps, "{} (reversed)", msg);
+        }
+    }
 }
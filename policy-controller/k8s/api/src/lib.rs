@@ -1,11 +1,17 @@
 #![deny(warnings, rust_2018_idioms)]
 #![forbid(unsafe_code)]
 
+pub mod duration;
+pub mod external_workload;
+pub mod gateway;
 pub mod labels;
 pub mod policy;
 
-pub use self::labels::Labels;
-pub use k8s_gateway_api as gateway;
+pub use self::{
+    duration::Duration,
+    external_workload::{ExternalWorkload, ExternalWorkloadSpec},
+    labels::Labels,
+};
 pub use k8s_openapi::{
     api::{
         self,
@@ -14,6 +20,7 @@ pub use k8s_openapi::{
             Container, ContainerPort, HTTPGetAction, Namespace, Node, NodeSpec, Pod, PodSpec,
             PodStatus, Probe, Service, ServiceAccount, ServicePort, ServiceSpec,
         },
+        discovery::v1::EndpointSlice,
     },
     apimachinery::{
         self,
@@ -24,7 +31,10 @@ pub use k8s_openapi::{
     },
 };
 pub use kube::{
-    api::{Api, ListParams, ObjectMeta, Patch, PatchParams, Resource, ResourceExt},
+    api::{
+        Api, ApiResource, DynamicObject, ListParams, ObjectMeta, Patch, PatchParams, Resource,
+        ResourceExt,
+    },
     error::ErrorResponse,
     runtime::watcher::Event as WatchEvent,
     Client, Error,
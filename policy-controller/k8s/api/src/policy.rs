@@ -1,24 +1,28 @@
 pub mod authorization_policy;
+pub mod egress_network;
 pub mod httproute;
 pub mod meshtls_authentication;
 mod network;
 pub mod network_authentication;
+pub mod ratelimit_policy;
 pub mod server;
 pub mod server_authorization;
 pub mod target_ref;
 
 pub use self::{
     authorization_policy::{AuthorizationPolicy, AuthorizationPolicySpec},
+    egress_network::{EgressNetwork, EgressNetworkSpec},
     httproute::{HttpRoute, HttpRouteSpec},
     meshtls_authentication::{MeshTLSAuthentication, MeshTLSAuthenticationSpec},
     network::Network,
     network_authentication::{NetworkAuthentication, NetworkAuthenticationSpec},
+    ratelimit_policy::{HttpLocalRateLimitPolicy, RateLimitPolicySpec},
     server::{Server, ServerSpec},
     server_authorization::{ServerAuthorization, ServerAuthorizationSpec},
     target_ref::{ClusterTargetRef, LocalTargetRef, NamespacedTargetRef},
 };
 
-fn targets_kind<T>(group: Option<&str>, kind: &str) -> bool
+pub(crate) fn targets_kind<T>(group: Option<&str>, kind: &str) -> bool
 where
     T: kube::Resource,
     T::DynamicType: Default,
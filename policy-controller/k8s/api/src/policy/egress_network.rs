@@ -0,0 +1,68 @@
+pub use super::Network;
+
+/// Describes a set of destinations outside of the cluster.
+///
+/// An `EgressNetwork` applies to outbound traffic from workloads in its own
+/// namespace. Routes may attach to it to configure the traffic it matches.
+#[derive(
+    Clone,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    kube::CustomResource,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+)]
+#[kube(
+    group = "policy.linkerd.io",
+    version = "v1alpha1",
+    kind = "EgressNetwork",
+    namespaced
+)]
+#[serde(rename_all = "camelCase")]
+pub struct EgressNetworkSpec {
+    /// DNS names matched by this resource. A name may start with `*.` to
+    /// match all of its subdomains.
+    pub hostnames: Option<Vec<String>>,
+
+    /// IP networks matched by this resource.
+    pub networks: Option<Vec<Network>>,
+
+    /// Determines whether traffic that is not matched by an attached route is
+    /// allowed or denied. Defaults to `Allow`.
+    #[serde(default)]
+    pub traffic_policy: TrafficPolicy,
+}
+
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    schemars::JsonSchema,
+)]
+pub enum TrafficPolicy {
+    #[default]
+    Allow,
+    Deny,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn traffic_policy_defaults_to_allow() {
+        let spec = serde_json::from_value::<EgressNetworkSpec>(serde_json::json!({
+            "networks": [{ "cidr": "0.0.0.0/0" }],
+        }))
+        .expect("spec must deserialize without a traffic policy");
+        assert_eq!(spec.traffic_policy, TrafficPolicy::Allow);
+    }
+}
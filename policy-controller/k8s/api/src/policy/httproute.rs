@@ -155,6 +155,38 @@ pub struct HttpRouteRule {
     ///
     /// Support for weight: Core
     pub backend_refs: Option<Vec<HttpBackendRef>>,
+
+    /// Timeouts defines the timeouts that can be configured for an HTTP
+    /// request.
+    ///
+    /// Support: Extended
+    pub timeouts: Option<HttpRouteTimeouts>,
+}
+
+/// HTTPRouteTimeouts defines timeouts that can be configured for an HTTPRoute.
+/// Timeout values are represented with Gateway API Duration formatting.
+/// Specifying a zero value such as "0s" is interpreted as no timeout.
+#[derive(
+    Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpRouteTimeouts {
+    /// Request specifies the maximum duration for a gateway to respond to an
+    /// HTTP request. If the gateway has not been able to respond before this
+    /// deadline is met, the gateway MUST return a timeout error.
+    ///
+    /// This timeout is intended to cover as close to the whole request-response
+    /// transaction as possible, including any retries.
+    pub request: Option<crate::Duration>,
+
+    /// BackendRequest specifies a timeout for an individual request from the
+    /// gateway to a backend. This covers the time from when the request first
+    /// starts being sent from the gateway to when the full response has been
+    /// received from the backend.
+    ///
+    /// When this field is set, it MUST be less than or equal to the value of
+    /// the `request` timeout.
+    pub backend_request: Option<crate::Duration>,
 }
 
 /// HTTPRouteFilter defines processing steps that must be completed during the
@@ -177,6 +209,15 @@ pub enum HttpRouteFilter {
         request_header_modifier: HttpRequestHeaderFilter,
     },
 
+    /// ResponseHeaderModifier defines a schema for a filter that modifies
+    /// response headers.
+    ///
+    /// Support: Extended
+    #[serde(rename_all = "camelCase")]
+    ResponseHeaderModifier {
+        response_header_modifier: HttpRequestHeaderFilter,
+    },
+
     /// RequestRedirect defines a schema for a filter that responds to the
     /// request with an HTTP redirection.
     ///
@@ -185,6 +226,46 @@ pub enum HttpRouteFilter {
     RequestRedirect {
         request_redirect: HttpRequestRedirectFilter,
     },
+
+    /// RequestFailureInjector defines a schema for a filter that fails a
+    /// ratio of requests with a synthetic response.
+    ///
+    /// Support: Custom
+    #[serde(rename_all = "camelCase")]
+    RequestFailureInjector {
+        request_failure_injector: HttpRequestFailureInjectorFilter,
+    },
+}
+
+/// HTTPRequestFailureInjectorFilter defines configuration for the
+/// RequestFailureInjector filter.
+#[derive(
+    Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpRequestFailureInjectorFilter {
+    /// Status is the HTTP status code of the synthetic response. Defaults to
+    /// 500.
+    pub status: Option<u16>,
+
+    /// Message is included in the body of the synthetic response.
+    pub message: Option<String>,
+
+    /// Ratio is the ratio of requests that should fail. Defaults to failing
+    /// all requests.
+    pub ratio: Option<Ratio>,
+}
+
+/// Ratio defines a fraction of requests as `numerator / denominator`.
+#[derive(
+    Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct Ratio {
+    pub numerator: u32,
+
+    /// Defaults to 100.
+    pub denominator: Option<u32>,
 }
 
 /// HTTPRouteStatus defines the observed state of HTTPRoute.
@@ -0,0 +1,47 @@
+use super::{LocalTargetRef, NamespacedTargetRef};
+
+#[derive(
+    Clone, Debug, kube::CustomResource, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+#[kube(
+    group = "policy.linkerd.io",
+    version = "v1alpha1",
+    kind = "HTTPLocalRateLimitPolicy",
+    struct = "HttpLocalRateLimitPolicy",
+    namespaced
+)]
+#[serde(rename_all = "camelCase")]
+pub struct RateLimitPolicySpec {
+    /// Identifies the `Server` to which the rate limit applies.
+    pub target_ref: LocalTargetRef,
+
+    /// Limits the overall rate of requests handled by each proxy.
+    pub total: Option<Limit>,
+
+    /// Limits the rate of requests handled by each proxy for each distinct
+    /// client identity.
+    pub identity: Option<Limit>,
+
+    /// Overrides the per-identity limit for a set of clients.
+    pub overrides: Option<Vec<Override>>,
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct Limit {
+    pub requests_per_second: u32,
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct Override {
+    pub requests_per_second: u32,
+
+    /// References the `ServiceAccount`s whose clients are subject to this
+    /// limit.
+    pub client_refs: Vec<NamespacedTargetRef>,
+}
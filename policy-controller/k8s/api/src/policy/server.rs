@@ -4,7 +4,8 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::{fmt, num::NonZeroU16};
 
-/// Describes a server interface exposed by a set of pods.
+/// Describes a server interface exposed by a set of pods (or external
+/// workloads, which `pod_selector` selects by their labels).
 #[derive(Clone, Debug, PartialEq, Eq, CustomResource, Deserialize, Serialize, JsonSchema)]
 #[kube(
     group = "policy.linkerd.io",
@@ -19,12 +20,18 @@ pub struct ServerSpec {
     pub proxy_protocol: Option<ProxyProtocol>,
 }
 
-/// References a pod spec's port by name or number.
-#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize, JsonSchema)]
+/// References a pod spec's port by name or number, or a set of ports.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, JsonSchema)]
 #[serde(untagged)]
 pub enum Port {
     Number(NonZeroU16),
     Name(String),
+    /// A comma-separated list of port numbers and port ranges, e.g.
+    /// `8080,9000-9100`.
+    ///
+    /// Port names must contain a letter, so strings without letters are read
+    /// as port sets.
+    Set(String),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
@@ -43,11 +50,31 @@ pub enum ProxyProtocol {
     Tls,
 }
 
+impl<'de> Deserialize<'de> for Port {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum IntOrString {
+            Int(NonZeroU16),
+            String(String),
+        }
+
+        match IntOrString::deserialize(deserializer)? {
+            IntOrString::Int(n) => Ok(Port::Number(n)),
+            IntOrString::String(s) if s.chars().any(|c| c.is_ascii_alphabetic()) => {
+                Ok(Port::Name(s))
+            }
+            IntOrString::String(s) => Ok(Port::Set(s)),
+        }
+    }
+}
+
 impl fmt::Display for Port {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Port::Number(n) => fmt::Display::fmt(n, f),
             Port::Name(n) => fmt::Display::fmt(n, f),
+            Port::Set(s) => fmt::Display::fmt(s, f),
         }
     }
 }
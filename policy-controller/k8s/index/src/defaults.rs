@@ -1,7 +1,7 @@
 use ahash::AHashMap as HashMap;
 use anyhow::{anyhow, Error, Result};
 use linkerd_policy_controller_core::{
-    inbound::{AuthorizationRef, ClientAuthentication, ClientAuthorization},
+    inbound::{AuthorizationAction, AuthorizationRef, ClientAuthentication, ClientAuthorization},
     IdentityMatch, IpNet,
 };
 use std::hash::Hash;
@@ -21,6 +21,10 @@ pub enum DefaultPolicy {
 
     /// Indicates that all traffic is denied unless explicitly permitted by an authorization policy.
     Deny,
+
+    /// Indicates that traffic that would be denied is permitted, but flagged by the proxy. This
+    /// allows the effects of a `deny` policy to be observed before it is enforced.
+    Audit,
 }
 
 // === impl DefaultPolicy ===
@@ -47,6 +51,7 @@ impl std::str::FromStr for DefaultPolicy {
                 cluster_only: true,
             }),
             "deny" => Ok(Self::Deny),
+            "audit" => Ok(Self::Audit),
             s => Err(anyhow!("invalid mode: {:?}", s)),
         }
     }
@@ -72,6 +77,7 @@ impl DefaultPolicy {
                 cluster_only: true,
             } => "cluster-unauthenticated",
             Self::Deny => "deny",
+            Self::Audit => "audit",
         }
     }
 
@@ -80,6 +86,19 @@ impl DefaultPolicy {
         config: &ClusterInfo,
     ) -> HashMap<AuthorizationRef, ClientAuthorization> {
         let mut authzs = HashMap::default();
+        if let DefaultPolicy::Audit = self {
+            authzs.insert(
+                AuthorizationRef::Default(self.as_str()),
+                ClientAuthorization {
+                    authentication: ClientAuthentication::Unauthenticated,
+                    networks: vec![
+                        "0.0.0.0/0".parse::<IpNet>().unwrap().into(),
+                        "::/0".parse::<IpNet>().unwrap().into(),
+                    ],
+                    action: AuthorizationAction::Audit,
+                },
+            );
+        }
         if let DefaultPolicy::Allow {
             authenticated_only,
             cluster_only,
@@ -103,6 +122,7 @@ impl DefaultPolicy {
                 ClientAuthorization {
                     authentication,
                     networks,
+                    action: AuthorizationAction::Allow,
                 },
             );
         };
@@ -124,6 +144,7 @@ mod test {
     fn test_parse_displayed() {
         for default in [
             DefaultPolicy::Deny,
+            DefaultPolicy::Audit,
             DefaultPolicy::Allow {
                 authenticated_only: true,
                 cluster_only: false,
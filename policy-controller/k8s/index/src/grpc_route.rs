@@ -0,0 +1,35 @@
+use crate::http_route;
+use anyhow::{bail, Result};
+use linkerd_policy_controller_core::grpc_route;
+use linkerd_policy_controller_k8s_api::gateway as api;
+
+pub fn try_match(
+    api::GrpcRouteMatch { method, headers }: api::GrpcRouteMatch,
+) -> Result<grpc_route::GrpcRouteMatch> {
+    let method = method.map(method_match).transpose()?;
+
+    let headers = headers
+        .into_iter()
+        .flatten()
+        .map(http_route::header_match)
+        .collect::<Result<_>>()?;
+
+    Ok(grpc_route::GrpcRouteMatch { method, headers })
+}
+
+pub fn method_match(method_match: api::GrpcMethodMatch) -> Result<grpc_route::GrpcMethodMatch> {
+    match method_match {
+        api::GrpcMethodMatch::Exact { service, method } => {
+            // Empty values match any service or method.
+            let service = service.filter(|s| !s.is_empty());
+            let method = method.filter(|m| !m.is_empty());
+            if service.is_none() && method.is_none() {
+                bail!("GRPCMethodMatch must specify at least one of service or method");
+            }
+            Ok(grpc_route::GrpcMethodMatch { service, method })
+        }
+        api::GrpcMethodMatch::RegularExpression { .. } => {
+            bail!("GRPCMethodMatch regular expressions are not supported")
+        }
+    }
+}
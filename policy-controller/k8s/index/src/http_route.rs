@@ -1,7 +1,10 @@
 use anyhow::{anyhow, bail, Result};
 use k8s_gateway_api as api;
 use linkerd_policy_controller_core::http_route;
-use std::num::NonZeroU16;
+use linkerd_policy_controller_k8s_api::policy::httproute::{
+    HttpRequestFailureInjectorFilter, HttpRouteTimeouts, Ratio,
+};
+use std::{num::NonZeroU16, time};
 
 pub fn try_match(
     api::HttpRouteMatch {
@@ -93,10 +96,10 @@ pub fn query_param_match(
     }
 }
 
-pub fn req_header_modifier(
+pub fn header_modifier(
     api::HttpRequestHeaderFilter { set, add, remove }: api::HttpRequestHeaderFilter,
-) -> Result<http_route::RequestHeaderModifierFilter> {
-    Ok(http_route::RequestHeaderModifierFilter {
+) -> Result<http_route::HeaderModifierFilter> {
+    Ok(http_route::HeaderModifierFilter {
         add: add
             .into_iter()
             .flatten()
@@ -135,6 +138,77 @@ pub fn req_redirect(
     })
 }
 
+pub fn failure_injector(
+    HttpRequestFailureInjectorFilter {
+        status,
+        message,
+        ratio,
+    }: HttpRequestFailureInjectorFilter,
+) -> Result<http_route::FailureInjectorFilter> {
+    let status = match status {
+        Some(status) => http_route::StatusCode::from_u16(status)?,
+        None => http_route::StatusCode::INTERNAL_SERVER_ERROR,
+    };
+
+    let ratio = match ratio {
+        Some(Ratio {
+            numerator,
+            denominator,
+        }) => {
+            let denominator = denominator.unwrap_or(100);
+            if denominator == 0 {
+                bail!("RequestFailureInjector ratio denominator must be greater than 0");
+            }
+            if numerator > denominator {
+                bail!(
+                    "RequestFailureInjector ratio numerator ({numerator}) must not exceed \
+                        the denominator ({denominator})"
+                );
+            }
+            http_route::Ratio {
+                numerator,
+                denominator,
+            }
+        }
+        None => http_route::Ratio {
+            numerator: 1,
+            denominator: 1,
+        },
+    };
+
+    Ok(http_route::FailureInjectorFilter {
+        status,
+        message: message.unwrap_or_default(),
+        ratio,
+    })
+}
+
+pub fn timeouts(
+    HttpRouteTimeouts {
+        request,
+        backend_request,
+    }: HttpRouteTimeouts,
+) -> Result<http_route::RouteTimeouts> {
+    // A zero-valued timeout disables the timeout.
+    let request = request.map(time::Duration::from).filter(|d| !d.is_zero());
+    let backend_request = backend_request
+        .map(time::Duration::from)
+        .filter(|d| !d.is_zero());
+
+    if let (Some(request), Some(backend_request)) = (request, backend_request) {
+        if backend_request > request {
+            bail!(
+                "backendRequest timeout ({backend_request:?}) must not exceed request timeout ({request:?})"
+            );
+        }
+    }
+
+    Ok(http_route::RouteTimeouts {
+        request,
+        backend_request,
+    })
+}
+
 fn path_modifier(path_modifier: api::HttpPathModifier) -> Result<http_route::PathModifier> {
     use api::HttpPathModifier::*;
     match path_modifier {
@@ -1,13 +1,16 @@
 pub mod authorization_policy;
+mod grpc_route;
 mod http_route;
 pub mod index;
 mod meshtls_authentication;
 mod network_authentication;
 mod pod;
+pub mod ratelimit_policy;
 mod server;
 pub mod server_authorization;
 
 pub use index::{Index, SharedIndex};
+pub use pod::tcp_ports_by_name;
 
 #[cfg(test)]
 mod tests;
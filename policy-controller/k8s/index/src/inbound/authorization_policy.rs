@@ -14,6 +14,7 @@ pub(crate) struct Spec {
 #[derive(Debug, PartialEq)]
 pub(crate) enum Target {
     HttpRoute(String),
+    GrpcRoute(String),
     Server(String),
     Namespace,
 }
@@ -64,6 +65,7 @@ fn target(t: LocalTargetRef) -> Result<Target> {
         t if t.targets_kind::<k8s::policy::Server>() => Ok(Target::Server(t.name)),
         t if t.targets_kind::<k8s::Namespace>() => Ok(Target::Namespace),
         t if t.targets_kind::<k8s::policy::HttpRoute>() => Ok(Target::HttpRoute(t.name)),
+        t if t.targets_kind::<k8s::gateway::GrpcRoute>() => Ok(Target::GrpcRoute(t.name)),
         _ => anyhow::bail!(
             "unsupported authorization target type: {}",
             t.canonical_kind()
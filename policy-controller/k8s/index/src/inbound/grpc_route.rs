@@ -0,0 +1,85 @@
+use super::http_route::{ParentRef, RouteBinding, Status};
+use crate::{grpc_route, http_route};
+use ahash::AHashMap as HashMap;
+use anyhow::{bail, Error, Result};
+use linkerd_policy_controller_core::inbound::{Filter, GrpcRoute, GrpcRouteRule};
+use linkerd_policy_controller_k8s_api::{self as k8s, gateway as api};
+
+impl TryFrom<api::GrpcRoute> for RouteBinding<GrpcRoute> {
+    type Error = Error;
+
+    fn try_from(route: api::GrpcRoute) -> Result<Self, Self::Error> {
+        let route_ns = route.metadata.namespace.as_deref();
+        let creation_timestamp = route.metadata.creation_timestamp.map(|k8s::Time(t)| t);
+        let parents = ParentRef::collect_from(route_ns, route.spec.inner.parent_refs)?;
+        let hostnames = route
+            .spec
+            .hostnames
+            .into_iter()
+            .flatten()
+            .map(http_route::host_match)
+            .collect();
+
+        let rules = route
+            .spec
+            .rules
+            .into_iter()
+            .flatten()
+            .map(try_rule)
+            .collect::<Result<_>>()?;
+
+        let statuses = route
+            .status
+            .map_or_else(Vec::new, |status| Status::collect_from(status.inner));
+
+        Ok(RouteBinding {
+            parents,
+            route: GrpcRoute {
+                hostnames,
+                rules,
+                authorizations: HashMap::default(),
+                creation_timestamp,
+            },
+            statuses,
+        })
+    }
+}
+
+fn try_rule(
+    api::GrpcRouteRule {
+        matches, filters, ..
+    }: api::GrpcRouteRule,
+) -> Result<GrpcRouteRule> {
+    let matches = matches
+        .into_iter()
+        .flatten()
+        .map(grpc_route::try_match)
+        .collect::<Result<_>>()?;
+
+    let filters = filters
+        .into_iter()
+        .flatten()
+        .map(try_filter)
+        .collect::<Result<_>>()?;
+
+    Ok(GrpcRouteRule { matches, filters })
+}
+
+fn try_filter(filter: api::GrpcRouteFilter) -> Result<Filter> {
+    let filter = match filter {
+        api::GrpcRouteFilter::RequestHeaderModifier {
+            request_header_modifier,
+        } => {
+            let filter = http_route::header_modifier(request_header_modifier)?;
+            Filter::RequestHeaderModifier(filter)
+        }
+
+        api::GrpcRouteFilter::RequestMirror { .. } => {
+            bail!("RequestMirror filter is not supported")
+        }
+        api::GrpcRouteFilter::ExtensionRef { .. } => {
+            bail!("ExtensionRef filter is not supported")
+        }
+    };
+    Ok(filter)
+}
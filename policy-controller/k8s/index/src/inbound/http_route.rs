@@ -12,9 +12,9 @@ use linkerd_policy_controller_k8s_api::{
 use std::fmt;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct RouteBinding {
+pub struct RouteBinding<R> {
     pub parents: Vec<ParentRef>,
-    pub route: HttpRoute,
+    pub route: R,
     pub statuses: Vec<Status>,
 }
 
@@ -42,17 +42,17 @@ pub enum ConditionType {
 
 #[derive(Clone, Debug, thiserror::Error)]
 pub enum InvalidParentRef {
-    #[error("HTTPRoute resource may not reference a parent Server in an other namespace")]
+    #[error("route resource may not reference a parent Server in an other namespace")]
     ServerInAnotherNamespace,
 
-    #[error("HTTPRoute resource may not reference a parent by port")]
+    #[error("route resource may not reference a parent by port")]
     SpecifiesPort,
 
-    #[error("HTTPRoute resource may not reference a parent by section name")]
+    #[error("route resource may not reference a parent by section name")]
     SpecifiesSection,
 }
 
-impl TryFrom<api::HttpRoute> for RouteBinding {
+impl TryFrom<api::HttpRoute> for RouteBinding<HttpRoute> {
     type Error = Error;
 
     fn try_from(route: api::HttpRoute) -> Result<Self, Self::Error> {
@@ -98,7 +98,7 @@ impl TryFrom<api::HttpRoute> for RouteBinding {
     }
 }
 
-impl TryFrom<policy::HttpRoute> for RouteBinding {
+impl TryFrom<policy::HttpRoute> for RouteBinding<HttpRoute> {
     type Error = Error;
 
     fn try_from(route: policy::HttpRoute) -> Result<Self, Self::Error> {
@@ -142,7 +142,7 @@ impl TryFrom<policy::HttpRoute> for RouteBinding {
     }
 }
 
-impl RouteBinding {
+impl<R> RouteBinding<R> {
     #[inline]
     pub fn selects_server(&self, name: &str) -> bool {
         self.parents
@@ -160,7 +160,9 @@ impl RouteBinding {
                     .any(|condition| condition.type_ == ConditionType::Accepted && condition.status)
         })
     }
+}
 
+impl RouteBinding<HttpRoute> {
     pub fn try_match(
         api::HttpRouteMatch {
             path,
@@ -218,7 +220,7 @@ impl RouteBinding {
             api::HttpRouteFilter::RequestHeaderModifier {
                 request_header_modifier,
             } => {
-                let filter = http_route::req_header_modifier(request_header_modifier)?;
+                let filter = http_route::header_modifier(request_header_modifier)?;
                 Filter::RequestHeaderModifier(filter)
             }
 
@@ -245,21 +247,35 @@ impl RouteBinding {
             policy::HttpRouteFilter::RequestHeaderModifier {
                 request_header_modifier,
             } => {
-                let filter = http_route::req_header_modifier(request_header_modifier)?;
+                let filter = http_route::header_modifier(request_header_modifier)?;
                 Filter::RequestHeaderModifier(filter)
             }
 
+            policy::HttpRouteFilter::ResponseHeaderModifier {
+                response_header_modifier,
+            } => {
+                let filter = http_route::header_modifier(response_header_modifier)?;
+                Filter::ResponseHeaderModifier(filter)
+            }
+
             policy::HttpRouteFilter::RequestRedirect { request_redirect } => {
                 let filter = http_route::req_redirect(request_redirect)?;
                 Filter::RequestRedirect(filter)
             }
+
+            policy::HttpRouteFilter::RequestFailureInjector {
+                request_failure_injector,
+            } => {
+                let filter = http_route::failure_injector(request_failure_injector)?;
+                Filter::FailureInjector(filter)
+            }
         };
         Ok(filter)
     }
 }
 
 impl ParentRef {
-    fn collect_from(
+    pub(crate) fn collect_from(
         route_ns: Option<&str>,
         parent_refs: Option<Vec<api::ParentReference>>,
     ) -> Result<Vec<Self>, InvalidParentRef> {
@@ -1,14 +1,16 @@
-//! Keeps track of `Pod`, `Server`, and `ServerAuthorization` resources to
-//! provide a dynamic server configuration for all known ports on all pods.
+//! Keeps track of `Pod`, `ExternalWorkload`, `Server`, and `ServerAuthorization`
+//! resources to provide a dynamic server configuration for all known ports on
+//! all workloads.
 //!
-//! The `Index` type exposes a single public method: `Index::pod_server_rx`,
-//! which is used to lookup pod/ports (i.e. by the gRPC API). Otherwise, it
+//! The `Index` type exposes `Index::pod_server_rx` and
+//! `Index::external_workload_server_rx`, which are used to lookup
+//! workload/ports (i.e. by the gRPC API). Otherwise, it
 //! implements `kubert::index::IndexNamespacedResource` for the indexed
 //! kubernetes resources.
 
 use super::{
     authorization_policy, http_route::RouteBinding, meshtls_authentication, network_authentication,
-    pod, server, server_authorization,
+    pod, ratelimit_policy, server, server_authorization,
 };
 use crate::{
     ports::{PortHasher, PortMap, PortSet},
@@ -19,12 +21,13 @@ use anyhow::{anyhow, bail, Result};
 use linkerd_policy_controller_core::{
     http_route::{HttpRouteMatch, Method, PathMatch},
     inbound::{
-        AuthorizationRef, ClientAuthentication, ClientAuthorization, HttpRoute, HttpRouteRef,
-        HttpRouteRule, InboundServer, ProxyProtocol, ServerRef,
+        AuthorizationAction, AuthorizationRef, ClientAuthentication, ClientAuthorization,
+        GrpcRoute, GrpcRouteRef, HttpRoute, HttpRouteRef, HttpRouteRule, InboundServer,
+        ProxyProtocol, RateLimit, ServerRef,
     },
     IdentityMatch, Ipv4Net, Ipv6Net, NetworkMatch,
 };
-use linkerd_policy_controller_k8s_api::{self as k8s, policy::server::Port, ResourceExt};
+use linkerd_policy_controller_k8s_api::{self as k8s, ResourceExt};
 use parking_lot::RwLock;
 use std::{
     collections::{hash_map::Entry, BTreeSet},
@@ -51,6 +54,11 @@ pub struct Index {
 struct NamespaceIndex {
     cluster_info: Arc<ClusterInfo>,
     by_ns: HashMap<String, Namespace>,
+
+    /// Settings configured by `Namespace` annotations, which are inherited by
+    /// pods in the namespace. These are tracked separately from `by_ns` so
+    /// that they persist while the namespace has no indexed resources.
+    settings: HashMap<String, pod::Settings>,
 }
 
 /// Holds all `NetworkAuthentication` and `MeshTLSAuthentication` indices by-namespace.
@@ -74,9 +82,14 @@ struct Namespace {
 struct PodIndex {
     namespace: String,
     by_name: HashMap<String, Pod>,
+
+    /// External workloads are selected by servers like pods, so they're
+    /// indexed with the same state (but without probes).
+    external_workloads: HashMap<String, Pod>,
 }
 
-/// Holds a single pod's data with the server watches for all known ports.
+/// Holds a single pod's (or external workload's) data with the server watches
+/// for all known ports.
 ///
 /// The set of ports/servers is updated as clients discover server configuration
 /// or as `Server` resources select a port.
@@ -122,11 +135,16 @@ struct PolicyIndex {
     namespace: String,
     cluster_info: Arc<ClusterInfo>,
 
+    /// Settings inherited by pods from the namespace's annotations.
+    ns_settings: pod::Settings,
+
     servers: HashMap<String, server::Server>,
     server_authorizations: HashMap<String, server_authorization::ServerAuthz>,
 
     authorization_policies: HashMap<String, authorization_policy::Spec>,
-    http_routes: HashMap<String, RouteBinding>,
+    ratelimit_policies: HashMap<String, ratelimit_policy::Spec>,
+    http_routes: HashMap<String, RouteBinding<HttpRoute>>,
+    grpc_routes: HashMap<String, RouteBinding<GrpcRoute>>,
 }
 
 #[derive(Debug, Default)]
@@ -140,6 +158,12 @@ struct NsUpdate<T> {
     removed: HashSet<String>,
 }
 
+/// A type of route that may be bound to `Server`s.
+trait BoundRoute: PartialEq + Sized {
+    /// Returns the bindings for this type of route in the namespace.
+    fn bindings(policy: &mut PolicyIndex) -> &mut HashMap<String, RouteBinding<Self>>;
+}
+
 // === impl Index ===
 
 impl Index {
@@ -150,6 +174,7 @@ impl Index {
             namespaces: NamespaceIndex {
                 cluster_info,
                 by_ns: HashMap::default(),
+                settings: HashMap::default(),
             },
             authentications: AuthenticationNsIndex::default(),
         }))
@@ -176,11 +201,58 @@ impl Index {
             .get_mut(pod)
             .ok_or_else(|| anyhow::anyhow!("pod {}.{} not found", pod, namespace))?;
         Ok(pod
-            .port_server_or_default(port, &self.cluster_info)
+            .port_server_or_default(port, &ns.policy.ns_settings, &self.cluster_info)
             .watch
             .subscribe())
     }
 
+    /// Obtains an external workload:port's server receiver.
+    ///
+    /// An error is returned if the external workload is not found. If the port
+    /// is not found, a default is server is created.
+    pub fn external_workload_server_rx(
+        &mut self,
+        namespace: &str,
+        name: &str,
+        port: NonZeroU16,
+    ) -> Result<watch::Receiver<InboundServer>> {
+        let ns = self
+            .namespaces
+            .by_ns
+            .get_mut(namespace)
+            .ok_or_else(|| anyhow::anyhow!("namespace not found: {}", namespace))?;
+        let workload =
+            ns.pods.external_workloads.get_mut(name).ok_or_else(|| {
+                anyhow::anyhow!("external workload {}.{} not found", name, namespace)
+            })?;
+        Ok(workload
+            .port_server_or_default(port, &ns.policy.ns_settings, &self.cluster_info)
+            .watch
+            .subscribe())
+    }
+
+    /// Updates the settings that pods inherit from a namespace, reindexing the
+    /// namespace's pods if the settings changed.
+    fn update_ns_settings(&mut self, namespace: String, settings: pod::Settings) {
+        let prior = if settings == pod::Settings::default() {
+            self.namespaces.settings.remove(&namespace)
+        } else {
+            self.namespaces
+                .settings
+                .insert(namespace.clone(), settings.clone())
+        };
+        if prior.unwrap_or_default() == settings {
+            tracing::debug!("No changes");
+            return;
+        }
+
+        tracing::debug!(?settings, "Updating namespace settings");
+        self.ns_with_reindex(namespace, |ns| {
+            ns.policy.ns_settings = settings;
+            true
+        })
+    }
+
     fn ns_with_reindex(&mut self, namespace: String, f: impl FnOnce(&mut Namespace) -> bool) {
         self.namespaces
             .get_with_reindex(namespace, &self.authentications, f)
@@ -202,46 +274,47 @@ impl Index {
         }
     }
 
-    fn apply_route<R>(&mut self, route: R)
+    fn apply_route<T, R>(&mut self, route: R)
     where
+        T: BoundRoute,
         R: ResourceExt,
-        RouteBinding: TryFrom<R>,
-        <RouteBinding as TryFrom<R>>::Error: std::fmt::Display,
+        RouteBinding<T>: TryFrom<R>,
+        <RouteBinding<T> as TryFrom<R>>::Error: std::fmt::Display,
     {
-        let ns = route.namespace().expect("HttpRoute must have a namespace");
+        let ns = route.namespace().expect("route must have a namespace");
         let name = route.name_unchecked();
         let _span = info_span!("apply", %ns, %name).entered();
 
-        let route_binding = match route.try_into() {
+        let route_binding: RouteBinding<T> = match route.try_into() {
             Ok(binding) => binding,
             Err(error) => {
-                tracing::info!(%ns, %name, %error, "Ignoring HTTPRoute");
+                tracing::info!(%ns, %name, %error, "Ignoring route");
                 return;
             }
         };
 
-        self.ns_or_default_with_reindex(ns, |ns| ns.policy.update_http_route(name, route_binding))
+        self.ns_or_default_with_reindex(ns, |ns| ns.policy.update_route(name, route_binding))
     }
 
-    fn reset_route<R>(&mut self, routes: Vec<R>, deleted: HashMap<String, HashSet<String>>)
+    fn reset_route<T, R>(&mut self, routes: Vec<R>, deleted: HashMap<String, HashSet<String>>)
     where
+        T: BoundRoute,
         R: ResourceExt,
-        RouteBinding: TryFrom<R>,
-        <RouteBinding as TryFrom<R>>::Error: std::fmt::Display,
+        RouteBinding<T>: TryFrom<R>,
+        <RouteBinding<T> as TryFrom<R>>::Error: std::fmt::Display,
     {
         let _span = info_span!("reset").entered();
 
         // Aggregate all of the updates by namespace so that we only reindex
         // once per namespace.
-        type Ns = NsUpdate<RouteBinding>;
-        let mut updates_by_ns = HashMap::<String, Ns>::default();
+        let mut updates_by_ns = HashMap::<String, NsUpdate<RouteBinding<T>>>::default();
         for route in routes.into_iter() {
-            let namespace = route.namespace().expect("HttpRoute must be namespaced");
+            let namespace = route.namespace().expect("route must be namespaced");
             let name = route.name_unchecked();
             let route_binding = match route.try_into() {
                 Ok(binding) => binding,
                 Err(error) => {
-                    tracing::info!(ns = %namespace, %name, %error, "Ignoring HTTPRoute");
+                    tracing::info!(ns = %namespace, %name, %error, "Ignoring route");
                     continue;
                 }
             };
@@ -255,14 +328,14 @@ impl Index {
             updates_by_ns.entry(ns).or_default().removed = names;
         }
 
-        for (namespace, Ns { added, removed }) in updates_by_ns.into_iter() {
+        for (namespace, NsUpdate { added, removed }) in updates_by_ns.into_iter() {
             if added.is_empty() {
                 // If there are no live resources in the namespace, we do not
                 // want to create a default namespace instance, we just want to
                 // clear out all resources for the namespace (and then drop the
                 // whole namespace, if necessary).
                 self.ns_with_reindex(namespace, |ns| {
-                    ns.policy.http_routes.clear();
+                    T::bindings(&mut ns.policy).clear();
                     true
                 });
             } else {
@@ -272,10 +345,10 @@ impl Index {
                 self.ns_or_default_with_reindex(namespace, |ns| {
                     let mut changed = !removed.is_empty();
                     for name in removed.into_iter() {
-                        ns.policy.http_routes.remove(&name);
+                        T::bindings(&mut ns.policy).remove(&name);
                     }
                     for (name, route_binding) in added.into_iter() {
-                        changed = ns.policy.update_http_route(name, route_binding) || changed;
+                        changed = ns.policy.update_route(name, route_binding) || changed;
                     }
                     changed
                 });
@@ -283,10 +356,28 @@ impl Index {
         }
     }
 
-    fn delete_route(&mut self, ns: String, name: String) {
+    fn delete_route<T: BoundRoute>(&mut self, ns: String, name: String) {
         let _span = info_span!("delete", %ns, %name).entered();
-        self.ns_with_reindex(ns, |ns| ns.policy.http_routes.remove(&name).is_some())
+        self.ns_with_reindex(ns, |ns| T::bindings(&mut ns.policy).remove(&name).is_some())
+    }
+}
+
+impl kubert::index::IndexClusterResource<k8s::Namespace> for Index {
+    fn apply(&mut self, ns: k8s::Namespace) {
+        let name = ns.name_unchecked();
+        let _span = info_span!("apply", ns = %name).entered();
+
+        let settings = pod::Settings::from_metadata(&ns.metadata);
+        self.update_ns_settings(name, settings);
+    }
+
+    fn delete(&mut self, name: String) {
+        let _span = info_span!("delete", ns = %name).entered();
+        self.update_ns_settings(name, pod::Settings::default());
     }
+
+    // Since apply only reindexes a single namespace at a time, there's no need
+    // to handle resets specially.
 }
 
 impl kubert::index::IndexNamespacedResource<k8s::Pod> for Index {
@@ -337,6 +428,35 @@ impl kubert::index::IndexNamespacedResource<k8s::Pod> for Index {
     // handle resets specially.
 }
 
+impl kubert::index::IndexNamespacedResource<k8s::ExternalWorkload> for Index {
+    fn apply(&mut self, workload: k8s::ExternalWorkload) {
+        let namespace = workload.namespace().unwrap();
+        let name = workload.name_unchecked();
+        let _span = info_span!("apply", ns = %namespace, %name).entered();
+
+        let port_names = pod::external_workload_tcp_ports_by_name(&workload.spec);
+        let meta = pod::Meta::from_metadata(workload.metadata);
+
+        // Unlike a pod's, an external workload's ports may change, so the
+        // workload is reindexed when either its metadata or ports change.
+        let ns = self.namespaces.get_or_default(namespace);
+        if let Some(workload) = ns.pods.update_external_workload(name, meta, port_names) {
+            workload.reindex_servers(&ns.policy, &self.authentications);
+        }
+    }
+
+    fn delete(&mut self, ns: String, name: String) {
+        tracing::debug!(%ns, %name, "delete");
+        if let Entry::Occupied(mut ns) = self.namespaces.by_ns.entry(ns) {
+            // Once the workload is removed, there's nothing else to update. Any
+            // open watches will complete.
+            if ns.get_mut().pods.external_workloads.remove(&name).is_some() && ns.get().is_empty() {
+                ns.remove();
+            }
+        }
+    }
+}
+
 impl kubert::index::IndexNamespacedResource<k8s::policy::Server> for Index {
     fn apply(&mut self, srv: k8s::policy::Server) {
         let ns = srv.namespace().expect("server must be namespaced");
@@ -567,6 +687,90 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::AuthorizationPolicy> fo
     }
 }
 
+impl kubert::index::IndexNamespacedResource<k8s::policy::HttpLocalRateLimitPolicy> for Index {
+    fn apply(&mut self, policy: k8s::policy::HttpLocalRateLimitPolicy) {
+        let ns = policy.namespace().unwrap();
+        let name = policy.name_unchecked();
+        let _span = info_span!("apply", %ns, %name).entered();
+
+        let spec = match ratelimit_policy::Spec::try_from_resource(policy, &self.cluster_info) {
+            Ok(spec) => spec,
+            Err(error) => {
+                tracing::warn!(%error, "Invalid HTTPLocalRateLimitPolicy");
+                return;
+            }
+        };
+
+        self.ns_or_default_with_reindex(ns, |ns| ns.policy.update_ratelimit_policy(name, spec))
+    }
+
+    fn delete(&mut self, ns: String, name: String) {
+        let _span = info_span!("delete", %ns, %name).entered();
+        self.ns_with_reindex(ns, |ns| {
+            ns.policy.ratelimit_policies.remove(&name).is_some()
+        })
+    }
+
+    fn reset(
+        &mut self,
+        policies: Vec<k8s::policy::HttpLocalRateLimitPolicy>,
+        deleted: HashMap<String, HashSet<String>>,
+    ) {
+        let _span = info_span!("reset");
+
+        // Aggregate all of the updates by namespace so that we only reindex
+        // once per namespace.
+        type Ns = NsUpdate<ratelimit_policy::Spec>;
+        let mut updates_by_ns = HashMap::<String, Ns>::default();
+        for policy in policies.into_iter() {
+            let namespace = policy
+                .namespace()
+                .expect("httplocalratelimitpolicy must be namespaced");
+            let name = policy.name_unchecked();
+            match ratelimit_policy::Spec::try_from_resource(policy, &self.cluster_info) {
+                Ok(spec) => updates_by_ns
+                    .entry(namespace)
+                    .or_default()
+                    .added
+                    .push((name, spec)),
+                Err(error) => {
+                    tracing::warn!(ns = %namespace, %name, %error, "Invalid HTTPLocalRateLimitPolicy")
+                }
+            }
+        }
+        for (ns, names) in deleted.into_iter() {
+            updates_by_ns.entry(ns).or_default().removed = names;
+        }
+
+        for (namespace, Ns { added, removed }) in updates_by_ns.into_iter() {
+            if added.is_empty() {
+                // If there are no live resources in the namespace, we do not
+                // want to create a default namespace instance, we just want to
+                // clear out all resources for the namespace (and then drop the
+                // whole namespace, if necessary).
+                self.ns_with_reindex(namespace, |ns| {
+                    ns.policy.ratelimit_policies.clear();
+                    true
+                });
+            } else {
+                // Otherwise, we take greater care to reindex only when the
+                // state actually changed. The vast majority of resets will see
+                // no actual data change.
+                self.ns_or_default_with_reindex(namespace, |ns| {
+                    let mut changed = !removed.is_empty();
+                    for name in removed.into_iter() {
+                        ns.policy.ratelimit_policies.remove(&name);
+                    }
+                    for (name, spec) in added.into_iter() {
+                        changed = ns.policy.update_ratelimit_policy(name, spec) || changed;
+                    }
+                    changed
+                });
+            }
+        }
+    }
+}
+
 impl kubert::index::IndexNamespacedResource<k8s::policy::MeshTLSAuthentication> for Index {
     fn apply(&mut self, authn: k8s::policy::MeshTLSAuthentication) {
         let ns = authn
@@ -724,11 +928,11 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::NetworkAuthentication>
 
 impl kubert::index::IndexNamespacedResource<k8s::policy::HttpRoute> for Index {
     fn apply(&mut self, route: k8s::policy::HttpRoute) {
-        self.apply_route(route)
+        self.apply_route::<HttpRoute, _>(route)
     }
 
     fn delete(&mut self, ns: String, name: String) {
-        self.delete_route(ns, name)
+        self.delete_route::<HttpRoute>(ns, name)
     }
 
     fn reset(
@@ -736,7 +940,25 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::HttpRoute> for Index {
         routes: Vec<k8s::policy::HttpRoute>,
         deleted: HashMap<String, HashSet<String>>,
     ) {
-        self.reset_route(routes, deleted)
+        self.reset_route::<HttpRoute, _>(routes, deleted)
+    }
+}
+
+impl kubert::index::IndexNamespacedResource<k8s::gateway::GrpcRoute> for Index {
+    fn apply(&mut self, route: k8s::gateway::GrpcRoute) {
+        self.apply_route::<GrpcRoute, _>(route)
+    }
+
+    fn delete(&mut self, ns: String, name: String) {
+        self.delete_route::<GrpcRoute>(ns, name)
+    }
+
+    fn reset(
+        &mut self,
+        routes: Vec<k8s::gateway::GrpcRoute>,
+        deleted: HashMap<String, HashSet<String>>,
+    ) {
+        self.reset_route::<GrpcRoute, _>(routes, deleted)
     }
 }
 
@@ -744,9 +966,10 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::HttpRoute> for Index {
 
 impl NamespaceIndex {
     fn get_or_default(&mut self, ns: String) -> &mut Namespace {
-        self.by_ns
-            .entry(ns.clone())
-            .or_insert_with(|| Namespace::new(ns, self.cluster_info.clone()))
+        self.by_ns.entry(ns.clone()).or_insert_with(|| {
+            let settings = self.settings.get(&ns).cloned().unwrap_or_default();
+            Namespace::new(ns, self.cluster_info.clone(), settings)
+        })
     }
 
     /// Gets the given namespace and, if it exists, passes it to the given
@@ -789,19 +1012,23 @@ impl NamespaceIndex {
 // === impl Namespace ===
 
 impl Namespace {
-    fn new(namespace: String, cluster_info: Arc<ClusterInfo>) -> Self {
+    fn new(namespace: String, cluster_info: Arc<ClusterInfo>, ns_settings: pod::Settings) -> Self {
         Namespace {
             pods: PodIndex {
                 namespace: namespace.clone(),
                 by_name: HashMap::default(),
+                external_workloads: HashMap::default(),
             },
             policy: PolicyIndex {
                 namespace,
                 cluster_info,
+                ns_settings,
                 servers: HashMap::default(),
                 server_authorizations: HashMap::default(),
                 authorization_policies: HashMap::default(),
+                ratelimit_policies: HashMap::default(),
                 http_routes: HashMap::default(),
+                grpc_routes: HashMap::default(),
             },
         }
     }
@@ -823,7 +1050,7 @@ impl Namespace {
 impl PodIndex {
     #[inline]
     fn is_empty(&self) -> bool {
-        self.by_name.is_empty()
+        self.by_name.is_empty() && self.external_workloads.is_empty()
     }
 
     fn update(
@@ -864,12 +1091,44 @@ impl PodIndex {
         Ok(Some(pod))
     }
 
+    fn update_external_workload(
+        &mut self,
+        name: String,
+        meta: pod::Meta,
+        port_names: HashMap<String, PortSet>,
+    ) -> Option<&mut Pod> {
+        match self.external_workloads.entry(name.clone()) {
+            Entry::Vacant(entry) => Some(entry.insert(Pod {
+                meta,
+                port_names,
+                port_servers: PortMap::default(),
+                probes: PortMap::default(),
+            })),
+
+            Entry::Occupied(entry) => {
+                let workload = entry.into_mut();
+                if workload.meta == meta && workload.port_names == port_names {
+                    tracing::debug!(workload = %name, "No changes");
+                    return None;
+                }
+                tracing::debug!(workload = %name, "Updating");
+                workload.meta = meta;
+                workload.port_names = port_names;
+                Some(workload)
+            }
+        }
+    }
+
     fn reindex(&mut self, policy: &PolicyIndex, authns: &AuthenticationNsIndex) {
         let _span = info_span!("reindex", ns = %self.namespace).entered();
         for (name, pod) in self.by_name.iter_mut() {
             let _span = info_span!("pod", pod = %name).entered();
             pod.reindex_servers(policy, authns);
         }
+        for (name, workload) in self.external_workloads.iter_mut() {
+            let _span = info_span!("external_workload", workload = %name).entered();
+            workload.reindex_servers(policy, authns);
+        }
     }
 }
 
@@ -892,7 +1151,12 @@ impl Pod {
             std::hash::BuildHasherDefault::<PortHasher>::default(),
         );
 
-        for (srvname, server) in policy.servers.iter() {
+        // Servers are matched in order of their names so that, when multiple servers select the
+        // same port (e.g. one by number and one by name), the same server consistently wins.
+        let mut servers = policy.servers.iter().collect::<Vec<_>>();
+        servers.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+        for (srvname, server) in servers.into_iter() {
             if server.pod_selector.matches(&self.meta.labels) {
                 for port in self.select_ports(&server.port_ref).into_iter() {
                     // If the port is already matched to a server, then log a warning and skip
@@ -900,7 +1164,7 @@ impl Pod {
                     if let Some(prior) = matched_ports.get(&port) {
                         tracing::warn!(
                             port = %port,
-                            server = %prior,
+                            winner = %prior,
                             conflict = %srvname,
                             "Port already matched by another server; skipping"
                         );
@@ -927,7 +1191,7 @@ impl Pod {
 
         // Reset all remaining ports to the default policy.
         for port in unmatched_ports.into_iter() {
-            self.set_default_server(port, &policy.cluster_info);
+            self.set_default_server(port, &policy.ns_settings, &policy.cluster_info);
         }
     }
 
@@ -977,10 +1241,16 @@ impl Pod {
     }
 
     /// Updates a pod-port to use the given named server.
-    fn set_default_server(&mut self, port: NonZeroU16, config: &ClusterInfo) {
+    fn set_default_server(
+        &mut self,
+        port: NonZeroU16,
+        ns_settings: &pod::Settings,
+        config: &ClusterInfo,
+    ) {
         let server = Self::default_inbound_server(
             port,
             &self.meta.settings,
+            ns_settings,
             self.probes
                 .get(&port)
                 .into_iter()
@@ -1016,10 +1286,10 @@ impl Pod {
     /// Enumerates ports.
     ///
     /// A named port may refer to an arbitrary number of port numbers.
-    fn select_ports(&mut self, port_ref: &Port) -> Vec<NonZeroU16> {
+    fn select_ports(&mut self, port_ref: &server::PortRef) -> Vec<NonZeroU16> {
         match port_ref {
-            Port::Number(p) => Some(*p).into_iter().collect(),
-            Port::Name(name) => self
+            server::PortRef::Numbers(ports) => ports.iter().copied().collect(),
+            server::PortRef::Name(name) => self
                 .port_names
                 .get(name)
                 .into_iter()
@@ -1032,6 +1302,7 @@ impl Pod {
     fn port_server_or_default(
         &mut self,
         port: NonZeroU16,
+        ns_settings: &pod::Settings,
         config: &ClusterInfo,
     ) -> &mut PodPortServer {
         match self.port_servers.entry(port) {
@@ -1040,6 +1311,7 @@ impl Pod {
                 let (watch, _) = watch::channel(Self::default_inbound_server(
                     port,
                     &self.meta.settings,
+                    ns_settings,
                     self.probes
                         .get(&port)
                         .into_iter()
@@ -1055,10 +1327,11 @@ impl Pod {
     fn default_inbound_server<'p>(
         port: NonZeroU16,
         settings: &pod::Settings,
+        ns_settings: &pod::Settings,
         probe_paths: impl Iterator<Item = &'p str>,
         config: &ClusterInfo,
     ) -> InboundServer {
-        let protocol = if settings.opaque_ports.contains(&port) {
+        let protocol = if settings.is_opaque(ns_settings, port) {
             ProxyProtocol::Opaque
         } else {
             ProxyProtocol::Detect {
@@ -1066,8 +1339,10 @@ impl Pod {
             }
         };
 
-        let mut policy = settings.default_policy.unwrap_or(config.default_policy);
-        if settings.require_id_ports.contains(&port) {
+        let mut policy = settings
+            .default_policy(ns_settings)
+            .unwrap_or(config.default_policy);
+        if settings.requires_id(ns_settings, port) {
             if let DefaultPolicy::Allow {
                 ref mut authenticated_only,
                 ..
@@ -1079,13 +1354,26 @@ impl Pod {
 
         let authorizations = policy.default_authzs(config);
 
-        let http_routes = config.default_inbound_http_routes(probe_paths);
+        let mut http_routes = config.default_inbound_http_routes(probe_paths);
+        if let DefaultPolicy::Audit = policy {
+            // Routes with their own authorizations, like the probe route, are
+            // not covered by the server's authorizations. Audit them too so
+            // that requests they would deny are flagged rather than denied.
+            for route in http_routes.values_mut() {
+                if !route.authorizations.is_empty() {
+                    route.authorizations.extend(authorizations.clone());
+                }
+            }
+        }
+        let grpc_routes = config.default_inbound_grpc_routes();
 
         InboundServer {
             reference: ServerRef::Default(policy.as_str()),
             protocol,
             authorizations,
+            ratelimit: None,
             http_routes,
+            grpc_routes,
         }
     }
 }
@@ -1152,6 +1440,22 @@ impl PolicyIndex {
         true
     }
 
+    fn update_ratelimit_policy(&mut self, name: String, spec: ratelimit_policy::Spec) -> bool {
+        match self.ratelimit_policies.entry(name) {
+            Entry::Vacant(entry) => {
+                entry.insert(spec);
+            }
+            Entry::Occupied(entry) => {
+                let rl = entry.into_mut();
+                if *rl == spec {
+                    return false;
+                }
+                *rl = spec;
+            }
+        }
+        true
+    }
+
     fn inbound_server<'p>(
         &self,
         name: String,
@@ -1161,16 +1465,53 @@ impl PolicyIndex {
     ) -> InboundServer {
         tracing::trace!(%name, ?server, "Creating inbound server");
         let authorizations = self.client_authzs(&name, server, authentications);
+        let ratelimit = self.ratelimit(
+            |target| matches!(target, ratelimit_policy::Target::Server(n) if *n == name),
+        );
         let http_routes = self.http_routes(&name, authentications, probe_paths);
+        let grpc_routes = self.grpc_routes(&name, authentications);
 
         InboundServer {
             reference: ServerRef::Server(name),
             authorizations,
             protocol: server.protocol.clone(),
+            ratelimit,
             http_routes,
+            grpc_routes,
         }
     }
 
+    /// Finds the rate limit policy that applies to a target.
+    ///
+    /// Only a single rate limit may apply to a server or route. If multiple
+    /// policies target the same resource, the first policy by name is used.
+    fn ratelimit(&self, targets: impl Fn(&ratelimit_policy::Target) -> bool) -> Option<RateLimit> {
+        let mut policies = self
+            .ratelimit_policies
+            .iter()
+            .filter(|(_, spec)| targets(&spec.target))
+            .collect::<Vec<_>>();
+        policies.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let mut policies = policies.into_iter();
+        let (name, spec) = policies.next()?;
+        for (conflict, _) in policies {
+            tracing::warn!(
+                ns = %self.namespace,
+                ratelimitpolicy = %name,
+                %conflict,
+                "Multiple HTTPLocalRateLimitPolicies target the same resource; ignoring",
+            );
+        }
+
+        Some(RateLimit {
+            name: name.clone(),
+            total: spec.total,
+            identity: spec.identity,
+            overrides: spec.overrides.clone(),
+        })
+    }
+
     fn client_authzs(
         &self,
         server_name: &str,
@@ -1203,10 +1544,11 @@ impl PolicyIndex {
                     }
                 }
                 authorization_policy::Target::Namespace => {}
-                authorization_policy::Target::HttpRoute(_) => {
-                    // Policies which target HttpRoutes will be attached to
-                    // the route authorizations and should not be included in
-                    // the server authorizations.
+                authorization_policy::Target::HttpRoute(_)
+                | authorization_policy::Target::GrpcRoute(_) => {
+                    // Policies which target routes will be attached to the
+                    // route authorizations and should not be included in the
+                    // server authorizations.
                     continue;
                 }
             }
@@ -1241,32 +1583,29 @@ impl PolicyIndex {
 
     fn route_client_authzs(
         &self,
-        route_name: &str,
+        route: &authorization_policy::Target,
         authentications: &AuthenticationNsIndex,
     ) -> HashMap<AuthorizationRef, ClientAuthorization> {
         let mut authzs = HashMap::default();
 
         for (name, spec) in &self.authorization_policies {
             // Skip the policy if it doesn't apply to the route.
-            match &spec.target {
-                authorization_policy::Target::HttpRoute(n) if n == route_name => {}
-                _ => {
-                    tracing::trace!(
-                        ns = %self.namespace,
-                        authorizationpolicy = %name,
-                        route = %route_name,
-                        target = ?spec.target,
-                        "AuthorizationPolicy does not target HttpRoute",
-                    );
-                    continue;
-                }
+            if spec.target != *route {
+                tracing::trace!(
+                    ns = %self.namespace,
+                    authorizationpolicy = %name,
+                    ?route,
+                    target = ?spec.target,
+                    "AuthorizationPolicy does not target route",
+                );
+                continue;
             }
 
             tracing::trace!(
                 ns = %self.namespace,
                 authorizationpolicy = %name,
-                route = %route_name,
-                "AuthorizationPolicy targets HttpRoute",
+                ?route,
+                "AuthorizationPolicy targets route",
             );
             tracing::trace!(authns = ?spec.authentications);
 
@@ -1274,7 +1613,7 @@ impl PolicyIndex {
                 Ok(authz) => authz,
                 Err(error) => {
                     tracing::info!(
-                        route = %route_name,
+                        ?route,
                         authorizationpolicy = %name,
                         %error,
                         "Illegal AuthorizationPolicy; ignoring",
@@ -1303,7 +1642,10 @@ impl PolicyIndex {
             .filter(|(_, route)| route.accepted_by_server(server_name))
             .map(|(name, route)| {
                 let mut route = route.route.clone();
-                route.authorizations = self.route_client_authzs(name, authentications);
+                route.authorizations = self.route_client_authzs(
+                    &authorization_policy::Target::HttpRoute(name.clone()),
+                    authentications,
+                );
                 (HttpRouteRef::Linkerd(name.clone()), route)
             })
             .collect::<HashMap<_, _>>();
@@ -1313,6 +1655,31 @@ impl PolicyIndex {
         self.cluster_info.default_inbound_http_routes(probe_paths)
     }
 
+    fn grpc_routes(
+        &self,
+        server_name: &str,
+        authentications: &AuthenticationNsIndex,
+    ) -> HashMap<GrpcRouteRef, GrpcRoute> {
+        let routes = self
+            .grpc_routes
+            .iter()
+            .filter(|(_, route)| route.selects_server(server_name))
+            .filter(|(_, route)| route.accepted_by_server(server_name))
+            .map(|(name, route)| {
+                let mut route = route.route.clone();
+                route.authorizations = self.route_client_authzs(
+                    &authorization_policy::Target::GrpcRoute(name.clone()),
+                    authentications,
+                );
+                (GrpcRouteRef::Gateway(name.clone()), route)
+            })
+            .collect::<HashMap<_, _>>();
+        if !routes.is_empty() {
+            return routes;
+        }
+        self.cluster_info.default_inbound_grpc_routes()
+    }
+
     fn policy_client_authz(
         &self,
         spec: &authorization_policy::Spec,
@@ -1415,11 +1782,12 @@ impl PolicyIndex {
                     },
                 ]
             }),
+            action: AuthorizationAction::Allow,
         })
     }
 
-    fn update_http_route(&mut self, name: String, route: RouteBinding) -> bool {
-        match self.http_routes.entry(name) {
+    fn update_route<T: BoundRoute>(&mut self, name: String, route: RouteBinding<T>) -> bool {
+        match T::bindings(self).entry(name) {
             Entry::Vacant(entry) => {
                 entry.insert(route);
             }
@@ -1489,6 +1857,20 @@ impl AuthenticationIndex {
     }
 }
 
+// === impl BoundRoute ===
+
+impl BoundRoute for HttpRoute {
+    fn bindings(policy: &mut PolicyIndex) -> &mut HashMap<String, RouteBinding<Self>> {
+        &mut policy.http_routes
+    }
+}
+
+impl BoundRoute for GrpcRoute {
+    fn bindings(policy: &mut PolicyIndex) -> &mut HashMap<String, RouteBinding<Self>> {
+        &mut policy.grpc_routes
+    }
+}
+
 // === imp NsUpdate ===
 
 impl<T> Default for NsUpdate<T> {
@@ -1547,6 +1929,7 @@ impl ClusterInfo {
                     .map(Into::into)
                     .collect(),
                 authentication: ClientAuthentication::Unauthenticated,
+                action: AuthorizationAction::Allow,
             },
         ))
         .collect();
@@ -1564,4 +1947,11 @@ impl ClusterInfo {
 
         routes
     }
+
+    fn default_inbound_grpc_routes(&self) -> HashMap<GrpcRouteRef, GrpcRoute> {
+        // If no routes are defined for the server, use a default route that
+        // matches all requests. Default authorizations are instrumented on
+        // the server.
+        std::iter::once((GrpcRouteRef::Default("default"), GrpcRoute::default())).collect()
+    }
 }
@@ -16,15 +16,18 @@ pub(crate) struct Meta {
 }
 
 /// Per-pod settings, as configured by the pod's annotations.
-#[derive(Debug, Default, PartialEq)]
+///
+/// Namespaces may be annotated with the same settings, which apply to all pods
+/// in the namespace that do not set them.
+#[derive(Clone, Debug, Default, PartialEq)]
 pub(crate) struct Settings {
-    pub require_id_ports: PortSet,
-    pub opaque_ports: PortSet,
+    pub require_id_ports: Option<PortSet>,
+    pub opaque_ports: Option<PortSet>,
     pub default_policy: Option<DefaultPolicy>,
 }
 
 /// Gets the set of named ports with `protocol: TCP` from a pod spec.
-pub(crate) fn tcp_ports_by_name(spec: &k8s::PodSpec) -> HashMap<String, PortSet> {
+pub fn tcp_ports_by_name(spec: &k8s::PodSpec) -> HashMap<String, PortSet> {
     let mut ports = HashMap::<String, PortSet>::default();
     for (port, name) in spec
         .containers
@@ -37,6 +40,25 @@ pub(crate) fn tcp_ports_by_name(spec: &k8s::PodSpec) -> HashMap<String, PortSet>
     ports
 }
 
+/// Gets the set of named ports with `protocol: TCP` from an external workload
+/// spec.
+pub(crate) fn external_workload_tcp_ports_by_name(
+    spec: &k8s::ExternalWorkloadSpec,
+) -> HashMap<String, PortSet> {
+    let mut ports = HashMap::<String, PortSet>::default();
+    for port in spec.ports.iter().flatten() {
+        if let Some(ref proto) = port.protocol {
+            if !proto.eq_ignore_ascii_case("TCP") {
+                continue;
+            }
+        }
+        if let Some(name) = port.name.as_deref() {
+            ports.entry(name.to_string()).or_default().insert(port.port);
+        }
+    }
+    ports
+}
+
 /// Gets the container probe ports for a Pod.
 ///
 /// The result is a mapping for each probe port exposed by a container in the
@@ -114,7 +136,7 @@ impl Meta {
 // === impl Settings ===
 
 impl Settings {
-    /// Reads pod settings from the pod metadata including:
+    /// Reads pod settings from the pod (or namespace) metadata including:
     ///
     /// - Opaque ports
     /// - Ports that require identity
@@ -130,13 +152,11 @@ impl Settings {
             None
         });
 
-        let opaque_ports =
-            ports_annotation(anns, "config.linkerd.io/opaque-ports").unwrap_or_default();
+        let opaque_ports = ports_annotation(anns, "config.linkerd.io/opaque-ports");
         let require_id_ports = ports_annotation(
             anns,
             "config.linkerd.io/proxy-require-identity-inbound-ports",
-        )
-        .unwrap_or_default();
+        );
 
         Self {
             default_policy,
@@ -144,6 +164,27 @@ impl Settings {
             require_id_ports,
         }
     }
+
+    /// Returns the pod's default policy or, if it is not set, the namespace's.
+    pub(crate) fn default_policy(&self, ns: &Self) -> Option<DefaultPolicy> {
+        self.default_policy.or(ns.default_policy)
+    }
+
+    /// Returns true if the port is marked opaque on the pod or, if the pod
+    /// does not set opaque ports, on the namespace.
+    pub(crate) fn is_opaque(&self, ns: &Self, port: NonZeroU16) -> bool {
+        (self.opaque_ports.as_ref())
+            .or(ns.opaque_ports.as_ref())
+            .map_or(false, |ports| ports.contains(&port))
+    }
+
+    /// Returns true if the port requires identity on the pod or, if the pod
+    /// does not set identity-required ports, on the namespace.
+    pub(crate) fn requires_id(&self, ns: &Self, port: NonZeroU16) -> bool {
+        (self.require_id_ports.as_ref())
+            .or(ns.require_id_ports.as_ref())
+            .map_or(false, |ports| ports.contains(&port))
+    }
 }
 
 /// Attempts to read a default policy override from an annotation map.
@@ -177,6 +218,73 @@ mod tests {
     use super::*;
     use linkerd_policy_controller_k8s_api as k8s;
 
+    #[test]
+    fn default_policy_annotation() {
+        let settings = Settings::from_metadata(&k8s::ObjectMeta {
+            annotations: Some(
+                Some((
+                    "config.linkerd.io/default-inbound-policy".to_string(),
+                    "audit".to_string(),
+                ))
+                .into_iter()
+                .collect(),
+            ),
+            ..Default::default()
+        });
+        assert_eq!(settings.default_policy, Some(DefaultPolicy::Audit));
+
+        let settings = Settings::from_metadata(&k8s::ObjectMeta {
+            annotations: Some(
+                Some((
+                    "config.linkerd.io/default-inbound-policy".to_string(),
+                    "bogus".to_string(),
+                ))
+                .into_iter()
+                .collect(),
+            ),
+            ..Default::default()
+        });
+        assert_eq!(settings.default_policy, None);
+    }
+
+    #[test]
+    fn settings_inherited_from_namespace() {
+        let ns = Settings::from_metadata(&k8s::ObjectMeta {
+            annotations: Some(
+                [
+                    ("config.linkerd.io/default-inbound-policy", "deny"),
+                    ("config.linkerd.io/opaque-ports", "3306"),
+                    (
+                        "config.linkerd.io/proxy-require-identity-inbound-ports",
+                        "8443",
+                    ),
+                ]
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            ),
+            ..Default::default()
+        });
+        let pod = Settings::from_metadata(&k8s::ObjectMeta {
+            annotations: Some(
+                Some((
+                    "config.linkerd.io/opaque-ports".to_string(),
+                    "5432".to_string(),
+                ))
+                .into_iter()
+                .collect(),
+            ),
+            ..Default::default()
+        });
+
+        let port = |p: u16| NonZeroU16::try_from(p).unwrap();
+        assert_eq!(pod.default_policy(&ns), Some(DefaultPolicy::Deny));
+        assert!(pod.requires_id(&ns, port(8443)));
+        // The pod's opaque ports override the namespace's.
+        assert!(pod.is_opaque(&ns, port(5432)));
+        assert!(!pod.is_opaque(&ns, port(3306)));
+    }
+
     #[test]
     fn probe_multiple_paths() {
         let probes = pod_http_probes(&k8s::PodSpec {
@@ -0,0 +1,143 @@
+use crate::ClusterInfo;
+use anyhow::{bail, ensure, Result};
+use linkerd_policy_controller_core::inbound::{Limit, RateLimitOverride};
+use linkerd_policy_controller_k8s_api::{
+    self as k8s,
+    policy::{ratelimit_policy, LocalTargetRef, NamespacedTargetRef, RateLimitPolicySpec},
+    ResourceExt, ServiceAccount,
+};
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct Spec {
+    pub target: Target,
+    pub total: Option<Limit>,
+    pub identity: Option<Limit>,
+    pub overrides: Vec<RateLimitOverride>,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum Target {
+    Server(String),
+}
+
+/// Checks that a rate limit policy may be indexed.
+pub fn validate(spec: &RateLimitPolicySpec) -> Result<()> {
+    target(&spec.target_ref)?;
+
+    let total = spec.total.as_ref().map(limit).transpose()?;
+    let identity = spec.identity.as_ref().map(limit).transpose()?;
+    let overrides = spec.overrides.as_deref().unwrap_or_default();
+
+    ensure!(
+        total.is_some() || identity.is_some() || !overrides.is_empty(),
+        "at least one of total, identity, or overrides must be set"
+    );
+
+    if let (Some(total), Some(identity)) = (total, identity) {
+        ensure!(
+            identity.requests_per_second <= total.requests_per_second,
+            "identity requestsPerSecond ({}) must not exceed total requestsPerSecond ({})",
+            identity.requests_per_second,
+            total.requests_per_second,
+        );
+    }
+
+    for ovr in overrides {
+        ensure!(
+            ovr.requests_per_second > 0,
+            "override requestsPerSecond must be greater than zero"
+        );
+        if let Some(total) = total {
+            ensure!(
+                ovr.requests_per_second <= total.requests_per_second,
+                "override requestsPerSecond ({}) must not exceed total requestsPerSecond ({})",
+                ovr.requests_per_second,
+                total.requests_per_second,
+            );
+        }
+        ensure!(
+            !ovr.client_refs.is_empty(),
+            "override must reference at least one client"
+        );
+        for client in &ovr.client_refs {
+            client_ref(client)?;
+        }
+    }
+
+    Ok(())
+}
+
+impl Spec {
+    pub(crate) fn try_from_resource(
+        rl: k8s::policy::HttpLocalRateLimitPolicy,
+        cluster: &ClusterInfo,
+    ) -> Result<Self> {
+        let namespace = rl
+            .namespace()
+            .expect("HTTPLocalRateLimitPolicy must have a namespace");
+
+        validate(&rl.spec)?;
+
+        let target = target(&rl.spec.target_ref)?;
+        let total = rl.spec.total.as_ref().map(limit).transpose()?;
+        let identity = rl.spec.identity.as_ref().map(limit).transpose()?;
+
+        let overrides = rl
+            .spec
+            .overrides
+            .into_iter()
+            .flatten()
+            .map(|ovr| {
+                let client_identities = ovr
+                    .client_refs
+                    .iter()
+                    .map(|client| {
+                        client_ref(client)?;
+                        let ns = client.namespace.as_deref().unwrap_or(&namespace);
+                        Ok(cluster.service_account_identity(ns, &client.name))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(RateLimitOverride {
+                    requests_per_second: ovr.requests_per_second,
+                    client_identities,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            target,
+            total,
+            identity,
+            overrides,
+        })
+    }
+}
+
+fn target(t: &LocalTargetRef) -> Result<Target> {
+    if t.targets_kind::<k8s::policy::Server>() {
+        return Ok(Target::Server(t.name.clone()));
+    }
+    // The proxy API only carries rate limits on servers, so route targets
+    // cannot be enforced yet.
+    if t.targets_kind::<k8s::policy::HttpRoute>() {
+        bail!("rate limits may not target HTTPRoutes; target the Server instead");
+    }
+    bail!("unsupported rate limit target type: {}", t.canonical_kind())
+}
+
+fn client_ref(t: &NamespacedTargetRef) -> Result<()> {
+    if t.targets_kind::<ServiceAccount>() {
+        return Ok(());
+    }
+    bail!("unsupported rate limit client type: {}", t.canonical_kind())
+}
+
+fn limit(l: &ratelimit_policy::Limit) -> Result<Limit> {
+    ensure!(
+        l.requests_per_second > 0,
+        "requestsPerSecond must be greater than zero"
+    );
+    Ok(Limit {
+        requests_per_second: l.requests_per_second,
+    })
+}
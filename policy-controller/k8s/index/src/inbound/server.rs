@@ -1,4 +1,7 @@
-use crate::ClusterInfo;
+use crate::{
+    ports::{parse_portset, PortSet},
+    ClusterInfo,
+};
 use linkerd_policy_controller_core::inbound::ProxyProtocol;
 use linkerd_policy_controller_k8s_api::{self as k8s, policy::server::Port};
 
@@ -7,21 +10,39 @@ use linkerd_policy_controller_k8s_api::{self as k8s, policy::server::Port};
 pub(crate) struct Server {
     pub labels: k8s::Labels,
     pub pod_selector: k8s::labels::Selector,
-    pub port_ref: Port,
+    pub port_ref: PortRef,
     pub protocol: ProxyProtocol,
 }
 
+/// The ports selected by a `Server`.
+#[derive(Debug, PartialEq)]
+pub(crate) enum PortRef {
+    Numbers(PortSet),
+    Name(String),
+}
+
 impl Server {
     pub(crate) fn from_resource(srv: k8s::policy::Server, cluster: &ClusterInfo) -> Self {
         Self {
             labels: srv.metadata.labels.into(),
             pod_selector: srv.spec.pod_selector,
-            port_ref: srv.spec.port,
+            port_ref: port_ref(srv.spec.port),
             protocol: proxy_protocol(srv.spec.proxy_protocol, cluster),
         }
     }
 }
 
+fn port_ref(port: Port) -> PortRef {
+    match port {
+        Port::Number(port) => PortRef::Numbers(Some(port).into_iter().collect()),
+        Port::Name(name) => PortRef::Name(name),
+        Port::Set(spec) => PortRef::Numbers(parse_portset(&spec).unwrap_or_else(|error| {
+            tracing::warn!(%spec, %error, "Invalid server ports");
+            Default::default()
+        })),
+    }
+}
+
 fn proxy_protocol(
     p: Option<k8s::policy::server::ProxyProtocol>,
     cluster: &ClusterInfo,
@@ -32,7 +53,7 @@ fn proxy_protocol(
         },
         Some(k8s::policy::server::ProxyProtocol::Http1) => ProxyProtocol::Http1,
         Some(k8s::policy::server::ProxyProtocol::Http2) => ProxyProtocol::Http2,
-        Some(k8s::policy::server::ProxyProtocol::Grpc) => ProxyProtocol::Http2,
+        Some(k8s::policy::server::ProxyProtocol::Grpc) => ProxyProtocol::Grpc,
         Some(k8s::policy::server::ProxyProtocol::Opaque) => ProxyProtocol::Opaque,
         Some(k8s::policy::server::ProxyProtocol::Tls) => ProxyProtocol::Tls,
     }
@@ -1,7 +1,7 @@
 use crate::ClusterInfo;
 use anyhow::Result;
 use linkerd_policy_controller_core::{
-    inbound::{ClientAuthentication, ClientAuthorization},
+    inbound::{AuthorizationAction, ClientAuthentication, ClientAuthorization},
     IdentityMatch, NetworkMatch,
 };
 use linkerd_policy_controller_k8s_api::{self as k8s, policy::server_authorization::MeshTls};
@@ -86,6 +86,7 @@ fn client_authz(
     Ok(ClientAuthorization {
         networks,
         authentication,
+        action: AuthorizationAction::Allow,
     })
 }
 
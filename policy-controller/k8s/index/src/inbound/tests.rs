@@ -1,7 +1,11 @@
 mod annotation;
 mod authorization_policy;
+mod external_workload;
+mod grpc_routes;
 mod http_routes;
+mod ratelimit_policy;
 mod server_authorization;
+mod server_ports;
 
 use crate::{
     defaults::DefaultPolicy,
@@ -13,8 +17,8 @@ use ahash::AHashMap as HashMap;
 use kubert::index::IndexNamespacedResource;
 use linkerd_policy_controller_core::{
     inbound::{
-        AuthorizationRef, ClientAuthentication, ClientAuthorization, HttpRoute, HttpRouteRef,
-        InboundServer, ProxyProtocol, ServerRef,
+        AuthorizationAction, AuthorizationRef, ClientAuthentication, ClientAuthorization,
+        GrpcRoute, GrpcRouteRef, HttpRoute, HttpRouteRef, InboundServer, ProxyProtocol, ServerRef,
     },
     IdentityMatch, IpNet, Ipv4Net, Ipv6Net, NetworkMatch,
 };
@@ -44,8 +48,9 @@ struct TestConfig {
     _tracing: tracing::subscriber::DefaultGuard,
 }
 
-const DEFAULTS: [DefaultPolicy; 5] = [
+const DEFAULTS: [DefaultPolicy; 6] = [
     DefaultPolicy::Deny,
+    DefaultPolicy::Audit,
     DefaultPolicy::Allow {
         authenticated_only: true,
         cluster_only: false,
@@ -136,6 +141,14 @@ fn mk_default_policy(
 
     match da {
         DefaultPolicy::Deny => None,
+        DefaultPolicy::Audit => Some((
+            AuthorizationRef::Default("audit"),
+            ClientAuthorization {
+                authentication: ClientAuthentication::Unauthenticated,
+                networks: all_nets,
+                action: AuthorizationAction::Audit,
+            },
+        )),
         DefaultPolicy::Allow {
             authenticated_only: true,
             cluster_only: false,
@@ -144,6 +157,7 @@ fn mk_default_policy(
             ClientAuthorization {
                 authentication: authed,
                 networks: all_nets,
+                action: AuthorizationAction::Allow,
             },
         )),
         DefaultPolicy::Allow {
@@ -154,6 +168,7 @@ fn mk_default_policy(
             ClientAuthorization {
                 authentication: ClientAuthentication::Unauthenticated,
                 networks: all_nets,
+                action: AuthorizationAction::Allow,
             },
         )),
         DefaultPolicy::Allow {
@@ -164,6 +179,7 @@ fn mk_default_policy(
             ClientAuthorization {
                 authentication: authed,
                 networks: cluster_nets,
+                action: AuthorizationAction::Allow,
             },
         )),
         DefaultPolicy::Allow {
@@ -174,6 +190,7 @@ fn mk_default_policy(
             ClientAuthorization {
                 authentication: ClientAuthentication::Unauthenticated,
                 networks: cluster_nets,
+                action: AuthorizationAction::Allow,
             },
         )),
     }
@@ -187,6 +204,12 @@ fn mk_default_routes() -> HashMap<HttpRouteRef, HttpRoute> {
         .collect()
 }
 
+fn mk_default_grpc_routes() -> HashMap<GrpcRouteRef, GrpcRoute> {
+    Some((GrpcRouteRef::Default("default"), GrpcRoute::default()))
+        .into_iter()
+        .collect()
+}
+
 impl TestConfig {
     fn from_default_policy(default_policy: DefaultPolicy) -> Self {
         Self::from_default_policy_with_probes(default_policy, vec![])
@@ -226,7 +249,9 @@ impl TestConfig {
             protocol: ProxyProtocol::Detect {
                 timeout: self.detect_timeout,
             },
+            ratelimit: None,
             http_routes: mk_default_routes(),
+            grpc_routes: mk_default_grpc_routes(),
         }
     }
 
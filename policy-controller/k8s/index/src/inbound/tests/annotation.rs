@@ -109,6 +109,7 @@ fn authenticated_annotated() {
                     authenticated_only: true,
                 },
                 DefaultPolicy::Deny => DefaultPolicy::Deny,
+                DefaultPolicy::Audit => DefaultPolicy::Audit,
             };
             InboundServer {
                 reference: ServerRef::Default(policy.as_str()),
@@ -116,7 +117,9 @@ fn authenticated_annotated() {
                 protocol: ProxyProtocol::Detect {
                     timeout: test.detect_timeout,
                 },
+                ratelimit: None,
                 http_routes: mk_default_routes(),
+                grpc_routes: mk_default_grpc_routes(),
             }
         };
 
@@ -128,3 +131,76 @@ fn authenticated_annotated() {
         assert_eq!(*rx.borrow(), config);
     }
 }
+
+/// Tests that pods inherit settings from their namespace's annotations unless
+/// the pod overrides them.
+#[test]
+fn namespace_annotated() {
+    let test = TestConfig::default();
+
+    let pod = mk_pod("ns-0", "pod-0", Some(("container-0", None)));
+    test.index.write().reset(vec![pod], Default::default());
+
+    let mut rx = test
+        .index
+        .write()
+        .pod_server_rx("ns-0", "pod-0", 2222.try_into().unwrap())
+        .expect("pod-0.ns-0 should exist");
+    assert_eq!(*rx.borrow_and_update(), test.default_server());
+
+    // Annotate the namespace and check that the pod's watch is updated.
+    let ns = k8s::Namespace {
+        metadata: k8s::ObjectMeta {
+            name: Some("ns-0".to_string()),
+            annotations: Some(
+                [
+                    ("config.linkerd.io/opaque-ports", "2222"),
+                    ("config.linkerd.io/default-inbound-policy", "deny"),
+                ]
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            ),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    <Index as kubert::index::IndexClusterResource<k8s::Namespace>>::apply(
+        &mut test.index.write(),
+        ns,
+    );
+    assert!(rx.has_changed().unwrap());
+    {
+        let server = rx.borrow_and_update();
+        assert_eq!(server.protocol, ProxyProtocol::Opaque);
+        assert_eq!(
+            server.reference,
+            ServerRef::Default(DefaultPolicy::Deny.as_str())
+        );
+    }
+
+    // A pod annotation overrides the namespace's.
+    let mut pod = mk_pod("ns-0", "pod-0", Some(("container-0", None)));
+    pod.annotations_mut().insert(
+        "config.linkerd.io/default-inbound-policy".into(),
+        test.default_policy.to_string(),
+    );
+    test.index.write().apply(pod);
+    assert!(rx.has_changed().unwrap());
+    {
+        let server = rx.borrow_and_update();
+        assert_eq!(server.protocol, ProxyProtocol::Opaque);
+        assert_eq!(
+            server.reference,
+            ServerRef::Default(test.default_policy.as_str())
+        );
+    }
+
+    // Removing the namespace's settings restores the defaults.
+    <Index as kubert::index::IndexClusterResource<k8s::Namespace>>::delete(
+        &mut test.index.write(),
+        "ns-0".to_string(),
+    );
+    assert!(rx.has_changed().unwrap());
+    assert_eq!(*rx.borrow(), test.default_server());
+}
@@ -31,7 +31,9 @@ fn links_authorization_policy_with_mtls_name() {
             reference: ServerRef::Server("srv-8080".to_string()),
             authorizations: Default::default(),
             protocol: ProxyProtocol::Http1,
+            ratelimit: None,
             http_routes: mk_default_routes(),
+            grpc_routes: mk_default_grpc_routes(),
         },
     );
 
@@ -40,6 +42,7 @@ fn links_authorization_policy_with_mtls_name() {
         authentication: ClientAuthentication::TlsAuthenticated(vec![IdentityMatch::Exact(
             "foo.bar".to_string(),
         )]),
+        action: AuthorizationAction::Allow,
     };
     test.index.write().apply(mk_authorization_policy(
         "ns-0",
@@ -85,7 +88,9 @@ fn links_authorization_policy_with_mtls_name() {
             .into_iter()
             .collect(),
             protocol: ProxyProtocol::Http1,
+            ratelimit: None,
             http_routes: mk_default_routes(),
+            grpc_routes: mk_default_grpc_routes(),
         },
     );
 }
@@ -121,7 +126,9 @@ fn authorization_targets_namespace() {
             reference: ServerRef::Server("srv-8080".to_string()),
             authorizations: Default::default(),
             protocol: ProxyProtocol::Http1,
+            ratelimit: None,
             http_routes: mk_default_routes(),
+            grpc_routes: mk_default_grpc_routes(),
         },
     );
 
@@ -130,6 +137,7 @@ fn authorization_targets_namespace() {
         authentication: ClientAuthentication::TlsAuthenticated(vec![IdentityMatch::Exact(
             "foo.bar".to_string(),
         )]),
+        action: AuthorizationAction::Allow,
     };
     test.index.write().apply(mk_authorization_policy(
         "ns-0",
@@ -175,7 +183,9 @@ fn authorization_targets_namespace() {
             .into_iter()
             .collect(),
             protocol: ProxyProtocol::Http1,
+            ratelimit: None,
             http_routes: mk_default_routes(),
+            grpc_routes: mk_default_grpc_routes(),
         },
     );
 }
@@ -211,7 +221,9 @@ fn links_authorization_policy_with_service_account() {
             reference: ServerRef::Server("srv-8080".to_string()),
             authorizations: Default::default(),
             protocol: ProxyProtocol::Http1,
+            ratelimit: None,
             http_routes: mk_default_routes(),
+            grpc_routes: mk_default_grpc_routes(),
         },
     );
 
@@ -220,6 +232,7 @@ fn links_authorization_policy_with_service_account() {
         authentication: ClientAuthentication::TlsAuthenticated(vec![IdentityMatch::Exact(
             "foo.ns-0.serviceaccount.identity.linkerd.cluster.example.com".to_string(),
         )]),
+        action: AuthorizationAction::Allow,
     };
     test.index.write().apply(mk_authorization_policy(
         "ns-0",
@@ -259,7 +272,9 @@ fn links_authorization_policy_with_service_account() {
             .into_iter()
             .collect(),
             protocol: ProxyProtocol::Http1,
+            ratelimit: None,
             http_routes: mk_default_routes(),
+            grpc_routes: mk_default_grpc_routes(),
         },
     );
 }
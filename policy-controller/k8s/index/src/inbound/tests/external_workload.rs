@@ -0,0 +1,179 @@
+use super::*;
+use linkerd_policy_controller_k8s_api::external_workload::{MeshTls, PortSpec, WorkloadIp};
+
+fn mk_external_workload(
+    ns: impl ToString,
+    name: impl ToString,
+    ports: impl IntoIterator<Item = (&'static str, u16)>,
+) -> k8s::ExternalWorkload {
+    k8s::ExternalWorkload {
+        metadata: k8s::ObjectMeta {
+            namespace: Some(ns.to_string()),
+            name: Some(name.to_string()),
+            labels: Some(
+                Some(("app".to_string(), "app-0".to_string()))
+                    .into_iter()
+                    .collect(),
+            ),
+            ..Default::default()
+        },
+        spec: k8s::ExternalWorkloadSpec {
+            mesh_tls: MeshTls {
+                identity: "vm-0.ns-0.serviceaccount.identity.linkerd.cluster.example.com"
+                    .to_string(),
+                server_name: "vm-0.ns-0.cluster.example.com".to_string(),
+            },
+            ports: Some(
+                ports
+                    .into_iter()
+                    .map(|(name, port)| PortSpec {
+                        name: Some(name.to_string()),
+                        port: port.try_into().unwrap(),
+                        protocol: None,
+                    })
+                    .collect(),
+            ),
+            workload_ips: Some(vec![WorkloadIp {
+                ip: "198.51.100.10".to_string(),
+            }]),
+        },
+    }
+}
+
+#[test]
+fn external_workload_must_exist_for_lookup() {
+    let test = TestConfig::default();
+    test.index
+        .write()
+        .apply(mk_external_workload("ns-0", "vm-0", None));
+
+    test.index
+        .write()
+        .external_workload_server_rx("ns-0", "vm-1", 8080.try_into().unwrap())
+        .expect_err("vm-1.ns-0 must not exist");
+    // Pods and external workloads are indexed separately.
+    test.index
+        .write()
+        .pod_server_rx("ns-0", "vm-0", 8080.try_into().unwrap())
+        .expect_err("pod vm-0.ns-0 must not exist");
+}
+
+#[test]
+fn server_selects_external_workload() {
+    let test = TestConfig::default();
+    test.index.write().apply(mk_external_workload(
+        "ns-0",
+        "vm-0",
+        Some(("admin-http", 8080)),
+    ));
+
+    let mut rx = test
+        .index
+        .write()
+        .external_workload_server_rx("ns-0", "vm-0", 8080.try_into().unwrap())
+        .expect("vm-0.ns-0 should exist");
+    assert_eq!(*rx.borrow_and_update(), test.default_server());
+
+    test.index.write().apply(mk_server(
+        "ns-0",
+        "srv-admin-http",
+        Port::Name("admin-http".to_string()),
+        None,
+        Some(("app", "app-0")),
+        Some(k8s::policy::server::ProxyProtocol::Http1),
+    ));
+    assert!(rx.has_changed().unwrap());
+    assert_eq!(
+        rx.borrow_and_update().reference,
+        ServerRef::Server("srv-admin-http".to_string())
+    );
+
+    // Unlike a pod's, an external workload's ports may change. When the named
+    // port moves, the old port is reset to the default server.
+    test.index.write().apply(mk_external_workload(
+        "ns-0",
+        "vm-0",
+        Some(("admin-http", 9090)),
+    ));
+    assert!(rx.has_changed().unwrap());
+    assert_eq!(*rx.borrow_and_update(), test.default_server());
+
+    let rx = test
+        .index
+        .write()
+        .external_workload_server_rx("ns-0", "vm-0", 9090.try_into().unwrap())
+        .expect("vm-0.ns-0 should exist");
+    assert_eq!(
+        rx.borrow().reference,
+        ServerRef::Server("srv-admin-http".to_string())
+    );
+}
+
+#[test]
+fn external_workload_labels_change() {
+    let test = TestConfig::default();
+    test.index
+        .write()
+        .apply(mk_external_workload("ns-0", "vm-0", Some(("http", 8080))));
+    test.index.write().apply(mk_server(
+        "ns-0",
+        "srv-http",
+        Port::Name("http".to_string()),
+        None,
+        Some(("app", "app-0")),
+        Some(k8s::policy::server::ProxyProtocol::Http1),
+    ));
+
+    let mut rx = test
+        .index
+        .write()
+        .external_workload_server_rx("ns-0", "vm-0", 8080.try_into().unwrap())
+        .expect("vm-0.ns-0 should exist");
+    assert_eq!(
+        rx.borrow_and_update().reference,
+        ServerRef::Server("srv-http".to_string())
+    );
+
+    // Reapplying an unchanged workload does not update the watch.
+    test.index
+        .write()
+        .apply(mk_external_workload("ns-0", "vm-0", Some(("http", 8080))));
+    assert!(!rx.has_changed().unwrap());
+
+    // When the workload's labels no longer match the server's selector, the
+    // port is reset to the default server.
+    let mut workload = mk_external_workload("ns-0", "vm-0", Some(("http", 8080)));
+    workload
+        .labels_mut()
+        .insert("app".to_string(), "app-1".to_string());
+    test.index.write().apply(workload);
+    assert!(rx.has_changed().unwrap());
+    assert_eq!(*rx.borrow_and_update(), test.default_server());
+}
+
+#[test]
+fn external_workload_delete() {
+    let test = TestConfig::default();
+    test.index
+        .write()
+        .apply(mk_external_workload("ns-0", "vm-0", Some(("http", 8080))));
+
+    let rx = test
+        .index
+        .write()
+        .external_workload_server_rx("ns-0", "vm-0", 8080.try_into().unwrap())
+        .expect("vm-0.ns-0 should exist");
+
+    <Index as IndexNamespacedResource<k8s::ExternalWorkload>>::delete(
+        &mut test.index.write(),
+        "ns-0".to_string(),
+        "vm-0".to_string(),
+    );
+
+    // Open watches complete once the workload is removed.
+    assert!(rx.has_changed().is_err());
+    test.index
+        .write()
+        .external_workload_server_rx("ns-0", "vm-0", 8080.try_into().unwrap())
+        .expect_err("vm-0.ns-0 must not exist");
+}
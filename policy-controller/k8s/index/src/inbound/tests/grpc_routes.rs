@@ -0,0 +1,221 @@
+use super::*;
+use linkerd_policy_controller_core::{
+    grpc_route::{GrpcMethodMatch, GrpcRouteMatch},
+    POLICY_CONTROLLER_NAME,
+};
+
+const POLICY_API_GROUP: &str = "policy.linkerd.io";
+const GATEWAY_API_GROUP: &str = "gateway.networking.k8s.io";
+
+#[test]
+fn route_attaches_to_server() {
+    let test = TestConfig::default();
+    // Create pod.
+    let mut pod = mk_pod("ns-0", "pod-0", Some(("container-0", None)));
+    pod.labels_mut()
+        .insert("app".to_string(), "app-0".to_string());
+    test.index.write().apply(pod);
+
+    let mut rx = test
+        .index
+        .write()
+        .pod_server_rx("ns-0", "pod-0", 8080.try_into().unwrap())
+        .expect("pod-0.ns-0 should exist");
+    assert_eq!(*rx.borrow_and_update(), test.default_server());
+
+    // Create server.
+    test.index.write().apply(mk_server(
+        "ns-0",
+        "srv-8080",
+        Port::Number(8080.try_into().unwrap()),
+        Some(("app", "app-0")),
+        Some(("app", "app-0")),
+        Some(k8s::policy::server::ProxyProtocol::Grpc),
+    ));
+    assert!(rx.has_changed().unwrap());
+    assert_eq!(
+        *rx.borrow_and_update(),
+        InboundServer {
+            reference: ServerRef::Server("srv-8080".to_string()),
+            authorizations: Default::default(),
+            protocol: ProxyProtocol::Grpc,
+            ratelimit: None,
+            http_routes: mk_default_routes(),
+            grpc_routes: mk_default_grpc_routes(),
+        },
+    );
+
+    // Create route.
+    test.index
+        .write()
+        .apply(mk_route("ns-0", "route-foo", "srv-8080"));
+    assert!(rx.has_changed().unwrap());
+    {
+        let server = rx.borrow_and_update();
+        assert_eq!(server.reference, ServerRef::Server("srv-8080".to_string()));
+        let route = &server.grpc_routes[&GrpcRouteRef::Gateway("route-foo".to_string())];
+        assert_eq!(
+            route.rules[0].matches,
+            vec![GrpcRouteMatch {
+                method: Some(GrpcMethodMatch {
+                    service: Some("io.linkerd.Foo".to_string()),
+                    method: Some("Bar".to_string()),
+                }),
+                headers: vec![],
+            }]
+        );
+        assert!(!server
+            .grpc_routes
+            .contains_key(&GrpcRouteRef::Default("default")));
+    }
+
+    // Create authz policy.
+    test.index.write().apply(mk_authorization_policy(
+        "ns-0",
+        "authz-foo",
+        "route-foo",
+        vec![NamespacedTargetRef {
+            group: None,
+            kind: "ServiceAccount".to_string(),
+            namespace: Some("ns-0".to_string()),
+            name: "foo".to_string(),
+        }],
+    ));
+
+    assert!(rx.has_changed().unwrap());
+    assert!(
+        rx.borrow().grpc_routes[&GrpcRouteRef::Gateway("route-foo".to_string())]
+            .authorizations
+            .contains_key(&AuthorizationRef::AuthorizationPolicy(
+                "authz-foo".to_string()
+            ))
+    );
+
+    // Delete route.
+    <Index as IndexNamespacedResource<k8s::gateway::GrpcRoute>>::delete(
+        &mut test.index.write(),
+        "ns-0".to_string(),
+        "route-foo".to_string(),
+    );
+    assert!(rx.has_changed().unwrap());
+    assert_eq!(rx.borrow_and_update().grpc_routes, mk_default_grpc_routes());
+}
+
+#[test]
+fn route_requires_accepted_status() {
+    let test = TestConfig::default();
+    let mut pod = mk_pod("ns-0", "pod-0", Some(("container-0", None)));
+    pod.labels_mut()
+        .insert("app".to_string(), "app-0".to_string());
+    test.index.write().apply(pod);
+
+    test.index.write().apply(mk_server(
+        "ns-0",
+        "srv-8080",
+        Port::Number(8080.try_into().unwrap()),
+        Some(("app", "app-0")),
+        Some(("app", "app-0")),
+        Some(k8s::policy::server::ProxyProtocol::Grpc),
+    ));
+
+    let mut rx = test
+        .index
+        .write()
+        .pod_server_rx("ns-0", "pod-0", 8080.try_into().unwrap())
+        .expect("pod-0.ns-0 should exist");
+    rx.borrow_and_update();
+
+    // A route without an accepted status is not bound to the server.
+    let mut route = mk_route("ns-0", "route-foo", "srv-8080");
+    route.status = None;
+    test.index.write().apply(route);
+    assert_eq!(rx.borrow_and_update().grpc_routes, mk_default_grpc_routes());
+}
+
+fn mk_route(
+    ns: impl ToString,
+    name: impl ToString,
+    server: impl ToString,
+) -> k8s::gateway::GrpcRoute {
+    use chrono::Utc;
+    use k8s::{gateway::*, Time};
+
+    GrpcRoute {
+        metadata: k8s::ObjectMeta {
+            namespace: Some(ns.to_string()),
+            name: Some(name.to_string()),
+            creation_timestamp: Some(Time(Utc::now())),
+            ..Default::default()
+        },
+        spec: GrpcRouteSpec {
+            inner: CommonRouteSpec {
+                parent_refs: Some(vec![ParentReference {
+                    group: Some(POLICY_API_GROUP.to_string()),
+                    kind: Some("Server".to_string()),
+                    namespace: None,
+                    name: server.to_string(),
+                    section_name: None,
+                    port: None,
+                }]),
+            },
+            hostnames: None,
+            rules: Some(vec![GrpcRouteRule {
+                matches: Some(vec![k8s::gateway::GrpcRouteMatch {
+                    method: Some(k8s::gateway::GrpcMethodMatch::Exact {
+                        service: Some("io.linkerd.Foo".to_string()),
+                        method: Some("Bar".to_string()),
+                    }),
+                    headers: None,
+                }]),
+                filters: None,
+                backend_refs: None,
+            }]),
+        },
+        status: Some(GrpcRouteStatus {
+            inner: RouteStatus {
+                parents: vec![RouteParentStatus {
+                    parent_ref: ParentReference {
+                        group: Some(POLICY_API_GROUP.to_string()),
+                        kind: Some("Server".to_string()),
+                        namespace: None,
+                        name: server.to_string(),
+                        section_name: None,
+                        port: None,
+                    },
+                    controller_name: POLICY_CONTROLLER_NAME.to_string(),
+                    conditions: vec![k8s::Condition {
+                        last_transition_time: k8s::Time(chrono::DateTime::<chrono::Utc>::MIN_UTC),
+                        message: "".to_string(),
+                        observed_generation: None,
+                        reason: "Accepted".to_string(),
+                        status: "True".to_string(),
+                        type_: "Accepted".to_string(),
+                    }],
+                }],
+            },
+        }),
+    }
+}
+
+fn mk_authorization_policy(
+    ns: impl ToString,
+    name: impl ToString,
+    route: impl ToString,
+    authns: impl IntoIterator<Item = NamespacedTargetRef>,
+) -> k8s::policy::AuthorizationPolicy {
+    k8s::policy::AuthorizationPolicy {
+        metadata: k8s::ObjectMeta {
+            namespace: Some(ns.to_string()),
+            name: Some(name.to_string()),
+            ..Default::default()
+        },
+        spec: k8s::policy::AuthorizationPolicySpec {
+            target_ref: LocalTargetRef {
+                group: Some(GATEWAY_API_GROUP.to_string()),
+                kind: "GRPCRoute".to_string(),
+                name: route.to_string(),
+            },
+            required_authentication_refs: authns.into_iter().collect(),
+        },
+    }
+}
@@ -1,6 +1,7 @@
 use super::*;
 use linkerd_policy_controller_core::{
-    http_route::{HttpRouteMatch, Method, PathMatch},
+    http_route::{FailureInjectorFilter, HttpRouteMatch, Method, PathMatch, Ratio, StatusCode},
+    inbound::Filter,
     POLICY_CONTROLLER_NAME,
 };
 
@@ -38,7 +39,9 @@ fn route_attaches_to_server() {
             reference: ServerRef::Server("srv-8080".to_string()),
             authorizations: Default::default(),
             protocol: ProxyProtocol::Http1,
+            ratelimit: None,
             http_routes: mk_default_routes(),
+            grpc_routes: mk_default_grpc_routes(),
         },
     );
 
@@ -79,6 +82,60 @@ fn route_attaches_to_server() {
     );
 }
 
+#[test]
+fn route_with_failure_injector() {
+    let test = TestConfig::default();
+    let mut pod = mk_pod("ns-0", "pod-0", Some(("container-0", None)));
+    pod.labels_mut()
+        .insert("app".to_string(), "app-0".to_string());
+    test.index.write().apply(pod);
+
+    test.index.write().apply(mk_server(
+        "ns-0",
+        "srv-8080",
+        Port::Number(8080.try_into().unwrap()),
+        Some(("app", "app-0")),
+        Some(("app", "app-0")),
+        Some(k8s::policy::server::ProxyProtocol::Http1),
+    ));
+
+    let mut rx = test
+        .index
+        .write()
+        .pod_server_rx("ns-0", "pod-0", 8080.try_into().unwrap())
+        .expect("pod-0.ns-0 should exist");
+    rx.borrow_and_update();
+
+    let mut route = mk_route("ns-0", "route-foo", "srv-8080");
+    route.spec.rules.as_mut().unwrap()[0].filters = Some(vec![
+        k8s::policy::httproute::HttpRouteFilter::RequestFailureInjector {
+            request_failure_injector: k8s::policy::httproute::HttpRequestFailureInjectorFilter {
+                status: Some(503),
+                message: Some("chaos".to_string()),
+                ratio: Some(k8s::policy::httproute::Ratio {
+                    numerator: 10,
+                    denominator: None,
+                }),
+            },
+        },
+    ]);
+    test.index.write().apply(route);
+    assert!(rx.has_changed().unwrap());
+    let server = rx.borrow_and_update();
+    let route = &server.http_routes[&HttpRouteRef::Linkerd("route-foo".to_string())];
+    assert_eq!(
+        route.rules[0].filters,
+        vec![Filter::FailureInjector(FailureInjectorFilter {
+            status: StatusCode::SERVICE_UNAVAILABLE,
+            message: "chaos".to_string(),
+            ratio: Ratio {
+                numerator: 10,
+                denominator: 100,
+            },
+        })]
+    );
+}
+
 #[test]
 fn routes_created_for_probes() {
     let policy = DefaultPolicy::Allow {
@@ -125,6 +182,7 @@ fn routes_created_for_probes() {
         ClientAuthorization {
             networks: vec!["10.0.0.1/24".parse::<IpNet>().unwrap().into()],
             authentication: ClientAuthentication::Unauthenticated,
+            action: AuthorizationAction::Allow,
         },
     );
     let liveness_match = HttpRouteMatch {
@@ -207,6 +265,58 @@ fn routes_created_for_probes() {
         .contains_key(&HttpRouteRef::Default("probes")));
 }
 
+#[test]
+fn probe_routes_audited_by_default() {
+    let probe_networks = vec!["10.0.0.1/24".parse().unwrap()];
+    let test = TestConfig::from_default_policy_with_probes(DefaultPolicy::Audit, probe_networks);
+
+    let container = k8s::Container {
+        liveness_probe: Some(k8s::Probe {
+            http_get: Some(k8s::HTTPGetAction {
+                path: Some("/liveness-container-1".to_string()),
+                port: k8s::IntOrString::Int(5432),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    test.index
+        .write()
+        .apply(mk_pod_with_containers("ns-0", "pod-0", Some(container)));
+
+    let rx = test
+        .index
+        .write()
+        .pod_server_rx("ns-0", "pod-0", 5432.try_into().unwrap())
+        .expect("pod-0.ns-0 should exist");
+
+    // Probes from outside the probe networks would be denied by the probe
+    // route, so they must be audited as well.
+    let update = rx.borrow();
+    let probes = update
+        .http_routes
+        .get(&HttpRouteRef::Default("probe"))
+        .unwrap();
+    assert_eq!(
+        probes
+            .authorizations
+            .get(&AuthorizationRef::Default("probe")),
+        Some(&ClientAuthorization {
+            networks: vec!["10.0.0.1/24".parse::<IpNet>().unwrap().into()],
+            authentication: ClientAuthentication::Unauthenticated,
+            action: AuthorizationAction::Allow,
+        }),
+    );
+    assert_eq!(
+        probes
+            .authorizations
+            .get(&AuthorizationRef::Default("audit"))
+            .map(|authz| authz.action),
+        Some(AuthorizationAction::Audit),
+    );
+}
+
 fn mk_route(
     ns: impl ToString,
     name: impl ToString,
@@ -245,6 +355,7 @@ fn mk_route(
                 }]),
                 filters: None,
                 backend_refs: None,
+                timeouts: None,
             }]),
         },
         status: Some(k8s::policy::httproute::HttpRouteStatus {
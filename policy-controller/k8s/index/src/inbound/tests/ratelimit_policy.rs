@@ -0,0 +1,187 @@
+use super::*;
+use linkerd_policy_controller_core::inbound::{Limit, RateLimit, RateLimitOverride};
+
+#[test]
+fn links_ratelimit_policy_to_server() {
+    let test = TestConfig::default();
+
+    let mut pod = mk_pod("ns-0", "pod-0", Some(("container-0", None)));
+    pod.labels_mut()
+        .insert("app".to_string(), "app-0".to_string());
+    test.index.write().apply(pod);
+
+    let mut rx = test
+        .index
+        .write()
+        .pod_server_rx("ns-0", "pod-0", 8080.try_into().unwrap())
+        .expect("pod-0.ns-0 should exist");
+    assert_eq!(*rx.borrow_and_update(), test.default_server());
+
+    test.index.write().apply(mk_server(
+        "ns-0",
+        "srv-8080",
+        Port::Number(8080.try_into().unwrap()),
+        None,
+        Some(("app", "app-0")),
+        Some(k8s::policy::server::ProxyProtocol::Http1),
+    ));
+    assert!(rx.has_changed().unwrap());
+    assert_eq!(rx.borrow_and_update().ratelimit, None);
+
+    test.index.write().apply(mk_ratelimit_policy(
+        "ns-0",
+        "rl-foo",
+        "srv-8080",
+        Some(100),
+        Some(10),
+        vec![(20, "sa-0")],
+    ));
+    assert!(rx.has_changed().unwrap());
+    assert_eq!(
+        *rx.borrow_and_update(),
+        InboundServer {
+            reference: ServerRef::Server("srv-8080".to_string()),
+            authorizations: Default::default(),
+            protocol: ProxyProtocol::Http1,
+            ratelimit: Some(RateLimit {
+                name: "rl-foo".to_string(),
+                total: Some(Limit {
+                    requests_per_second: 100,
+                }),
+                identity: Some(Limit {
+                    requests_per_second: 10,
+                }),
+                overrides: vec![RateLimitOverride {
+                    requests_per_second: 20,
+                    client_identities: vec![
+                        "sa-0.ns-0.serviceaccount.identity.linkerd.cluster.example.com".to_string()
+                    ],
+                }],
+            }),
+            http_routes: mk_default_routes(),
+            grpc_routes: mk_default_grpc_routes(),
+        },
+    );
+
+    <Index as IndexNamespacedResource<k8s::policy::HttpLocalRateLimitPolicy>>::delete(
+        &mut test.index.write(),
+        "ns-0".to_string(),
+        "rl-foo".to_string(),
+    );
+    assert!(rx.has_changed().unwrap());
+    assert_eq!(rx.borrow_and_update().ratelimit, None);
+}
+
+#[test]
+fn ignores_invalid_ratelimit_policy() {
+    let test = TestConfig::default();
+
+    let mut pod = mk_pod("ns-0", "pod-0", Some(("container-0", None)));
+    pod.labels_mut()
+        .insert("app".to_string(), "app-0".to_string());
+    test.index.write().apply(pod);
+
+    test.index.write().apply(mk_server(
+        "ns-0",
+        "srv-8080",
+        Port::Number(8080.try_into().unwrap()),
+        None,
+        Some(("app", "app-0")),
+        Some(k8s::policy::server::ProxyProtocol::Http1),
+    ));
+
+    let mut rx = test
+        .index
+        .write()
+        .pod_server_rx("ns-0", "pod-0", 8080.try_into().unwrap())
+        .expect("pod-0.ns-0 should exist");
+    assert_eq!(rx.borrow_and_update().ratelimit, None);
+
+    // The per-identity limit may not exceed the total limit.
+    test.index.write().apply(mk_ratelimit_policy(
+        "ns-0",
+        "rl-foo",
+        "srv-8080",
+        Some(10),
+        Some(100),
+        vec![],
+    ));
+    assert!(!rx.has_changed().unwrap());
+}
+
+#[test]
+fn ignores_http_route_target() {
+    let test = TestConfig::default();
+
+    let mut pod = mk_pod("ns-0", "pod-0", Some(("container-0", None)));
+    pod.labels_mut()
+        .insert("app".to_string(), "app-0".to_string());
+    test.index.write().apply(pod);
+
+    test.index.write().apply(mk_server(
+        "ns-0",
+        "srv-8080",
+        Port::Number(8080.try_into().unwrap()),
+        None,
+        Some(("app", "app-0")),
+        Some(k8s::policy::server::ProxyProtocol::Http1),
+    ));
+
+    let mut rx = test
+        .index
+        .write()
+        .pod_server_rx("ns-0", "pod-0", 8080.try_into().unwrap())
+        .expect("pod-0.ns-0 should exist");
+    assert_eq!(rx.borrow_and_update().ratelimit, None);
+
+    let mut rl = mk_ratelimit_policy("ns-0", "rl-foo", "route-0", Some(100), None, vec![]);
+    rl.spec.target_ref.kind = "HTTPRoute".to_string();
+    test.index.write().apply(rl);
+    assert!(!rx.has_changed().unwrap());
+}
+
+fn mk_ratelimit_policy(
+    ns: impl ToString,
+    name: impl ToString,
+    server: impl ToString,
+    total: Option<u32>,
+    identity: Option<u32>,
+    overrides: impl IntoIterator<Item = (u32, &'static str)>,
+) -> k8s::policy::HttpLocalRateLimitPolicy {
+    use k8s::policy::ratelimit_policy::{Limit, Override};
+
+    k8s::policy::HttpLocalRateLimitPolicy {
+        metadata: k8s::ObjectMeta {
+            namespace: Some(ns.to_string()),
+            name: Some(name.to_string()),
+            ..Default::default()
+        },
+        spec: k8s::policy::RateLimitPolicySpec {
+            target_ref: LocalTargetRef {
+                group: Some("policy.linkerd.io".to_string()),
+                kind: "Server".to_string(),
+                name: server.to_string(),
+            },
+            total: total.map(|requests_per_second| Limit {
+                requests_per_second,
+            }),
+            identity: identity.map(|requests_per_second| Limit {
+                requests_per_second,
+            }),
+            overrides: Some(
+                overrides
+                    .into_iter()
+                    .map(|(requests_per_second, sa)| Override {
+                        requests_per_second,
+                        client_refs: vec![NamespacedTargetRef {
+                            group: None,
+                            kind: "ServiceAccount".to_string(),
+                            name: sa.to_string(),
+                            namespace: None,
+                        }],
+                    })
+                    .collect(),
+            ),
+        },
+    }
+}
@@ -43,7 +43,9 @@ fn link_server_authz(selector: ServerSelector) {
             reference: ServerRef::Server("srv-8080".to_string()),
             authorizations: Default::default(),
             protocol: ProxyProtocol::Http1,
+            ratelimit: None,
             http_routes: mk_default_routes(),
+            grpc_routes: mk_default_grpc_routes(),
         },
     );
     test.index.write().apply(mk_server_authz(
@@ -0,0 +1,119 @@
+use super::*;
+
+#[test]
+fn server_selects_port_set() {
+    let test = TestConfig::default();
+
+    let mut pod = mk_pod("ns-0", "pod-0", Some(("container-0", None)));
+    pod.labels_mut()
+        .insert("app".to_string(), "app-0".to_string());
+    test.index.write().apply(pod);
+
+    let mut rxs = [8080, 9000, 9050, 9100]
+        .into_iter()
+        .map(|port| {
+            test.index
+                .write()
+                .pod_server_rx("ns-0", "pod-0", port.try_into().unwrap())
+                .expect("pod-0.ns-0 should exist")
+        })
+        .collect::<Vec<_>>();
+    let mut rx_9101 = test
+        .index
+        .write()
+        .pod_server_rx("ns-0", "pod-0", 9101.try_into().unwrap())
+        .expect("pod-0.ns-0 should exist");
+    for rx in rxs.iter_mut().chain(Some(&mut rx_9101)) {
+        assert_eq!(*rx.borrow_and_update(), test.default_server());
+    }
+
+    test.index.write().apply(mk_server(
+        "ns-0",
+        "srv-0",
+        Port::Set("8080,9000-9100".to_string()),
+        None,
+        Some(("app", "app-0")),
+        Some(k8s::policy::server::ProxyProtocol::Opaque),
+    ));
+    for rx in rxs.iter_mut() {
+        assert!(rx.has_changed().unwrap());
+        let server = rx.borrow_and_update();
+        assert_eq!(server.reference, ServerRef::Server("srv-0".to_string()));
+        assert_eq!(server.protocol, ProxyProtocol::Opaque);
+    }
+    assert!(!rx_9101.has_changed().unwrap());
+
+    // Ports that are not discovered before the server is applied are also
+    // selected.
+    let rx = test
+        .index
+        .write()
+        .pod_server_rx("ns-0", "pod-0", 9099.try_into().unwrap())
+        .expect("pod-0.ns-0 should exist");
+    assert_eq!(
+        rx.borrow().reference,
+        ServerRef::Server("srv-0".to_string())
+    );
+}
+
+#[test]
+fn named_and_numeric_server_conflict() {
+    // Regardless of the order in which conflicting servers are applied, the
+    // server that sorts first by name selects the port.
+    for reversed in [false, true] {
+        let test = TestConfig::default();
+
+        let mut pod = mk_pod(
+            "ns-0",
+            "pod-0",
+            Some((
+                "container-0",
+                Some(ContainerPort {
+                    name: Some("admin-http".to_string()),
+                    container_port: 8080,
+                    protocol: Some("TCP".to_string()),
+                    ..ContainerPort::default()
+                }),
+            )),
+        );
+        pod.labels_mut()
+            .insert("app".to_string(), "app-0".to_string());
+        test.index.write().apply(pod);
+
+        let mut servers = vec![
+            mk_server(
+                "ns-0",
+                "srv-a",
+                Port::Name("admin-http".to_string()),
+                None,
+                Some(("app", "app-0")),
+                Some(k8s::policy::server::ProxyProtocol::Http1),
+            ),
+            mk_server(
+                "ns-0",
+                "srv-b",
+                Port::Number(8080.try_into().unwrap()),
+                None,
+                Some(("app", "app-0")),
+                Some(k8s::policy::server::ProxyProtocol::Opaque),
+            ),
+        ];
+        if reversed {
+            servers.reverse();
+        }
+        for server in servers {
+            test.index.write().apply(server);
+        }
+
+        let rx = test
+            .index
+            .write()
+            .pod_server_rx("ns-0", "pod-0", 8080.try_into().unwrap())
+            .expect("pod-0.ns-0 should exist");
+        assert_eq!(
+            rx.borrow().reference,
+            ServerRef::Server("srv-a".to_string())
+        );
+        assert_eq!(rx.borrow().protocol, ProxyProtocol::Http1);
+    }
+}
@@ -1,8 +1,9 @@
 //! The policy controller serves discovery requests from inbound proxies, indicating how the proxy
 //! should admit connections into a Pod. It watches the following cluster resources:
 //!
-//! - A `Namespace` may be annotated with a default-allow policy that applies to all pods in the
-//!   namespace (unless they are annotated with a default policy).
+//! - A `Namespace` may be annotated with a default-allow policy, opaque ports, and ports that
+//!   require identity. These apply to all pods in the namespace (unless the pods are annotated
+//!   with their own settings).
 //! - Each `Pod` enumerate its ports. We maintain an index of each pod's ports, linked to `Server`
 //!   objects.
 //! - Each `Server` selects over pods in the same namespace.
@@ -25,6 +26,7 @@
 
 mod cluster_info;
 mod defaults;
+pub mod grpc_route;
 pub mod http_route;
 pub mod inbound;
 pub mod outbound;
@@ -32,4 +34,4 @@ pub mod ports;
 
 pub use cluster_info::ClusterInfo;
 pub use defaults::DefaultPolicy;
-pub use inbound::authorization_policy;
+pub use inbound::{authorization_policy, ratelimit_policy};
@@ -1,3 +1,9 @@
+pub mod egress_network;
 pub mod index;
 
-pub use index::{Index, ServiceRef, SharedIndex};
+#[cfg(test)]
+mod tests;
+
+pub use index::{
+    validate_service_annotations, ExternalWorkloadRef, Index, ServiceRef, SharedIndex,
+};
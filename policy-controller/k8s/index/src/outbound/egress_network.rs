@@ -0,0 +1,177 @@
+use anyhow::{bail, Result};
+use linkerd_policy_controller_core::{outbound::TrafficPolicy, IpNet, NetworkMatch};
+use linkerd_policy_controller_k8s_api::policy::{egress_network, EgressNetworkSpec};
+use std::net::IpAddr;
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct Spec {
+    pub hostnames: Vec<HostnameMatch>,
+    pub networks: Vec<NetworkMatch>,
+    pub traffic_policy: TrafficPolicy,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum HostnameMatch {
+    Exact(String),
+
+    /// Matches all subdomains of a domain. The suffix includes the leading
+    /// `.`.
+    Suffix(String),
+}
+
+#[inline]
+pub fn validate(spec: EgressNetworkSpec) -> Result<()> {
+    Spec::try_from(spec)?;
+    Ok(())
+}
+
+impl TryFrom<EgressNetworkSpec> for Spec {
+    type Error = anyhow::Error;
+
+    fn try_from(spec: EgressNetworkSpec) -> Result<Self> {
+        let hostnames = spec
+            .hostnames
+            .into_iter()
+            .flatten()
+            .map(hostname_match)
+            .collect::<Result<Vec<_>>>()?;
+
+        let networks = spec
+            .networks
+            .into_iter()
+            .flatten()
+            .map(|net| {
+                let except = net
+                    .except
+                    .into_iter()
+                    .flatten()
+                    .map(|except| {
+                        if !net.cidr.contains(&except) {
+                            bail!("cidr '{}' does not include exception '{except}'", net.cidr);
+                        }
+                        if except.contains(&net.cidr) {
+                            bail!(
+                                "cidr '{}' is completely negated by exception '{except}'",
+                                net.cidr
+                            );
+                        }
+                        Ok(IpNet::from(except))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(NetworkMatch {
+                    net: net.cidr.into(),
+                    except,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        if hostnames.is_empty() && networks.is_empty() {
+            bail!("at least one hostname or network must be specified");
+        }
+
+        let traffic_policy = match spec.traffic_policy {
+            egress_network::TrafficPolicy::Allow => TrafficPolicy::Allow,
+            egress_network::TrafficPolicy::Deny => TrafficPolicy::Deny,
+        };
+
+        Ok(Spec {
+            hostnames,
+            networks,
+            traffic_policy,
+        })
+    }
+}
+
+fn hostname_match(hostname: String) -> Result<HostnameMatch> {
+    let hostname = hostname.trim_end_matches('.').to_ascii_lowercase();
+    let (name, wildcard) = match hostname.strip_prefix("*.") {
+        Some(name) => (name, true),
+        None => (hostname.as_str(), false),
+    };
+    if name.is_empty()
+        || name
+            .split('.')
+            .any(|label| label.is_empty() || label.contains('*'))
+    {
+        bail!("invalid hostname: {hostname}");
+    }
+    if wildcard {
+        Ok(HostnameMatch::Suffix(format!(".{name}")))
+    } else {
+        Ok(HostnameMatch::Exact(hostname))
+    }
+}
+
+// === impl Spec ===
+
+impl Spec {
+    /// Returns the prefix length of the most specific network that includes
+    /// `addr`, if any.
+    pub(crate) fn match_ip(&self, addr: IpAddr) -> Option<u8> {
+        self.networks
+            .iter()
+            .filter(|m| m.net.contains(&addr) && !m.except.iter().any(|e| e.contains(&addr)))
+            .map(|m| m.net.prefix_len())
+            .max()
+    }
+
+    /// Returns a score for the most specific hostname that matches `host`, if
+    /// any. Exact matches are preferred over wildcards, and longer wildcards
+    /// are preferred over shorter ones.
+    pub(crate) fn match_hostname(&self, host: &str) -> Option<usize> {
+        let host = host.trim_end_matches('.').to_ascii_lowercase();
+        self.hostnames
+            .iter()
+            .filter_map(|m| match m {
+                HostnameMatch::Exact(name) if *name == host => Some(usize::MAX),
+                HostnameMatch::Suffix(suffix) if host.ends_with(suffix.as_str()) => {
+                    Some(suffix.len())
+                }
+                _ => None,
+            })
+            .max()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linkerd_policy_controller_k8s_api::policy::Network;
+
+    fn mk_spec(hostnames: &[&str], networks: &[&str]) -> Spec {
+        Spec::try_from(EgressNetworkSpec {
+            hostnames: Some(hostnames.iter().map(ToString::to_string).collect()),
+            networks: Some(
+                networks
+                    .iter()
+                    .map(|net| Network {
+                        cidr: net.parse().unwrap(),
+                        except: None,
+                    })
+                    .collect(),
+            ),
+            traffic_policy: egress_network::TrafficPolicy::Allow,
+        })
+        .expect("spec must be valid")
+    }
+
+    #[test]
+    fn matches_most_specific_network() {
+        let spec = mk_spec(&[], &["0.0.0.0/0", "203.0.113.0/24"]);
+        assert_eq!(spec.match_ip("203.0.113.10".parse().unwrap()), Some(24));
+        assert_eq!(spec.match_ip("198.51.100.10".parse().unwrap()), Some(0));
+        assert_eq!(spec.match_ip("2001:db8::1".parse().unwrap()), None);
+    }
+
+    #[test]
+    fn prefers_exact_hostnames() {
+        let spec = mk_spec(&["api.example.com", "*.example.com"], &[]);
+        assert_eq!(spec.match_hostname("API.example.com."), Some(usize::MAX));
+        assert_eq!(
+            spec.match_hostname("www.example.com"),
+            Some(".example.com".len())
+        );
+        assert_eq!(spec.match_hostname("example.com"), None);
+        assert_eq!(spec.match_hostname("example.org"), None);
+    }
+}
@@ -1,17 +1,26 @@
+use super::egress_network;
 use crate::{
-    http_route,
+    grpc_route, http_route,
     ports::{ports_annotation, PortSet},
     ClusterInfo,
 };
-use ahash::AHashMap as HashMap;
-use anyhow::{bail, ensure, Result};
-use k8s_gateway_api::{BackendObjectReference, HttpBackendRef, ParentReference};
+use ahash::{AHashMap as HashMap, AHashSet as HashSet};
+use anyhow::{anyhow, bail, ensure, Result};
 use linkerd_policy_controller_core::outbound::{
-    Backend, Backoff, FailureAccrual, HttpRoute, HttpRouteRule, OutboundPolicy, WeightedService,
+    Backend, Backoff, CircuitBreaker, FailureAccrual, Filter, GrpcRetryStatus, GrpcRoute,
+    GrpcRouteRule, HttpRoute, HttpRouteRule, OutboundPolicy, ParentKind, RetryPolicy, TcpRoute,
+    TcpRouteRule, TlsRoute, TrafficPolicy, WeightedService,
+};
+use linkerd_policy_controller_k8s_api::{
+    gateway::{self, BackendObjectReference, BackendRef, ParentReference, RouteStatus},
+    policy as api, EndpointSlice, ExternalWorkload, IntOrString, Resource, ResourceExt, Service,
+    Time,
 };
-use linkerd_policy_controller_k8s_api::{policy as api, ResourceExt, Service, Time};
 use parking_lot::RwLock;
-use std::{hash::Hash, net::IpAddr, num::NonZeroU16, sync::Arc, time};
+use std::{
+    collections::BTreeMap, hash::Hash, net::IpAddr, num::NonZeroU16, ops::RangeInclusive,
+    sync::Arc, time,
+};
 use tokio::sync::watch;
 
 #[derive(Debug)]
@@ -19,16 +28,85 @@ pub struct Index {
     namespaces: NamespaceIndex,
     services_by_ip: HashMap<IpAddr, ServiceRef>,
     service_info: HashMap<ServiceRef, ServiceInfo>,
+
+    /// Endpoint addresses of headless and selector-based Services, indexed
+    /// from EndpointSlices so that traffic addressed directly to pods can be
+    /// resolved to a Service.
+    endpoint_slices: HashMap<EndpointSliceRef, EndpointSliceInfo>,
+    slices_by_ip: HashMap<IpAddr, HashSet<EndpointSliceRef>>,
+
+    /// ExternalWorkloads, i.e. meshed workloads outside of the cluster. These
+    /// are resolved to the Services that select them.
+    external_workloads: HashMap<ExternalWorkloadRef, ExternalWorkloadInfo>,
+    external_workloads_by_ip: HashMap<IpAddr, ExternalWorkloadRef>,
+
+    /// ExternalName Services by the DNS name they alias.
+    services_by_external_name: HashMap<String, HashSet<ServiceRef>>,
+
+    /// Egress networks by namespace and name.
+    egress_networks: HashMap<String, HashMap<String, egress_network::Spec>>,
+
+    reference_grants: ReferenceGrants,
+
+    /// Routes with backends in other namespaces. These routes are reindexed
+    /// when ReferenceGrants change.
+    cross_namespace_routes: HashMap<RouteRef, CrossNamespaceRoute>,
 }
 
 pub type SharedIndex = Arc<RwLock<Index>>;
 
+/// ReferenceGrant specs by namespace and name.
+type ReferenceGrants = HashMap<String, HashMap<String, gateway::ReferenceGrantSpec>>;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct EndpointSliceRef {
+    namespace: String,
+    name: String,
+}
+
+#[derive(Debug)]
+struct EndpointSliceInfo {
+    service: ServiceRef,
+    addrs: Vec<IpAddr>,
+    /// Endpoint ports, which are named after the Service ports they serve.
+    ports: Vec<(String, NonZeroU16)>,
+}
+
+#[derive(Debug)]
+struct ExternalWorkloadInfo {
+    addrs: Vec<IpAddr>,
+    labels: BTreeMap<String, String>,
+    /// Workload ports by name, used to resolve named Service target ports.
+    ports: Vec<(Option<String>, NonZeroU16)>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct RouteRef {
+    kind: &'static str,
+    namespace: String,
+    name: String,
+}
+
+#[derive(Clone, Debug)]
+enum CrossNamespaceRoute {
+    Http(api::HttpRoute),
+    Grpc(gateway::GrpcRoute),
+    Tcp(gateway::TcpRoute),
+    Tls(gateway::TlsRoute),
+}
+
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub struct ServiceRef {
     pub name: String,
     pub namespace: String,
 }
 
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct ExternalWorkloadRef {
+    pub name: String,
+    pub namespace: String,
+}
+
 /// Holds all `Pod`, `Server`, and `ServerAuthorization` indices by-namespace.
 #[derive(Debug)]
 struct NamespaceIndex {
@@ -39,13 +117,29 @@ struct NamespaceIndex {
 #[derive(Debug)]
 struct Namespace {
     service_routes: HashMap<ServicePort, ServiceRoutes>,
+    egress_routes: HashMap<EgressPort, ServiceRoutes>,
     namespace: Arc<String>,
 }
 
 #[derive(Debug, Default)]
 struct ServiceInfo {
     opaque_ports: PortSet,
+    cluster_ips: Vec<IpAddr>,
+    /// Service ports by name, used to map endpoint ports to Service ports.
+    ports: Vec<(String, NonZeroU16)>,
+    /// Selects the ExternalWorkloads that serve the Service.
+    selector: Option<BTreeMap<String, String>>,
+    /// Service ports by the workload port that they target.
+    target_ports: Vec<(NonZeroU16, TargetPort)>,
     accrual: Option<FailureAccrual>,
+    circuit_breaker: Option<CircuitBreaker>,
+    retry: Option<RetryPolicy>,
+}
+
+#[derive(Debug)]
+enum TargetPort {
+    Number(NonZeroU16),
+    Name(String),
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
@@ -54,35 +148,211 @@ struct ServicePort {
     port: NonZeroU16,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct EgressPort {
+    egress: String,
+    port: NonZeroU16,
+}
+
 #[derive(Debug)]
 struct ServiceRoutes {
-    routes: HashMap<String, HttpRoute>,
+    http_routes: HashMap<String, HttpRoute>,
+    grpc_routes: HashMap<String, GrpcRoute>,
+    tcp_routes: HashMap<String, TcpRoute>,
+    tls_routes: HashMap<String, TlsRoute>,
     watch: watch::Sender<OutboundPolicy>,
     opaque: bool,
     accrual: Option<FailureAccrual>,
+    circuit_breaker: Option<CircuitBreaker>,
+    retry: Option<RetryPolicy>,
+    parent: ParentKind,
 }
 
 impl kubert::index::IndexNamespacedResource<api::HttpRoute> for Index {
     fn apply(&mut self, route: api::HttpRoute) {
         tracing::debug!(name = route.name_unchecked(), "indexing route");
         let ns = route.namespace().expect("HttpRoute must have a namespace");
+        let backend_namespaces = route
+            .spec
+            .rules
+            .iter()
+            .flatten()
+            .flat_map(|rule| rule.backend_refs.iter().flatten())
+            .filter_map(|b| b.backend_ref.as_ref())
+            .map(|b| b.inner.namespace.as_deref());
+        self.track_cross_namespace_route(
+            "HTTPRoute",
+            &ns,
+            route.name_unchecked(),
+            backend_namespaces,
+            || CrossNamespaceRoute::Http(route.clone()),
+        );
         self.namespaces
             .by_ns
             .entry(ns.clone())
             .or_insert_with(|| Namespace {
                 service_routes: Default::default(),
+                egress_routes: Default::default(),
                 namespace: Arc::new(ns),
             })
-            .apply(route, &self.namespaces.cluster_info, &self.service_info);
+            .apply(
+                route,
+                &self.namespaces.cluster_info,
+                &self.service_info,
+                &self.reference_grants,
+                &self.egress_networks,
+            );
     }
 
     fn delete(&mut self, namespace: String, name: String) {
+        self.cross_namespace_routes.remove(&RouteRef {
+            kind: "HTTPRoute",
+            namespace: namespace.clone(),
+            name: name.clone(),
+        });
         if let Some(ns_index) = self.namespaces.by_ns.get_mut(&namespace) {
             ns_index.delete(name);
         }
     }
 }
 
+impl kubert::index::IndexNamespacedResource<gateway::GrpcRoute> for Index {
+    fn apply(&mut self, route: gateway::GrpcRoute) {
+        tracing::debug!(name = route.name_unchecked(), "indexing grpc route");
+        let ns = route.namespace().expect("GrpcRoute must have a namespace");
+        let backend_namespaces = route
+            .spec
+            .rules
+            .iter()
+            .flatten()
+            .flat_map(|rule| rule.backend_refs.iter().flatten())
+            .filter_map(|b| b.backend_ref.as_ref())
+            .map(|b| b.inner.namespace.as_deref());
+        self.track_cross_namespace_route(
+            "GRPCRoute",
+            &ns,
+            route.name_unchecked(),
+            backend_namespaces,
+            || CrossNamespaceRoute::Grpc(route.clone()),
+        );
+        self.namespaces
+            .by_ns
+            .entry(ns.clone())
+            .or_insert_with(|| Namespace {
+                service_routes: Default::default(),
+                egress_routes: Default::default(),
+                namespace: Arc::new(ns),
+            })
+            .apply_grpc_route(
+                route,
+                &self.namespaces.cluster_info,
+                &self.service_info,
+                &self.reference_grants,
+            );
+    }
+
+    fn delete(&mut self, namespace: String, name: String) {
+        self.cross_namespace_routes.remove(&RouteRef {
+            kind: "GRPCRoute",
+            namespace: namespace.clone(),
+            name: name.clone(),
+        });
+        if let Some(ns_index) = self.namespaces.by_ns.get_mut(&namespace) {
+            ns_index.delete_grpc_route(name);
+        }
+    }
+}
+
+impl kubert::index::IndexNamespacedResource<gateway::TcpRoute> for Index {
+    fn apply(&mut self, route: gateway::TcpRoute) {
+        tracing::debug!(name = route.name_unchecked(), "indexing tcp route");
+        let ns = route.namespace().expect("TcpRoute must have a namespace");
+        let backend_namespaces = route
+            .spec
+            .rules
+            .iter()
+            .flat_map(|rule| rule.backend_refs.iter())
+            .map(|b| b.inner.namespace.as_deref());
+        self.track_cross_namespace_route(
+            "TCPRoute",
+            &ns,
+            route.name_unchecked(),
+            backend_namespaces,
+            || CrossNamespaceRoute::Tcp(route.clone()),
+        );
+        self.namespaces
+            .by_ns
+            .entry(ns.clone())
+            .or_insert_with(|| Namespace {
+                service_routes: Default::default(),
+                egress_routes: Default::default(),
+                namespace: Arc::new(ns),
+            })
+            .apply_tcp_route(
+                route,
+                &self.namespaces.cluster_info,
+                &self.service_info,
+                &self.reference_grants,
+            );
+    }
+
+    fn delete(&mut self, namespace: String, name: String) {
+        self.cross_namespace_routes.remove(&RouteRef {
+            kind: "TCPRoute",
+            namespace: namespace.clone(),
+            name: name.clone(),
+        });
+        if let Some(ns_index) = self.namespaces.by_ns.get_mut(&namespace) {
+            ns_index.delete_tcp_route(name);
+        }
+    }
+}
+
+impl kubert::index::IndexNamespacedResource<gateway::TlsRoute> for Index {
+    fn apply(&mut self, route: gateway::TlsRoute) {
+        tracing::debug!(name = route.name_unchecked(), "indexing tls route");
+        let ns = route.namespace().expect("TlsRoute must have a namespace");
+        let backend_namespaces = route
+            .spec
+            .rules
+            .iter()
+            .flat_map(|rule| rule.backend_refs.iter())
+            .map(|b| b.inner.namespace.as_deref());
+        self.track_cross_namespace_route(
+            "TLSRoute",
+            &ns,
+            route.name_unchecked(),
+            backend_namespaces,
+            || CrossNamespaceRoute::Tls(route.clone()),
+        );
+        self.namespaces
+            .by_ns
+            .entry(ns.clone())
+            .or_insert_with(|| Namespace {
+                service_routes: Default::default(),
+                egress_routes: Default::default(),
+                namespace: Arc::new(ns),
+            })
+            .apply_tls_route(
+                route,
+                &self.namespaces.cluster_info,
+                &self.service_info,
+                &self.reference_grants,
+            );
+    }
+
+    fn delete(&mut self, namespace: String, name: String) {
+        self.cross_namespace_routes.remove(&RouteRef {
+            kind: "TLSRoute",
+            namespace: namespace.clone(),
+            name: name.clone(),
+        });
+        if let Some(ns_index) = self.namespaces.by_ns.get_mut(&namespace) {
+            ns_index.delete_tls_route(name);
+        }
+    }
+}
+
 impl kubert::index::IndexNamespacedResource<Service> for Index {
     fn apply(&mut self, service: Service) {
         let name = service.name_unchecked();
@@ -90,33 +360,101 @@ impl kubert::index::IndexNamespacedResource<Service> for Index {
         let accrual = parse_accrual_config(service.annotations())
             .map_err(|error| tracing::error!(%error, service=name, namespace=ns, "failed to parse accrual config"))
             .unwrap_or_default();
+        let circuit_breaker = parse_circuit_breaker_config(service.annotations())
+            .map_err(|error| tracing::error!(%error, service=name, namespace=ns, "failed to parse circuit breaker config"))
+            .unwrap_or_default();
+        let retry = parse_retry_config(service.annotations())
+            .map_err(|error| tracing::error!(%error, service=name, namespace=ns, "failed to parse retry config"))
+            .unwrap_or_default();
         let opaque_ports =
             ports_annotation(service.annotations(), "config.linkerd.io/opaque-ports")
                 .unwrap_or_else(|| self.namespaces.cluster_info.default_opaque_ports.clone());
 
-        if let Some(cluster_ip) = service
-            .spec
-            .as_ref()
-            .and_then(|spec| spec.cluster_ip.as_deref())
-            .filter(|ip| !ip.is_empty() && *ip != "None")
-        {
-            match cluster_ip.parse() {
-                Ok(addr) => {
-                    let service_ref = ServiceRef {
-                        name,
-                        namespace: ns.clone(),
-                    };
-                    self.services_by_ip.insert(addr, service_ref);
-                }
+        let service_ref = ServiceRef {
+            name: name.clone(),
+            namespace: ns.clone(),
+        };
+
+        // Dual-stack Services have an address per IP family in `cluster_ips`,
+        // the first of which is also set as `cluster_ip`.
+        let mut cluster_ips = Vec::new();
+        let ips = service.spec.iter().flat_map(|spec| {
+            spec.cluster_ips
+                .iter()
+                .flatten()
+                .chain(spec.cluster_ip.iter())
+        });
+        for cluster_ip in ips.filter(|ip| !ip.is_empty() && *ip != "None") {
+            match cluster_ip.parse::<IpAddr>() {
+                Ok(addr) if !cluster_ips.contains(&addr) => cluster_ips.push(addr),
+                Ok(_) => {}
                 Err(error) => {
                     tracing::error!(%error, service=name, cluster_ip, "invalid cluster ip");
                 }
             }
         }
+        // Addresses that the Service no longer has are removed.
+        self.remove_cluster_ips(&service_ref);
+        for addr in &cluster_ips {
+            self.services_by_ip.insert(*addr, service_ref.clone());
+        }
+
+        let external_name = service
+            .spec
+            .as_ref()
+            .filter(|spec| spec.type_.as_deref() == Some("ExternalName"))
+            .and_then(|spec| spec.external_name.as_deref())
+            .map(|name| name.trim_end_matches('.').to_ascii_lowercase())
+            .filter(|name| !name.is_empty());
+        self.remove_external_name(&service_ref);
+        if let Some(external_name) = external_name {
+            self.services_by_external_name
+                .entry(external_name)
+                .or_default()
+                .insert(service_ref);
+        }
+
+        let ports = service
+            .spec
+            .iter()
+            .flat_map(|spec| spec.ports.iter().flatten())
+            .filter_map(|port| {
+                let number = NonZeroU16::try_from(u16::try_from(port.port).ok()?).ok()?;
+                Some((port.name.clone().unwrap_or_default(), number))
+            })
+            .collect();
+
+        let selector = service
+            .spec
+            .as_ref()
+            .and_then(|spec| spec.selector.clone())
+            .filter(|selector| !selector.is_empty());
+        let target_ports = service
+            .spec
+            .iter()
+            .flat_map(|spec| spec.ports.iter().flatten())
+            .filter_map(|port| {
+                let number = NonZeroU16::try_from(u16::try_from(port.port).ok()?).ok()?;
+                let target = match &port.target_port {
+                    None => TargetPort::Number(number),
+                    Some(IntOrString::Int(target)) => {
+                        TargetPort::Number(NonZeroU16::try_from(u16::try_from(*target).ok()?).ok()?)
+                    }
+                    Some(IntOrString::String(name)) => TargetPort::Name(name.clone()),
+                };
+                Some((number, target))
+            })
+            .collect();
 
         let service_info = ServiceInfo {
             opaque_ports,
+            cluster_ips,
+            ports,
+            selector,
+            target_ports,
             accrual,
+            circuit_breaker,
+            retry,
         };
 
         self.namespaces
@@ -124,6 +462,7 @@ impl kubert::index::IndexNamespacedResource<Service> for Index {
             .entry(ns.clone())
             .or_insert_with(|| Namespace {
                 service_routes: Default::default(),
+                egress_routes: Default::default(),
                 namespace: Arc::new(ns),
             })
             .update_service(service.name_unchecked(), &service_info);
@@ -139,8 +478,181 @@ impl kubert::index::IndexNamespacedResource<Service> for Index {
 
     fn delete(&mut self, namespace: String, name: String) {
         let service_ref = ServiceRef { name, namespace };
+        self.remove_cluster_ips(&service_ref);
         self.service_info.remove(&service_ref);
-        self.services_by_ip.retain(|_, v| *v != service_ref);
+        self.remove_external_name(&service_ref);
+    }
+}
+
+impl kubert::index::IndexNamespacedResource<ExternalWorkload> for Index {
+    fn apply(&mut self, workload: ExternalWorkload) {
+        let name = workload.name_unchecked();
+        let namespace = workload
+            .namespace()
+            .expect("ExternalWorkload must have a namespace");
+        let workload_ref = ExternalWorkloadRef {
+            namespace: namespace.clone(),
+            name: name.clone(),
+        };
+        self.remove_external_workload(&workload_ref);
+
+        let addrs = workload
+            .spec
+            .workload_ips
+            .iter()
+            .flatten()
+            .filter_map(|ip| match ip.ip.parse::<IpAddr>() {
+                Ok(addr) => Some(addr),
+                Err(error) => {
+                    tracing::warn!(%error, workload = name, %namespace, ip = %ip.ip, "invalid workload ip");
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+        for addr in &addrs {
+            self.external_workloads_by_ip
+                .insert(*addr, workload_ref.clone());
+        }
+        let ports = workload
+            .spec
+            .ports
+            .iter()
+            .flatten()
+            .map(|port| (port.name.clone(), port.port))
+            .collect();
+        self.external_workloads.insert(
+            workload_ref,
+            ExternalWorkloadInfo {
+                addrs,
+                labels: workload.labels().clone(),
+                ports,
+            },
+        );
+    }
+
+    fn delete(&mut self, namespace: String, name: String) {
+        self.remove_external_workload(&ExternalWorkloadRef { namespace, name });
+    }
+}
+
+impl kubert::index::IndexNamespacedResource<EndpointSlice> for Index {
+    fn apply(&mut self, slice: EndpointSlice) {
+        let name = slice.name_unchecked();
+        let namespace = slice
+            .namespace()
+            .expect("EndpointSlice must have a namespace");
+        let slice_ref = EndpointSliceRef {
+            namespace: namespace.clone(),
+            name: name.clone(),
+        };
+        self.remove_endpoint_slice(&slice_ref);
+
+        let Some(service) = slice.labels().get("kubernetes.io/service-name").cloned() else {
+            return;
+        };
+        let addrs = slice
+            .endpoints
+            .iter()
+            .flat_map(|endpoint| endpoint.addresses.iter())
+            .filter_map(|addr| match addr.parse::<IpAddr>() {
+                Ok(addr) => Some(addr),
+                Err(error) => {
+                    tracing::debug!(%error, slice = name, %namespace, addr, "ignoring endpoint address");
+                    None
+                }
+            })
+            .collect::<Vec<_>>();
+        let ports = slice
+            .ports
+            .iter()
+            .flatten()
+            .filter_map(|port| {
+                let number = NonZeroU16::try_from(u16::try_from(port.port?).ok()?).ok()?;
+                Some((port.name.clone().unwrap_or_default(), number))
+            })
+            .collect();
+
+        for addr in &addrs {
+            self.slices_by_ip
+                .entry(*addr)
+                .or_default()
+                .insert(slice_ref.clone());
+        }
+        self.endpoint_slices.insert(
+            slice_ref,
+            EndpointSliceInfo {
+                service: ServiceRef {
+                    name: service,
+                    namespace,
+                },
+                addrs,
+                ports,
+            },
+        );
+    }
+
+    fn delete(&mut self, namespace: String, name: String) {
+        self.remove_endpoint_slice(&EndpointSliceRef { namespace, name });
+    }
+}
+
+impl kubert::index::IndexNamespacedResource<api::EgressNetwork> for Index {
+    fn apply(&mut self, egress: api::EgressNetwork) {
+        let name = egress.name_unchecked();
+        let ns = egress
+            .namespace()
+            .expect("EgressNetwork must have a namespace");
+        let spec = match egress_network::Spec::try_from(egress.spec) {
+            Ok(spec) => spec,
+            Err(error) => {
+                tracing::warn!(%error, egress = name, namespace = ns, "invalid EgressNetwork");
+                return;
+            }
+        };
+
+        if let Some(ns_index) = self.namespaces.by_ns.get_mut(&ns) {
+            ns_index.update_egress(&name, spec.traffic_policy);
+        }
+        self.egress_networks
+            .entry(ns)
+            .or_default()
+            .insert(name, spec);
+    }
+
+    fn delete(&mut self, namespace: String, name: String) {
+        if let Some(ns_index) = self.namespaces.by_ns.get_mut(&namespace) {
+            ns_index.delete_egress(&name);
+        }
+        if let Some(egress_networks) = self.egress_networks.get_mut(&namespace) {
+            egress_networks.remove(&name);
+            if egress_networks.is_empty() {
+                self.egress_networks.remove(&namespace);
+            }
+        }
+    }
+}
+
+impl kubert::index::IndexNamespacedResource<gateway::ReferenceGrant> for Index {
+    fn apply(&mut self, grant: gateway::ReferenceGrant) {
+        let name = grant.name_unchecked();
+        let ns = grant
+            .namespace()
+            .expect("ReferenceGrant must have a namespace");
+        self.reference_grants
+            .entry(ns)
+            .or_default()
+            .insert(name, grant.spec);
+        self.reindex_cross_namespace_routes();
+    }
+
+    fn delete(&mut self, namespace: String, name: String) {
+        if let Some(grants) = self.reference_grants.get_mut(&namespace) {
+            grants.remove(&name);
+            if grants.is_empty() {
+                self.reference_grants.remove(&namespace);
+            }
+        }
+        self.reindex_cross_namespace_routes();
     }
 }
 
@@ -153,6 +665,14 @@ impl Index {
             },
             services_by_ip: HashMap::default(),
             service_info: HashMap::default(),
+            endpoint_slices: HashMap::default(),
+            slices_by_ip: HashMap::default(),
+            external_workloads: HashMap::default(),
+            external_workloads_by_ip: HashMap::default(),
+            services_by_external_name: HashMap::default(),
+            egress_networks: HashMap::default(),
+            reference_grants: HashMap::default(),
+            cross_namespace_routes: HashMap::default(),
         }))
     }
 
@@ -168,6 +688,7 @@ impl Index {
             .entry(namespace.clone())
             .or_insert_with(|| Namespace {
                 service_routes: Default::default(),
+                egress_routes: Default::default(),
                 namespace: Arc::new(namespace.to_string()),
             });
         let key = ServicePort { service, port };
@@ -177,9 +698,246 @@ impl Index {
         Ok(routes.watch.subscribe())
     }
 
+    pub fn egress_policy_rx(
+        &mut self,
+        namespace: String,
+        egress: String,
+        port: NonZeroU16,
+    ) -> Result<watch::Receiver<OutboundPolicy>> {
+        let traffic_policy = self
+            .egress_networks
+            .get(&namespace)
+            .and_then(|egress_networks| egress_networks.get(&egress))
+            .map(|spec| spec.traffic_policy)
+            .ok_or_else(|| anyhow!("EgressNetwork {namespace}/{egress} not found"))?;
+        let ns = self
+            .namespaces
+            .by_ns
+            .entry(namespace.clone())
+            .or_insert_with(|| Namespace {
+                service_routes: Default::default(),
+                egress_routes: Default::default(),
+                namespace: Arc::new(namespace),
+            });
+        let key = EgressPort { egress, port };
+        tracing::debug!(?key, "subscribing to egress port");
+        let routes =
+            ns.egress_routes_or_default(key, &self.namespaces.cluster_info, traffic_policy);
+        Ok(routes.watch.subscribe())
+    }
+
     pub fn lookup_service(&self, addr: IpAddr) -> Option<ServiceRef> {
         self.services_by_ip.get(&addr).cloned()
     }
+
+    /// Finds the Service that serves the endpoint `addr:port`, e.g. a pod of a
+    /// headless Service, returning the Service port that the endpoint port
+    /// serves. When an endpoint belongs to multiple Services, ties are broken
+    /// by namespace and name.
+    pub fn lookup_endpoint(
+        &self,
+        addr: IpAddr,
+        port: NonZeroU16,
+    ) -> Option<(ServiceRef, NonZeroU16)> {
+        self.slices_by_ip
+            .get(&addr)?
+            .iter()
+            .filter_map(|slice_ref| {
+                let slice = self.endpoint_slices.get(slice_ref)?;
+                let (port_name, _) = slice.ports.iter().find(|(_, p)| *p == port)?;
+                let service = self.service_info.get(&slice.service)?;
+                let (_, service_port) = service.ports.iter().find(|(name, _)| name == port_name)?;
+                Some((slice.service.clone(), *service_port))
+            })
+            .min_by(|(a, _), (b, _)| {
+                a.namespace
+                    .cmp(&b.namespace)
+                    .then_with(|| a.name.cmp(&b.name))
+            })
+    }
+
+    fn remove_cluster_ips(&mut self, service_ref: &ServiceRef) {
+        let Some(service) = self.service_info.get(service_ref) else {
+            return;
+        };
+        for addr in &service.cluster_ips {
+            if self.services_by_ip.get(addr) == Some(service_ref) {
+                self.services_by_ip.remove(addr);
+            }
+        }
+    }
+
+    fn remove_endpoint_slice(&mut self, slice_ref: &EndpointSliceRef) {
+        let Some(slice) = self.endpoint_slices.remove(slice_ref) else {
+            return;
+        };
+        for addr in slice.addrs {
+            if let Some(slices) = self.slices_by_ip.get_mut(&addr) {
+                slices.remove(slice_ref);
+                if slices.is_empty() {
+                    self.slices_by_ip.remove(&addr);
+                }
+            }
+        }
+    }
+
+    /// Finds the ExternalWorkload that has the address `addr`.
+    pub fn lookup_external_workload(&self, addr: IpAddr) -> Option<ExternalWorkloadRef> {
+        self.external_workloads_by_ip.get(&addr).cloned()
+    }
+
+    /// Finds the Service that selects the ExternalWorkload `workload`,
+    /// returning the Service port that targets the workload's `port`. When the
+    /// workload is selected by multiple Services, ties are broken by name.
+    pub fn lookup_external_workload_endpoint(
+        &self,
+        workload: &ExternalWorkloadRef,
+        port: NonZeroU16,
+    ) -> Option<(ServiceRef, NonZeroU16)> {
+        let info = self.external_workloads.get(workload)?;
+        let port_name = info
+            .ports
+            .iter()
+            .find(|(_, p)| *p == port)
+            .and_then(|(name, _)| name.as_deref());
+        self.service_info
+            .iter()
+            .filter(|(service, _)| service.namespace == workload.namespace)
+            .filter_map(|(service, svc)| {
+                let selector = svc.selector.as_ref()?;
+                if !selector.iter().all(|(k, v)| info.labels.get(k) == Some(v)) {
+                    return None;
+                }
+                let (service_port, _) =
+                    svc.target_ports.iter().find(|(_, target)| match target {
+                        TargetPort::Number(p) => *p == port,
+                        TargetPort::Name(name) => Some(name.as_str()) == port_name,
+                    })?;
+                Some((service.clone(), *service_port))
+            })
+            .min_by(|(a, _), (b, _)| a.name.cmp(&b.name))
+    }
+
+    fn remove_external_workload(&mut self, workload_ref: &ExternalWorkloadRef) {
+        let Some(ExternalWorkloadInfo { addrs, .. }) = self.external_workloads.remove(workload_ref)
+        else {
+            return;
+        };
+        for addr in addrs {
+            if self.external_workloads_by_ip.get(&addr) == Some(workload_ref) {
+                self.external_workloads_by_ip.remove(&addr);
+            }
+        }
+    }
+
+    /// Finds an ExternalName Service that aliases `host`. Services in the
+    /// client's namespace are preferred; otherwise ties are broken by
+    /// namespace and name.
+    pub fn lookup_external_name(
+        &self,
+        host: &str,
+        source_namespace: Option<&str>,
+    ) -> Option<ServiceRef> {
+        let host = host.trim_end_matches('.').to_ascii_lowercase();
+        self.services_by_external_name
+            .get(&host)?
+            .iter()
+            .min_by_key(|svc| {
+                (
+                    Some(svc.namespace.as_str()) != source_namespace,
+                    &svc.namespace,
+                    &svc.name,
+                )
+            })
+            .cloned()
+    }
+
+    fn remove_external_name(&mut self, service_ref: &ServiceRef) {
+        self.services_by_external_name.retain(|_, services| {
+            services.remove(service_ref);
+            !services.is_empty()
+        });
+    }
+
+    /// Finds the `EgressNetwork` in the client's namespace that most
+    /// specifically matches `addr`. Addresses in the cluster's networks are
+    /// never considered external.
+    pub fn lookup_egress_ip(&self, addr: IpAddr, source_namespace: &str) -> Option<String> {
+        if self
+            .namespaces
+            .cluster_info
+            .networks
+            .iter()
+            .any(|net| net.contains(&addr))
+        {
+            return None;
+        }
+        self.lookup_egress(source_namespace, |spec| {
+            spec.match_ip(addr).map(usize::from)
+        })
+    }
+
+    /// Finds the `EgressNetwork` in the client's namespace that most
+    /// specifically matches `host`.
+    pub fn lookup_egress_hostname(&self, host: &str, source_namespace: &str) -> Option<String> {
+        self.lookup_egress(source_namespace, |spec| spec.match_hostname(host))
+    }
+
+    fn lookup_egress(
+        &self,
+        namespace: &str,
+        score: impl Fn(&egress_network::Spec) -> Option<usize>,
+    ) -> Option<String> {
+        self.egress_networks
+            .get(namespace)?
+            .iter()
+            .filter_map(|(name, spec)| Some((score(spec)?, name)))
+            // Prefer the most specific match, breaking ties by name.
+            .max_by(|(a_score, a_name), (b_score, b_name)| {
+                a_score.cmp(b_score).then_with(|| b_name.cmp(a_name))
+            })
+            .map(|(_, name)| name.clone())
+    }
+
+    /// Records a route if any of its backends are in another namespace, so
+    /// that it may be reindexed when ReferenceGrants change.
+    fn track_cross_namespace_route<'a>(
+        &mut self,
+        kind: &'static str,
+        namespace: &str,
+        name: String,
+        mut backend_namespaces: impl Iterator<Item = Option<&'a str>>,
+        route: impl FnOnce() -> CrossNamespaceRoute,
+    ) {
+        let key = RouteRef {
+            kind,
+            namespace: namespace.to_string(),
+            name,
+        };
+        if backend_namespaces.any(|ns| ns.map_or(false, |ns| ns != namespace)) {
+            self.cross_namespace_routes.insert(key, route());
+        } else {
+            self.cross_namespace_routes.remove(&key);
+        }
+    }
+
+    fn reindex_cross_namespace_routes(&mut self) {
+        use kubert::index::IndexNamespacedResource;
+
+        let routes = self
+            .cross_namespace_routes
+            .values()
+            .cloned()
+            .collect::<Vec<_>>();
+        for route in routes {
+            match route {
+                CrossNamespaceRoute::Http(route) => self.apply(route),
+                CrossNamespaceRoute::Grpc(route) => self.apply(route),
+                CrossNamespaceRoute::Tcp(route) => self.apply(route),
+                CrossNamespaceRoute::Tls(route) => self.apply(route),
+            }
+        }
+    }
 }
 
 impl Namespace {
@@ -188,46 +946,174 @@ impl Namespace {
         route: api::HttpRoute,
         cluster_info: &ClusterInfo,
         service_info: &HashMap<ServiceRef, ServiceInfo>,
+        reference_grants: &ReferenceGrants,
+        egress_networks: &HashMap<String, HashMap<String, egress_network::Spec>>,
+    ) {
+        tracing::debug!(?route);
+        let name = route.name_unchecked();
+        let outbound_route =
+            match self.convert_route(route.clone(), cluster_info, service_info, reference_grants) {
+                Ok(route) => route,
+                Err(error) => {
+                    tracing::error!(%error, "failed to convert HttpRoute");
+                    return;
+                }
+            };
+        tracing::debug!(?outbound_route);
+
+        let service_ports = parent_service_ports(
+            route.spec.inner.parent_refs.as_deref(),
+            route.status.as_ref().map(|status| &status.inner),
+        );
+        for service_port in service_ports {
+            tracing::debug!(?service_port, route = %name, "inserting route for service");
+            let service_routes =
+                self.service_routes_or_default(service_port, cluster_info, service_info);
+            service_routes.apply(name.clone(), outbound_route.clone());
+        }
+
+        let egress_ports = parent_egress_ports(
+            route.spec.inner.parent_refs.as_deref(),
+            route.status.as_ref().map(|status| &status.inner),
+        );
+        for egress_port in egress_ports {
+            let traffic_policy = match egress_networks
+                .get(&*self.namespace)
+                .and_then(|egress_networks| egress_networks.get(&egress_port.egress))
+            {
+                Some(spec) => spec.traffic_policy,
+                None => {
+                    tracing::debug!(?egress_port, route = %name, "EgressNetwork not found");
+                    continue;
+                }
+            };
+            tracing::debug!(?egress_port, route = %name, "inserting route for egress network");
+            let egress_routes =
+                self.egress_routes_or_default(egress_port, cluster_info, traffic_policy);
+            egress_routes.apply(name.clone(), outbound_route.clone());
+        }
+    }
+
+    fn apply_grpc_route(
+        &mut self,
+        route: gateway::GrpcRoute,
+        cluster_info: &ClusterInfo,
+        service_info: &HashMap<ServiceRef, ServiceInfo>,
+        reference_grants: &ReferenceGrants,
     ) {
         tracing::debug!(?route);
         let name = route.name_unchecked();
-        let outbound_route = match self.convert_route(route.clone(), cluster_info, service_info) {
+        let outbound_route = match self.convert_grpc_route(
+            route.clone(),
+            cluster_info,
+            service_info,
+            reference_grants,
+        ) {
             Ok(route) => route,
             Err(error) => {
-                tracing::error!(%error, "failed to convert HttpRoute");
+                tracing::error!(%error, "failed to convert GrpcRoute");
                 return;
             }
         };
         tracing::debug!(?outbound_route);
 
-        for parent_ref in route.spec.inner.parent_refs.iter().flatten() {
-            if !is_parent_service(parent_ref) {
-                continue;
-            }
-            if !route_accepted_by_service(&route, &parent_ref.name) {
-                continue;
-            }
+        let service_ports = parent_service_ports(
+            route.spec.inner.parent_refs.as_deref(),
+            route.status.as_ref().map(|status| &status.inner),
+        );
+        for service_port in service_ports {
+            tracing::debug!(?service_port, route = %name, "inserting grpc route for service");
+            let service_routes =
+                self.service_routes_or_default(service_port, cluster_info, service_info);
+            service_routes.apply_grpc_route(name.clone(), outbound_route.clone());
+        }
+    }
+
+    fn apply_tcp_route(
+        &mut self,
+        route: gateway::TcpRoute,
+        cluster_info: &ClusterInfo,
+        service_info: &HashMap<ServiceRef, ServiceInfo>,
+        reference_grants: &ReferenceGrants,
+    ) {
+        tracing::debug!(?route);
+        let name = route.name_unchecked();
+        let rules = route
+            .spec
+            .rules
+            .into_iter()
+            .map(|rule| {
+                self.convert_tcp_rule::<gateway::TcpRoute>(
+                    rule.backend_refs,
+                    cluster_info,
+                    service_info,
+                    reference_grants,
+                )
+            })
+            .collect();
+        let outbound_route = TcpRoute {
+            rules,
+            creation_timestamp: route.metadata.creation_timestamp.map(|Time(t)| t),
+        };
+        tracing::debug!(?outbound_route);
+
+        let service_ports = parent_service_ports(
+            route.spec.inner.parent_refs.as_deref(),
+            route.status.as_ref().map(|status| &status.inner),
+        );
+        for service_port in service_ports {
+            tracing::debug!(?service_port, route = %name, "inserting tcp route for service");
+            let service_routes =
+                self.service_routes_or_default(service_port, cluster_info, service_info);
+            service_routes.apply_tcp_route(name.clone(), outbound_route.clone());
+        }
+    }
+
+    fn apply_tls_route(
+        &mut self,
+        route: gateway::TlsRoute,
+        cluster_info: &ClusterInfo,
+        service_info: &HashMap<ServiceRef, ServiceInfo>,
+        reference_grants: &ReferenceGrants,
+    ) {
+        tracing::debug!(?route);
+        let name = route.name_unchecked();
+        let hostnames = route
+            .spec
+            .hostnames
+            .into_iter()
+            .flatten()
+            .map(http_route::host_match)
+            .collect();
+        let rules = route
+            .spec
+            .rules
+            .into_iter()
+            .map(|rule| {
+                self.convert_tcp_rule::<gateway::TlsRoute>(
+                    rule.backend_refs,
+                    cluster_info,
+                    service_info,
+                    reference_grants,
+                )
+            })
+            .collect();
+        let outbound_route = TlsRoute {
+            hostnames,
+            rules,
+            creation_timestamp: route.metadata.creation_timestamp.map(|Time(t)| t),
+        };
+        tracing::debug!(?outbound_route);
 
-            if let Some(port) = parent_ref.port {
-                if let Some(port) = NonZeroU16::new(port) {
-                    let service_port = ServicePort {
-                        port,
-                        service: parent_ref.name.clone(),
-                    };
-                    tracing::debug!(
-                        ?service_port,
-                        route = route.name_unchecked(),
-                        "inserting route for service"
-                    );
-                    let service_routes =
-                        self.service_routes_or_default(service_port, cluster_info, service_info);
-                    service_routes.apply(name.clone(), outbound_route.clone());
-                } else {
-                    tracing::warn!(?parent_ref, "ignoring parent_ref with port 0");
-                }
-            } else {
-                tracing::warn!(?parent_ref, "ignoring parent_ref without port");
-            }
+        let service_ports = parent_service_ports(
+            route.spec.inner.parent_refs.as_deref(),
+            route.status.as_ref().map(|status| &status.inner),
+        );
+        for service_port in service_ports {
+            tracing::debug!(?service_port, route = %name, "inserting tls route for service");
+            let service_routes =
+                self.service_routes_or_default(service_port, cluster_info, service_info);
+            service_routes.apply_tls_route(name.clone(), outbound_route.clone());
         }
     }
 
@@ -238,15 +1124,58 @@ impl Namespace {
                 continue;
             }
             let opaque = service.opaque_ports.contains(&svc_port.port);
+            svc_routes.update_service(
+                opaque,
+                service.accrual,
+                service.circuit_breaker,
+                service.retry.clone(),
+            );
+        }
+    }
 
-            svc_routes.update_service(opaque, service.accrual);
+    fn update_egress(&mut self, name: &str, traffic_policy: TrafficPolicy) {
+        tracing::debug!(?name, ?traffic_policy, "updating egress network");
+        for (egress_port, egress_routes) in self.egress_routes.iter_mut() {
+            if egress_port.egress == name {
+                egress_routes.update_egress(traffic_policy);
+            }
         }
     }
 
+    /// Removes the routes of a deleted egress network. Open watches complete,
+    /// so that clients look the destination up again and no longer find the
+    /// egress network.
+    fn delete_egress(&mut self, name: &str) {
+        tracing::debug!(?name, "deleting egress network");
+        self.egress_routes
+            .retain(|egress_port, _| egress_port.egress != name);
+    }
+
     fn delete(&mut self, name: String) {
         for service in self.service_routes.values_mut() {
             service.delete(&name);
         }
+        for egress in self.egress_routes.values_mut() {
+            egress.delete(&name);
+        }
+    }
+
+    fn delete_grpc_route(&mut self, name: String) {
+        for service in self.service_routes.values_mut() {
+            service.delete_grpc_route(&name);
+        }
+    }
+
+    fn delete_tcp_route(&mut self, name: String) {
+        for service in self.service_routes.values_mut() {
+            service.delete_tcp_route(&name);
+        }
+    }
+
+    fn delete_tls_route(&mut self, name: String) {
+        for service in self.service_routes.values_mut() {
+            service.delete_tls_route(&name);
+        }
     }
 
     fn service_routes_or_default(
@@ -256,30 +1185,89 @@ impl Namespace {
         service_info: &HashMap<ServiceRef, ServiceInfo>,
     ) -> &mut ServiceRoutes {
         self.service_routes.entry(sp.clone()).or_insert_with(|| {
-            let authority = cluster.service_dns_authority(&self.namespace, &sp.service, sp.port);
             let service_ref = ServiceRef {
                 name: sp.service.clone(),
                 namespace: self.namespace.to_string(),
             };
-            let (opaque, accrual) = match service_info.get(&service_ref) {
-                Some(svc) => (svc.opaque_ports.contains(&sp.port), svc.accrual),
-                None => (false, None),
+            let info = service_info.get(&service_ref);
+            let authority = cluster.service_dns_authority(&self.namespace, &sp.service, sp.port);
+            let (opaque, accrual, circuit_breaker, retry) = match info {
+                Some(svc) => (
+                    svc.opaque_ports.contains(&sp.port),
+                    svc.accrual,
+                    svc.circuit_breaker,
+                    svc.retry.clone(),
+                ),
+                None => (false, None, None, None),
             };
 
             let (sender, _) = watch::channel(OutboundPolicy {
                 http_routes: Default::default(),
+                grpc_routes: Default::default(),
+                tcp_routes: Default::default(),
+                tls_routes: Default::default(),
                 authority,
                 name: sp.service.clone(),
                 namespace: self.namespace.to_string(),
                 port: sp.port,
                 opaque,
                 accrual,
+                circuit_breaker,
+                retry: retry.clone(),
+                parent: ParentKind::Service,
             });
             ServiceRoutes {
-                routes: Default::default(),
+                http_routes: Default::default(),
+                grpc_routes: Default::default(),
+                tcp_routes: Default::default(),
+                tls_routes: Default::default(),
                 watch: sender,
                 opaque,
                 accrual,
+                circuit_breaker,
+                retry,
+                parent: ParentKind::Service,
+            }
+        })
+    }
+
+    fn egress_routes_or_default(
+        &mut self,
+        ep: EgressPort,
+        cluster: &ClusterInfo,
+        traffic_policy: TrafficPolicy,
+    ) -> &mut ServiceRoutes {
+        self.egress_routes.entry(ep.clone()).or_insert_with(|| {
+            let opaque = cluster.default_opaque_ports.contains(&ep.port);
+            let parent = ParentKind::Egress { traffic_policy };
+            let (sender, _) = watch::channel(OutboundPolicy {
+                http_routes: Default::default(),
+                grpc_routes: Default::default(),
+                tcp_routes: Default::default(),
+                tls_routes: Default::default(),
+                // Egress traffic is forwarded to the client's original
+                // destination, so there is no authority to resolve.
+                authority: Default::default(),
+                name: ep.egress.clone(),
+                namespace: self.namespace.to_string(),
+                port: ep.port,
+                opaque,
+                accrual: None,
+                circuit_breaker: None,
+                retry: None,
+                parent,
+            });
+            ServiceRoutes {
+                http_routes: Default::default(),
+                grpc_routes: Default::default(),
+                tcp_routes: Default::default(),
+                tls_routes: Default::default(),
+                watch: sender,
+                opaque,
+                accrual: None,
+                circuit_breaker: None,
+                retry: None,
+                parent,
             }
         })
     }
@@ -289,7 +1277,17 @@ impl Namespace {
         route: api::HttpRoute,
         cluster: &ClusterInfo,
         service_info: &HashMap<ServiceRef, ServiceInfo>,
+        reference_grants: &ReferenceGrants,
     ) -> Result<HttpRoute> {
+        // Retries configured on the route override those configured on the
+        // parent Service. An invalid retry configuration must not drop the
+        // route, so it is logged and ignored.
+        let retry = parse_retry_config(route.annotations())
+            .map_err(|error| {
+                tracing::error!(%error, route = route.name_unchecked(), "failed to parse retry config")
+            })
+            .unwrap_or_default();
+
         let hostnames = route
             .spec
             .hostnames
@@ -303,7 +1301,7 @@ impl Namespace {
             .rules
             .into_iter()
             .flatten()
-            .map(|r| self.convert_rule(r, cluster, service_info))
+            .map(|r| self.convert_rule(r, retry.clone(), cluster, service_info, reference_grants))
             .collect::<Result<_>>()?;
 
         let creation_timestamp = route.metadata.creation_timestamp.map(|Time(t)| t);
@@ -318,8 +1316,10 @@ impl Namespace {
     fn convert_rule(
         &self,
         rule: api::httproute::HttpRouteRule,
+        retry: Option<RetryPolicy>,
         cluster: &ClusterInfo,
         service_info: &HashMap<ServiceRef, ServiceInfo>,
+        reference_grants: &ReferenceGrants,
     ) -> Result<HttpRouteRule> {
         let matches = rule
             .matches
@@ -332,70 +1332,325 @@ impl Namespace {
             .backend_refs
             .into_iter()
             .flatten()
-            .filter_map(|b| convert_backend(&self.namespace, b, cluster, service_info))
+            .filter_map(|b| b.backend_ref)
+            .map(|b| {
+                convert_backend::<api::HttpRoute>(
+                    &self.namespace,
+                    b,
+                    cluster,
+                    service_info,
+                    reference_grants,
+                )
+            })
+            .collect();
+
+        let filters = rule
+            .filters
+            .into_iter()
+            .flatten()
+            .filter_map(|f| convert_filter(f).transpose())
+            .collect::<Result<_>>()?;
+
+        let timeouts = rule
+            .timeouts
+            .map(http_route::timeouts)
+            .transpose()?
+            .unwrap_or_default();
+
+        Ok(HttpRouteRule {
+            matches,
+            backends,
+            filters,
+            timeouts,
+            retry,
+        })
+    }
+
+    fn convert_grpc_route(
+        &self,
+        route: gateway::GrpcRoute,
+        cluster: &ClusterInfo,
+        service_info: &HashMap<ServiceRef, ServiceInfo>,
+        reference_grants: &ReferenceGrants,
+    ) -> Result<GrpcRoute> {
+        // As with HTTPRoutes, retries configured on the route override those
+        // configured on the parent Service.
+        let retry = parse_retry_config(route.annotations())
+            .map_err(|error| {
+                tracing::error!(%error, route = route.name_unchecked(), "failed to parse retry config")
+            })
+            .unwrap_or_default();
+
+        let hostnames = route
+            .spec
+            .hostnames
+            .into_iter()
+            .flatten()
+            .map(http_route::host_match)
+            .collect();
+
+        let rules = route
+            .spec
+            .rules
+            .into_iter()
+            .flatten()
+            .map(|r| {
+                self.convert_grpc_rule(r, retry.clone(), cluster, service_info, reference_grants)
+            })
+            .collect::<Result<_>>()?;
+
+        let creation_timestamp = route.metadata.creation_timestamp.map(|Time(t)| t);
+
+        Ok(GrpcRoute {
+            hostnames,
+            rules,
+            creation_timestamp,
+        })
+    }
+
+    fn convert_grpc_rule(
+        &self,
+        rule: gateway::GrpcRouteRule,
+        retry: Option<RetryPolicy>,
+        cluster: &ClusterInfo,
+        service_info: &HashMap<ServiceRef, ServiceInfo>,
+        reference_grants: &ReferenceGrants,
+    ) -> Result<GrpcRouteRule> {
+        let matches = rule
+            .matches
+            .into_iter()
+            .flatten()
+            .map(grpc_route::try_match)
+            .collect::<Result<_>>()?;
+
+        let backends = rule
+            .backend_refs
+            .into_iter()
+            .flatten()
+            .filter_map(|b| b.backend_ref)
+            .map(|b| {
+                convert_backend::<gateway::GrpcRoute>(
+                    &self.namespace,
+                    b,
+                    cluster,
+                    service_info,
+                    reference_grants,
+                )
+            })
+            .collect();
+
+        let filters = rule
+            .filters
+            .into_iter()
+            .flatten()
+            .filter_map(|f| convert_grpc_filter(f).transpose())
+            .collect::<Result<_>>()?;
+
+        Ok(GrpcRouteRule {
+            matches,
+            backends,
+            filters,
+            retry,
+        })
+    }
+
+    fn convert_tcp_rule<R>(
+        &self,
+        backend_refs: Vec<BackendRef>,
+        cluster: &ClusterInfo,
+        service_info: &HashMap<ServiceRef, ServiceInfo>,
+        reference_grants: &ReferenceGrants,
+    ) -> TcpRouteRule
+    where
+        R: Resource<DynamicType = ()>,
+    {
+        let backends = backend_refs
+            .into_iter()
+            .map(|b| {
+                convert_backend::<R>(&self.namespace, b, cluster, service_info, reference_grants)
+            })
             .collect();
-        Ok(HttpRouteRule { matches, backends })
+        TcpRouteRule { backends }
     }
 }
 
-fn convert_backend(
+fn convert_backend<R>(
     ns: &str,
-    backend: HttpBackendRef,
+    backend: BackendRef,
     cluster: &ClusterInfo,
     services: &HashMap<ServiceRef, ServiceInfo>,
-) -> Option<Backend> {
-    backend.backend_ref.map(|backend| {
-        if !is_backend_service(&backend.inner) {
-            return Backend::Invalid {
-                weight: backend.weight.unwrap_or(1).into(),
-                message: format!(
-                    "unsupported backend type {group} {kind}",
-                    group = backend.inner.group.as_deref().unwrap_or("core"),
-                    kind = backend.inner.kind.as_deref().unwrap_or("<empty>"),
-                ),
-            };
-        }
+    reference_grants: &ReferenceGrants,
+) -> Backend
+where
+    R: Resource<DynamicType = ()>,
+{
+    if !is_backend_service(&backend.inner) {
+        return Backend::Invalid {
+            weight: backend.weight.unwrap_or(1).into(),
+            message: format!(
+                "unsupported backend type {group} {kind}",
+                group = backend.inner.group.as_deref().unwrap_or("core"),
+                kind = backend.inner.kind.as_deref().unwrap_or("<empty>"),
+            ),
+        };
+    }
 
-        let name = backend.inner.name;
-        let weight = backend.weight.unwrap_or(1);
+    let name = backend.inner.name;
+    let weight = backend.weight.unwrap_or(1);
 
-        // The gateway API dictates:
-        //
-        // Port is required when the referent is a Kubernetes Service.
-        let port = match backend
-            .inner
-            .port
-            .and_then(|p| NonZeroU16::try_from(p).ok())
-        {
-            Some(port) => port,
-            None => {
-                return Backend::Invalid {
-                    weight: weight.into(),
-                    message: format!("missing port for backend Service {name}"),
-                }
-            }
-        };
-        let service_ref = ServiceRef {
-            name: name.clone(),
-            namespace: backend.inner.namespace.unwrap_or_else(|| ns.to_string()),
-        };
-        if !services.contains_key(&service_ref) {
+    // The gateway API dictates:
+    //
+    // Port is required when the referent is a Kubernetes Service.
+    let port = match backend
+        .inner
+        .port
+        .and_then(|p| NonZeroU16::try_from(p).ok())
+    {
+        Some(port) => port,
+        None => {
             return Backend::Invalid {
                 weight: weight.into(),
-                message: format!("Service not found {name}"),
-            };
+                message: format!("missing port for backend Service {name}"),
+            }
         }
-
-        Backend::Service(WeightedService {
+    };
+    let service_ref = ServiceRef {
+        name: name.clone(),
+        namespace: backend.inner.namespace.unwrap_or_else(|| ns.to_string()),
+    };
+    if !backend_permitted::<R>(reference_grants, ns, &service_ref) {
+        return Backend::Invalid {
             weight: weight.into(),
-            authority: cluster.service_dns_authority(&service_ref.namespace, &name, port),
-            name,
-            namespace: ns.to_string(),
-            port,
-        })
+            message: format!(
+                "backend Service {}/{name} is not permitted by a ReferenceGrant",
+                service_ref.namespace,
+            ),
+        };
+    }
+    let Some(service) = services.get(&service_ref) else {
+        return Backend::Invalid {
+            weight: weight.into(),
+            message: format!("Service not found {name}"),
+        };
+    };
+
+    Backend::Service(WeightedService {
+        weight: weight.into(),
+        authority: cluster.service_dns_authority(&service_ref.namespace, &name, port),
+        name,
+        namespace: service_ref.namespace,
+        port,
+        circuit_breaker: service.circuit_breaker,
     })
 }
 
+/// Returns true if a route of kind `R` in `ns` may reference `service`. Routes
+/// may always reference Services in their own namespace; otherwise, a
+/// ReferenceGrant in the Service's namespace must permit the reference.
+fn backend_permitted<R>(reference_grants: &ReferenceGrants, ns: &str, service: &ServiceRef) -> bool
+where
+    R: Resource<DynamicType = ()>,
+{
+    if service.namespace == ns {
+        return true;
+    }
+    reference_grants
+        .get(&service.namespace)
+        .map_or(false, |grants| {
+            grants
+                .values()
+                .any(|grant| grant.permits::<R, Service>(ns, &service.name))
+        })
+}
+
+/// Converts the filters that apply to outbound routes. Filters that are only
+/// meaningful for inbound routes are ignored.
+fn convert_filter(filter: api::httproute::HttpRouteFilter) -> Result<Option<Filter>> {
+    let filter = match filter {
+        api::httproute::HttpRouteFilter::ResponseHeaderModifier {
+            response_header_modifier,
+        } => Filter::ResponseHeaderModifier(http_route::header_modifier(response_header_modifier)?),
+        api::httproute::HttpRouteFilter::RequestFailureInjector {
+            request_failure_injector,
+        } => Filter::FailureInjector(http_route::failure_injector(request_failure_injector)?),
+        api::httproute::HttpRouteFilter::RequestHeaderModifier {
+            request_header_modifier,
+        } => Filter::RequestHeaderModifier(http_route::header_modifier(request_header_modifier)?),
+        api::httproute::HttpRouteFilter::RequestRedirect { .. } => return Ok(None),
+    };
+    Ok(Some(filter))
+}
+
+/// Converts the filters that apply to outbound gRPC routes. Admission rejects
+/// the filters that cannot be expressed.
+fn convert_grpc_filter(filter: gateway::GrpcRouteFilter) -> Result<Option<Filter>> {
+    let filter = match filter {
+        gateway::GrpcRouteFilter::RequestHeaderModifier {
+            request_header_modifier,
+        } => Filter::RequestHeaderModifier(http_route::header_modifier(request_header_modifier)?),
+        gateway::GrpcRouteFilter::RequestMirror { .. }
+        | gateway::GrpcRouteFilter::ExtensionRef { .. } => return Ok(None),
+    };
+    Ok(Some(filter))
+}
+
+/// Returns the ports of the parent EgressNetworks that have accepted a route.
+fn parent_egress_ports(
+    parent_refs: Option<&[ParentReference]>,
+    status: Option<&RouteStatus>,
+) -> Vec<EgressPort> {
+    parent_refs
+        .into_iter()
+        .flatten()
+        .filter(|parent_ref| {
+            api::httproute::parent_ref_targets_kind::<api::EgressNetwork>(parent_ref)
+        })
+        .filter(|parent_ref| route_accepted_by_service(status, &parent_ref.name))
+        .filter_map(
+            |parent_ref| match parent_ref.port.and_then(NonZeroU16::new) {
+                Some(port) => Some(EgressPort {
+                    port,
+                    egress: parent_ref.name.clone(),
+                }),
+                None => {
+                    tracing::warn!(
+                        ?parent_ref,
+                        "ignoring EgressNetwork parent_ref without port"
+                    );
+                    None
+                }
+            },
+        )
+        .collect()
+}
+
+/// Returns the ports of the parent Services that have accepted a route.
+fn parent_service_ports(
+    parent_refs: Option<&[ParentReference]>,
+    status: Option<&RouteStatus>,
+) -> Vec<ServicePort> {
+    parent_refs
+        .into_iter()
+        .flatten()
+        .filter(|parent_ref| is_parent_service(parent_ref))
+        .filter(|parent_ref| route_accepted_by_service(status, &parent_ref.name))
+        .filter_map(|parent_ref| match parent_ref.port.map(NonZeroU16::new) {
+            Some(Some(port)) => Some(ServicePort {
+                port,
+                service: parent_ref.name.clone(),
+            }),
+            Some(None) => {
+                tracing::warn!(?parent_ref, "ignoring parent_ref with port 0");
+                None
+            }
+            None => {
+                tracing::warn!(?parent_ref, "ignoring parent_ref without port");
+                None
+            }
+        })
+        .collect()
+}
+
 #[inline]
 fn is_parent_service(parent: &ParentReference) -> bool {
     parent
@@ -407,11 +1662,9 @@ fn is_parent_service(parent: &ParentReference) -> bool {
 }
 
 #[inline]
-fn route_accepted_by_service(route: &api::HttpRoute, service: &str) -> bool {
-    route
-        .status
-        .as_ref()
-        .map(|status| status.inner.parents.as_slice())
+fn route_accepted_by_service(status: Option<&RouteStatus>, service: &str) -> bool {
+    status
+        .map(|status| status.parents.as_slice())
         .unwrap_or_default()
         .iter()
         .any(|parent_status| {
@@ -443,26 +1696,81 @@ fn is_service(group: Option<&str>, kind: &str) -> bool {
 
 impl ServiceRoutes {
     fn apply(&mut self, name: String, route: HttpRoute) {
-        self.routes.insert(name, route);
+        self.http_routes.insert(name, route);
+        self.send_if_modified();
+    }
+
+    fn apply_grpc_route(&mut self, name: String, route: GrpcRoute) {
+        self.grpc_routes.insert(name, route);
+        self.send_if_modified();
+    }
+
+    fn apply_tcp_route(&mut self, name: String, route: TcpRoute) {
+        self.tcp_routes.insert(name, route);
+        self.send_if_modified();
+    }
+
+    fn apply_tls_route(&mut self, name: String, route: TlsRoute) {
+        self.tls_routes.insert(name, route);
         self.send_if_modified();
     }
 
-    fn update_service(&mut self, opaque: bool, accrual: Option<FailureAccrual>) {
+    fn update_service(
+        &mut self,
+        opaque: bool,
+        accrual: Option<FailureAccrual>,
+        circuit_breaker: Option<CircuitBreaker>,
+        retry: Option<RetryPolicy>,
+    ) {
         self.opaque = opaque;
         self.accrual = accrual;
+        self.circuit_breaker = circuit_breaker;
+        self.retry = retry;
+        self.send_if_modified();
+    }
+
+    fn update_egress(&mut self, traffic_policy: TrafficPolicy) {
+        self.parent = ParentKind::Egress { traffic_policy };
         self.send_if_modified();
     }
 
     fn delete(&mut self, name: &String) {
-        self.routes.remove(name);
+        self.http_routes.remove(name);
+        self.send_if_modified();
+    }
+
+    fn delete_grpc_route(&mut self, name: &String) {
+        self.grpc_routes.remove(name);
+        self.send_if_modified();
+    }
+
+    fn delete_tcp_route(&mut self, name: &String) {
+        self.tcp_routes.remove(name);
+        self.send_if_modified();
+    }
+
+    fn delete_tls_route(&mut self, name: &String) {
+        self.tls_routes.remove(name);
         self.send_if_modified();
     }
 
     fn send_if_modified(&mut self) {
         self.watch.send_if_modified(|policy| {
             let mut modified = false;
-            if self.routes != policy.http_routes {
-                policy.http_routes = self.routes.clone();
+            if self.http_routes != policy.http_routes {
+                policy.http_routes = self.http_routes.clone();
+                modified = true;
+            }
+            if self.grpc_routes != policy.grpc_routes {
+                policy.grpc_routes = self.grpc_routes.clone();
+                modified = true;
+            }
+            if self.tcp_routes != policy.tcp_routes {
+                policy.tcp_routes = self.tcp_routes.clone();
+                modified = true;
+            }
+            if self.tls_routes != policy.tls_routes {
+                policy.tls_routes = self.tls_routes.clone();
                 modified = true;
             }
             if self.opaque != policy.opaque {
@@ -473,11 +1781,62 @@ impl ServiceRoutes {
                 policy.accrual = self.accrual;
                 modified = true;
             }
+            if self.circuit_breaker != policy.circuit_breaker {
+                policy.circuit_breaker = self.circuit_breaker;
+                modified = true;
+            }
+            if self.retry != policy.retry {
+                policy.retry = self.retry.clone();
+                modified = true;
+            }
+            if self.parent != policy.parent {
+                policy.parent = self.parent;
+                modified = true;
+            }
             modified
         });
     }
 }
 
+/// Validates the annotations that configure a Service's outbound policy.
+pub fn validate_service_annotations(
+    annotations: &std::collections::BTreeMap<String, String>,
+) -> Result<()> {
+    parse_accrual_config(annotations)?;
+    parse_circuit_breaker_config(annotations)?;
+    parse_retry_config(annotations)?;
+    for name in UNSUPPORTED_CIRCUIT_BREAKER_ANNOTATIONS {
+        ensure!(
+            !annotations.contains_key(name),
+            "{name} is not supported: the proxy only bounds pending requests"
+        );
+    }
+    for name in UNSUPPORTED_RETRY_ANNOTATIONS {
+        ensure!(
+            !annotations.contains_key(name),
+            "{name} is not supported: the proxy does not enforce retry budgets"
+        );
+    }
+    Ok(())
+}
+
+/// The proxy API's backend queue bounds only the requests waiting for an
+/// endpoint; it cannot limit concurrent requests or connections, so these
+/// annotations are rejected rather than silently ignored.
+const UNSUPPORTED_CIRCUIT_BREAKER_ANNOTATIONS: [&str; 2] = [
+    "balancer.linkerd.io/max-concurrent-requests",
+    "balancer.linkerd.io/max-connections",
+];
+
+/// The proxy API's route retry policy has no budget, so retries are limited
+/// only by `retry.linkerd.io/limit`. Budget annotations are rejected rather
+/// than silently ignored.
+const UNSUPPORTED_RETRY_ANNOTATIONS: [&str; 3] = [
+    "retry.linkerd.io/budget-ratio",
+    "retry.linkerd.io/budget-min-retries-per-second",
+    "retry.linkerd.io/budget-ttl",
+];
+
 fn parse_accrual_config(
     annotations: &std::collections::BTreeMap<String, String>,
 ) -> Result<Option<FailureAccrual>> {
@@ -533,6 +1892,116 @@ fn parse_accrual_config(
         .transpose()
 }
 
+fn parse_circuit_breaker_config(
+    annotations: &std::collections::BTreeMap<String, String>,
+) -> Result<Option<CircuitBreaker>> {
+    annotations
+        .get("balancer.linkerd.io/max-pending-requests")
+        .map(|value| {
+            let max_pending_requests = value
+                .parse::<u32>()
+                .map_err(|error| anyhow!("invalid max-pending-requests {value:?}: {error}"))?;
+            ensure!(
+                max_pending_requests > 0,
+                "max-pending-requests must be positive"
+            );
+            Ok(CircuitBreaker {
+                max_pending_requests,
+            })
+        })
+        .transpose()
+}
+
+fn parse_retry_config(
+    annotations: &std::collections::BTreeMap<String, String>,
+) -> Result<Option<RetryPolicy>> {
+    let status_ranges = annotations
+        .get("retry.linkerd.io/http")
+        .map(|statuses| parse_status_ranges(statuses))
+        .transpose()?;
+    let grpc_statuses = annotations
+        .get("retry.linkerd.io/grpc")
+        .map(|statuses| parse_grpc_statuses(statuses))
+        .transpose()?;
+    if status_ranges.is_none() && grpc_statuses.is_none() {
+        return Ok(None);
+    }
+
+    let max_retries = annotations
+        .get("retry.linkerd.io/limit")
+        .map(|s| s.parse::<u32>())
+        .transpose()?
+        .unwrap_or(1);
+
+    Ok(Some(RetryPolicy {
+        max_retries,
+        status_ranges: status_ranges.unwrap_or_default(),
+        grpc_statuses: grpc_statuses.unwrap_or_default(),
+    }))
+}
+
+/// Parses a comma-separated list of gRPC status names (e.g.
+/// `unavailable,deadline-exceeded`).
+fn parse_grpc_statuses(s: &str) -> Result<Vec<GrpcRetryStatus>> {
+    let statuses = s
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| match s.to_ascii_lowercase().as_str() {
+            "cancelled" => Ok(GrpcRetryStatus::Cancelled),
+            "deadline-exceeded" => Ok(GrpcRetryStatus::DeadlineExceeded),
+            "internal" => Ok(GrpcRetryStatus::Internal),
+            "resource-exhausted" => Ok(GrpcRetryStatus::ResourceExhausted),
+            "unavailable" => Ok(GrpcRetryStatus::Unavailable),
+            _ => bail!("unsupported retryable gRPC status: {s}"),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    ensure!(
+        !statuses.is_empty(),
+        "no retryable gRPC statuses configured"
+    );
+    Ok(statuses)
+}
+
+/// Parses a comma-separated list of HTTP statuses, status ranges (e.g.
+/// `500-504`), or status classes (e.g. `5xx`).
+fn parse_status_ranges(s: &str) -> Result<Vec<RangeInclusive<u16>>> {
+    let parse_status = |s: &str| -> Result<u16> {
+        let status = s.parse::<u16>()?;
+        ensure!(
+            (100..=599).contains(&status),
+            "invalid HTTP status: {status}"
+        );
+        Ok(status)
+    };
+
+    let ranges = s
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            if let Some(class) = s.strip_suffix("xx") {
+                let class = class.parse::<u16>()?;
+                ensure!((1..=5).contains(&class), "invalid HTTP status class: {s}");
+                return Ok(class * 100..=class * 100 + 99);
+            }
+            match s.split_once('-') {
+                Some((start, end)) => {
+                    let (start, end) = (parse_status(start.trim())?, parse_status(end.trim())?);
+                    ensure!(start <= end, "invalid HTTP status range: {s}");
+                    Ok(start..=end)
+                }
+                None => {
+                    let status = parse_status(s)?;
+                    Ok(status..=status)
+                }
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+    ensure!(!ranges.is_empty(), "no retryable HTTP statuses configured");
+    Ok(ranges)
+}
+
 fn parse_duration(s: &str) -> Result<time::Duration> {
     let s = s.trim();
     let offset = s
@@ -559,3 +2028,94 @@ fn parse_duration(s: &str) -> Result<time::Duration> {
         .ok_or_else(|| anyhow::anyhow!("Timeout value {} overflows when converted to 'ms'", s))?;
     Ok(time::Duration::from_millis(ms))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_status_ranges() {
+        use super::parse_status_ranges;
+
+        assert_eq!(parse_status_ranges("500").unwrap(), vec![500..=500], "500");
+        assert_eq!(parse_status_ranges("5xx").unwrap(), vec![500..=599], "5xx");
+        assert_eq!(
+            parse_status_ranges("500-504, 429").unwrap(),
+            vec![500..=504, 429..=429],
+            "500-504, 429"
+        );
+        assert!(parse_status_ranges("").is_err(), "empty");
+        assert!(parse_status_ranges(",").is_err(), ",");
+        assert!(parse_status_ranges("600").is_err(), "600");
+        assert!(parse_status_ranges("99").is_err(), "99");
+        assert!(parse_status_ranges("6xx").is_err(), "6xx");
+        assert!(parse_status_ranges("504-500").is_err(), "504-500");
+        assert!(parse_status_ranges("500-").is_err(), "500-");
+        assert!(parse_status_ranges("abc").is_err(), "abc");
+    }
+
+    #[test]
+    fn parse_retry_config() {
+        use super::parse_retry_config;
+
+        let annotations = |pairs: &[(&str, &str)]| {
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect::<std::collections::BTreeMap<_, _>>()
+        };
+
+        assert_eq!(parse_retry_config(&annotations(&[])).unwrap(), None);
+        assert_eq!(
+            parse_retry_config(&annotations(&[("retry.linkerd.io/http", "5xx")])).unwrap(),
+            Some(RetryPolicy {
+                max_retries: 1,
+                status_ranges: vec![500..=599],
+                grpc_statuses: vec![],
+            }),
+        );
+        assert_eq!(
+            parse_retry_config(&annotations(&[
+                ("retry.linkerd.io/http", "502,503"),
+                ("retry.linkerd.io/limit", "3"),
+            ]))
+            .unwrap(),
+            Some(RetryPolicy {
+                max_retries: 3,
+                status_ranges: vec![502..=502, 503..=503],
+                grpc_statuses: vec![],
+            }),
+        );
+        assert_eq!(
+            parse_retry_config(&annotations(&[(
+                "retry.linkerd.io/grpc",
+                "unavailable, deadline-exceeded"
+            )]))
+            .unwrap(),
+            Some(RetryPolicy {
+                max_retries: 1,
+                status_ranges: vec![],
+                grpc_statuses: vec![
+                    GrpcRetryStatus::Unavailable,
+                    GrpcRetryStatus::DeadlineExceeded
+                ],
+            }),
+        );
+        assert!(
+            parse_retry_config(&annotations(&[("retry.linkerd.io/grpc", "not-found")])).is_err(),
+            "unsupported gRPC status"
+        );
+        assert!(
+            parse_retry_config(&annotations(&[("retry.linkerd.io/http", "600")])).is_err(),
+            "invalid status"
+        );
+        assert!(
+            parse_retry_config(&annotations(&[
+                ("retry.linkerd.io/http", "5xx"),
+                ("retry.linkerd.io/limit", "-1"),
+            ]))
+            .is_err(),
+            "invalid limit"
+        );
+    }
+}
@@ -0,0 +1,169 @@
+mod annotations;
+mod egress_networks;
+mod endpoint_slices;
+mod external_names;
+mod external_workloads;
+mod http_routes;
+mod opaque_routes;
+mod services;
+
+use crate::{
+    defaults::DefaultPolicy,
+    outbound::index::{Index, SharedIndex},
+    ClusterInfo,
+};
+use linkerd_policy_controller_k8s_api as k8s;
+use std::sync::Arc;
+use tokio::time;
+
+struct TestConfig {
+    index: SharedIndex,
+    _tracing: tracing::subscriber::DefaultGuard,
+}
+
+fn mk_service(
+    ns: impl ToString,
+    name: impl ToString,
+    cluster_ips: impl IntoIterator<Item = &'static str>,
+) -> k8s::Service {
+    let cluster_ips = cluster_ips
+        .into_iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    k8s::Service {
+        metadata: k8s::ObjectMeta {
+            namespace: Some(ns.to_string()),
+            name: Some(name.to_string()),
+            ..Default::default()
+        },
+        spec: Some(k8s::ServiceSpec {
+            cluster_ip: cluster_ips.first().cloned(),
+            cluster_ips: Some(cluster_ips),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+fn mk_http_route(
+    ns: impl ToString,
+    name: impl ToString,
+    service: &str,
+    port: u16,
+    rule: k8s::policy::httproute::HttpRouteRule,
+) -> k8s::policy::HttpRoute {
+    use k8s::policy::httproute::*;
+
+    HttpRoute {
+        metadata: k8s::ObjectMeta {
+            namespace: Some(ns.to_string()),
+            name: Some(name.to_string()),
+            ..Default::default()
+        },
+        spec: HttpRouteSpec {
+            inner: CommonRouteSpec {
+                parent_refs: Some(vec![mk_service_parent_ref(service, port)]),
+            },
+            hostnames: None,
+            rules: Some(vec![rule]),
+        },
+        status: Some(HttpRouteStatus {
+            inner: mk_accepted_status(service, port),
+        }),
+    }
+}
+
+fn mk_http_rule(
+    backends: impl IntoIterator<Item = k8s::gateway::BackendRef>,
+) -> k8s::policy::httproute::HttpRouteRule {
+    k8s::policy::httproute::HttpRouteRule {
+        matches: None,
+        filters: None,
+        backend_refs: Some(
+            backends
+                .into_iter()
+                .map(|backend_ref| k8s::policy::httproute::HttpBackendRef {
+                    backend_ref: Some(backend_ref),
+                    filters: None,
+                })
+                .collect(),
+        ),
+        timeouts: None,
+    }
+}
+
+fn mk_backend_ref(name: &str, port: u16, weight: Option<u16>) -> k8s::gateway::BackendRef {
+    k8s::gateway::BackendRef {
+        weight,
+        inner: k8s::gateway::BackendObjectReference {
+            group: None,
+            kind: None,
+            name: name.to_string(),
+            namespace: None,
+            port: Some(port),
+        },
+    }
+}
+
+fn mk_service_parent_ref(service: &str, port: u16) -> k8s::gateway::ParentReference {
+    k8s::gateway::ParentReference {
+        group: Some("core".to_string()),
+        kind: Some("Service".to_string()),
+        namespace: None,
+        name: service.to_string(),
+        section_name: None,
+        port: Some(port),
+    }
+}
+
+/// Routes are only indexed once the status controller has accepted them.
+fn mk_accepted_status(service: &str, port: u16) -> k8s::gateway::RouteStatus {
+    k8s::gateway::RouteStatus {
+        parents: vec![k8s::gateway::RouteParentStatus {
+            parent_ref: mk_service_parent_ref(service, port),
+            controller_name: linkerd_policy_controller_core::POLICY_CONTROLLER_NAME.to_string(),
+            conditions: vec![k8s::Condition {
+                last_transition_time: k8s::Time(chrono::DateTime::<chrono::Utc>::MIN_UTC),
+                message: "".to_string(),
+                observed_generation: None,
+                reason: "Accepted".to_string(),
+                status: "True".to_string(),
+                type_: "Accepted".to_string(),
+            }],
+        }],
+    }
+}
+
+impl TestConfig {
+    fn init_tracing() -> tracing::subscriber::DefaultGuard {
+        tracing::subscriber::set_default(
+            tracing_subscriber::fmt()
+                .with_test_writer()
+                .with_max_level(tracing::Level::TRACE)
+                .finish(),
+        )
+    }
+}
+
+impl Default for TestConfig {
+    fn default() -> TestConfig {
+        let _tracing = Self::init_tracing();
+        let cluster = ClusterInfo {
+            networks: vec!["192.0.2.0/24".parse().unwrap()],
+            control_plane_ns: "linkerd".to_string(),
+            identity_domain: "cluster.example.com".into(),
+            dns_domain: "cluster.example.com".into(),
+            default_policy: DefaultPolicy::Allow {
+                authenticated_only: false,
+                cluster_only: true,
+            },
+            default_detect_timeout: time::Duration::from_secs(1),
+            default_opaque_ports: Default::default(),
+            probe_networks: vec![],
+        };
+        Self {
+            index: Index::shared(Arc::new(cluster)),
+            _tracing,
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use crate::outbound::validate_service_annotations;
+use std::collections::BTreeMap;
+
+fn annotations(
+    pairs: impl IntoIterator<Item = (&'static str, &'static str)>,
+) -> BTreeMap<String, String> {
+    pairs
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn accepts_unannotated_service() {
+    assert!(validate_service_annotations(&BTreeMap::new()).is_ok());
+}
+
+#[test]
+fn accepts_valid_annotations() {
+    let annotations = annotations([
+        ("balancer.linkerd.io/failure-accrual", "consecutive"),
+        (
+            "balancer.linkerd.io/failure-accrual-consecutive-max-failures",
+            "3",
+        ),
+        ("balancer.linkerd.io/max-pending-requests", "100"),
+        ("retry.linkerd.io/http", "5xx"),
+        ("retry.linkerd.io/grpc", "unavailable"),
+        ("retry.linkerd.io/limit", "2"),
+    ]);
+    assert!(validate_service_annotations(&annotations).is_ok());
+}
+
+#[test]
+fn rejects_unsupported_accrual_mode() {
+    let annotations = annotations([("balancer.linkerd.io/failure-accrual", "success-rate")]);
+    assert!(validate_service_annotations(&annotations).is_err());
+}
+
+#[test]
+fn rejects_invalid_accrual_penalties() {
+    let annotations = annotations([
+        ("balancer.linkerd.io/failure-accrual", "consecutive"),
+        (
+            "balancer.linkerd.io/failure-accrual-consecutive-min-penalty",
+            "10s",
+        ),
+        (
+            "balancer.linkerd.io/failure-accrual-consecutive-max-penalty",
+            "1s",
+        ),
+    ]);
+    assert!(validate_service_annotations(&annotations).is_err());
+}
+
+#[test]
+fn rejects_invalid_max_pending_requests() {
+    for value in ["0", "-1", "lots"] {
+        let annotations = annotations([("balancer.linkerd.io/max-pending-requests", value)]);
+        assert!(
+            validate_service_annotations(&annotations).is_err(),
+            "max-pending-requests {value:?} must be rejected"
+        );
+    }
+}
+
+#[test]
+fn rejects_invalid_retry_statuses() {
+    let annotations = annotations([("retry.linkerd.io/http", "600")]);
+    assert!(validate_service_annotations(&annotations).is_err());
+}
+
+#[test]
+fn rejects_unsupported_grpc_retry_statuses() {
+    let annotations = annotations([("retry.linkerd.io/grpc", "not-found")]);
+    assert!(validate_service_annotations(&annotations).is_err());
+}
+
+#[test]
+fn rejects_retry_budgets() {
+    for name in [
+        "retry.linkerd.io/budget-ratio",
+        "retry.linkerd.io/budget-min-retries-per-second",
+        "retry.linkerd.io/budget-ttl",
+    ] {
+        let annotations = annotations([("retry.linkerd.io/http", "5xx"), (name, "1")]);
+        assert!(
+            validate_service_annotations(&annotations).is_err(),
+            "{name} must be rejected"
+        );
+    }
+}
+
+#[test]
+fn rejects_unsupported_circuit_breakers() {
+    for name in [
+        "balancer.linkerd.io/max-concurrent-requests",
+        "balancer.linkerd.io/max-connections",
+    ] {
+        let annotations = annotations([(name, "100")]);
+        assert!(
+            validate_service_annotations(&annotations).is_err(),
+            "{name} must be rejected"
+        );
+    }
+}
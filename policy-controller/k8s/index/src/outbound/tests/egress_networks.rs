@@ -0,0 +1,182 @@
+use super::*;
+use kubert::index::IndexNamespacedResource;
+use linkerd_policy_controller_core::outbound::{ParentKind, TrafficPolicy};
+use linkerd_policy_controller_k8s_api::policy::{egress_network, Network};
+
+fn mk_egress_network(
+    ns: impl ToString,
+    name: impl ToString,
+    hostnames: impl IntoIterator<Item = &'static str>,
+    networks: impl IntoIterator<Item = &'static str>,
+    traffic_policy: egress_network::TrafficPolicy,
+) -> k8s::policy::EgressNetwork {
+    k8s::policy::EgressNetwork {
+        metadata: k8s::ObjectMeta {
+            namespace: Some(ns.to_string()),
+            name: Some(name.to_string()),
+            ..Default::default()
+        },
+        spec: k8s::policy::EgressNetworkSpec {
+            hostnames: Some(hostnames.into_iter().map(ToString::to_string).collect()),
+            networks: Some(
+                networks
+                    .into_iter()
+                    .map(|net| Network {
+                        cidr: net.parse().unwrap(),
+                        except: None,
+                    })
+                    .collect(),
+            ),
+            traffic_policy,
+        },
+    }
+}
+
+#[test]
+fn egress_ip_lookup() {
+    let test = TestConfig::default();
+    test.index.write().apply(mk_egress_network(
+        "ns-0",
+        "egress-all",
+        None,
+        ["0.0.0.0/0"],
+        egress_network::TrafficPolicy::Allow,
+    ));
+    test.index.write().apply(mk_egress_network(
+        "ns-0",
+        "egress-doc",
+        None,
+        ["203.0.113.0/24"],
+        egress_network::TrafficPolicy::Allow,
+    ));
+
+    // The most specific network is preferred.
+    let lookup = |addr: &str, ns: &str| {
+        test.index
+            .read()
+            .lookup_egress_ip(addr.parse().unwrap(), ns)
+    };
+    assert_eq!(
+        lookup("203.0.113.10", "ns-0"),
+        Some("egress-doc".to_string())
+    );
+    assert_eq!(
+        lookup("198.51.100.10", "ns-0"),
+        Some("egress-all".to_string())
+    );
+
+    // EgressNetworks only apply to clients in their own namespace, and
+    // addresses in the cluster's networks are never external.
+    assert_eq!(lookup("203.0.113.10", "ns-1"), None);
+    assert_eq!(lookup("192.0.2.10", "ns-0"), None);
+
+    <Index as IndexNamespacedResource<k8s::policy::EgressNetwork>>::delete(
+        &mut test.index.write(),
+        "ns-0".to_string(),
+        "egress-doc".to_string(),
+    );
+    assert_eq!(
+        lookup("203.0.113.10", "ns-0"),
+        Some("egress-all".to_string())
+    );
+}
+
+#[test]
+fn egress_hostname_lookup() {
+    let test = TestConfig::default();
+    test.index.write().apply(mk_egress_network(
+        "ns-0",
+        "egress-wildcard",
+        ["*.example.com"],
+        None,
+        egress_network::TrafficPolicy::Allow,
+    ));
+    test.index.write().apply(mk_egress_network(
+        "ns-0",
+        "egress-api",
+        ["api.example.com"],
+        None,
+        egress_network::TrafficPolicy::Allow,
+    ));
+
+    let lookup = |host: &str, ns: &str| test.index.read().lookup_egress_hostname(host, ns);
+    assert_eq!(
+        lookup("api.example.com", "ns-0"),
+        Some("egress-api".to_string())
+    );
+    assert_eq!(
+        lookup("www.example.com", "ns-0"),
+        Some("egress-wildcard".to_string())
+    );
+    assert_eq!(lookup("example.org", "ns-0"), None);
+    assert_eq!(lookup("api.example.com", "ns-1"), None);
+}
+
+#[test]
+fn egress_watch() {
+    let test = TestConfig::default();
+    test.index
+        .write()
+        .egress_policy_rx(
+            "ns-0".to_string(),
+            "egress-0".to_string(),
+            443.try_into().unwrap(),
+        )
+        .expect_err("egress-0.ns-0 must not exist");
+
+    test.index.write().apply(mk_egress_network(
+        "ns-0",
+        "egress-0",
+        None,
+        ["0.0.0.0/0"],
+        egress_network::TrafficPolicy::Allow,
+    ));
+    let mut rx = test
+        .index
+        .write()
+        .egress_policy_rx(
+            "ns-0".to_string(),
+            "egress-0".to_string(),
+            443.try_into().unwrap(),
+        )
+        .expect("egress-0.ns-0 should exist");
+    assert_eq!(
+        rx.borrow_and_update().parent,
+        ParentKind::Egress {
+            traffic_policy: TrafficPolicy::Allow
+        }
+    );
+
+    // Changes to the traffic policy are published to open watches.
+    test.index.write().apply(mk_egress_network(
+        "ns-0",
+        "egress-0",
+        None,
+        ["0.0.0.0/0"],
+        egress_network::TrafficPolicy::Deny,
+    ));
+    assert!(rx.has_changed().unwrap());
+    assert_eq!(
+        rx.borrow_and_update().parent,
+        ParentKind::Egress {
+            traffic_policy: TrafficPolicy::Deny
+        }
+    );
+
+    // Once the EgressNetwork is deleted, open watches complete and new
+    // lookups fail.
+    <Index as IndexNamespacedResource<k8s::policy::EgressNetwork>>::delete(
+        &mut test.index.write(),
+        "ns-0".to_string(),
+        "egress-0".to_string(),
+    );
+    assert!(rx.has_changed().is_err());
+    test.index
+        .write()
+        .egress_policy_rx(
+            "ns-0".to_string(),
+            "egress-0".to_string(),
+            443.try_into().unwrap(),
+        )
+        .expect_err("egress-0.ns-0 must not exist");
+}
@@ -0,0 +1,168 @@
+use super::*;
+use crate::outbound::index::ServiceRef;
+use k8s::api::discovery::v1::{Endpoint, EndpointPort};
+use kubert::index::IndexNamespacedResource;
+
+fn service_ref(ns: &str, name: &str) -> ServiceRef {
+    ServiceRef {
+        namespace: ns.to_string(),
+        name: name.to_string(),
+    }
+}
+
+fn mk_headless_service(ns: impl ToString, name: impl ToString) -> k8s::Service {
+    let mut svc = mk_service(ns, name, ["None"]);
+    svc.spec.as_mut().unwrap().ports = Some(vec![k8s::ServicePort {
+        name: Some("http".to_string()),
+        port: 80,
+        ..Default::default()
+    }]);
+    svc
+}
+
+fn mk_endpoint_slice(
+    ns: impl ToString,
+    name: impl ToString,
+    service: Option<&str>,
+    addrs: impl IntoIterator<Item = &'static str>,
+) -> k8s::EndpointSlice {
+    k8s::EndpointSlice {
+        metadata: k8s::ObjectMeta {
+            namespace: Some(ns.to_string()),
+            name: Some(name.to_string()),
+            labels: service.map(|svc| {
+                Some(("kubernetes.io/service-name".to_string(), svc.to_string()))
+                    .into_iter()
+                    .collect()
+            }),
+            ..Default::default()
+        },
+        address_type: "IPv4".to_string(),
+        endpoints: addrs
+            .into_iter()
+            .map(|addr| Endpoint {
+                addresses: vec![addr.to_string()],
+                ..Default::default()
+            })
+            .collect(),
+        ports: Some(vec![EndpointPort {
+            name: Some("http".to_string()),
+            port: Some(8080),
+            ..Default::default()
+        }]),
+    }
+}
+
+#[test]
+fn endpoint_lookup() {
+    let test = TestConfig::default();
+    test.index.write().apply(mk_endpoint_slice(
+        "ns-0",
+        "svc-0-abcde",
+        Some("svc-0"),
+        ["10.0.0.1"],
+    ));
+
+    // Endpoints are only resolved once their Service is known.
+    let lookup = |addr: &str, port: u16| {
+        test.index
+            .read()
+            .lookup_endpoint(addr.parse().unwrap(), port.try_into().unwrap())
+    };
+    assert_eq!(lookup("10.0.0.1", 8080), None);
+
+    test.index
+        .write()
+        .apply(mk_headless_service("ns-0", "svc-0"));
+    assert_eq!(
+        lookup("10.0.0.1", 8080),
+        Some((service_ref("ns-0", "svc-0"), 80.try_into().unwrap())),
+    );
+    assert_eq!(lookup("10.0.0.1", 9090), None);
+    assert_eq!(lookup("10.0.0.2", 8080), None);
+}
+
+#[test]
+fn stale_endpoints_removed() {
+    let test = TestConfig::default();
+    test.index
+        .write()
+        .apply(mk_headless_service("ns-0", "svc-0"));
+    test.index.write().apply(mk_endpoint_slice(
+        "ns-0",
+        "svc-0-abcde",
+        Some("svc-0"),
+        ["10.0.0.1"],
+    ));
+
+    let lookup = |addr: &str| {
+        test.index
+            .read()
+            .lookup_endpoint(addr.parse().unwrap(), 8080.try_into().unwrap())
+    };
+
+    test.index.write().apply(mk_endpoint_slice(
+        "ns-0",
+        "svc-0-abcde",
+        Some("svc-0"),
+        ["10.0.0.2"],
+    ));
+    assert_eq!(lookup("10.0.0.1"), None);
+    assert_eq!(
+        lookup("10.0.0.2"),
+        Some((service_ref("ns-0", "svc-0"), 80.try_into().unwrap())),
+    );
+
+    <Index as IndexNamespacedResource<k8s::EndpointSlice>>::delete(
+        &mut test.index.write(),
+        "ns-0".to_string(),
+        "svc-0-abcde".to_string(),
+    );
+    assert_eq!(lookup("10.0.0.2"), None);
+}
+
+#[test]
+fn endpoint_slice_without_service_ignored() {
+    let test = TestConfig::default();
+    test.index
+        .write()
+        .apply(mk_headless_service("ns-0", "svc-0"));
+    test.index
+        .write()
+        .apply(mk_endpoint_slice("ns-0", "slice-0", None, ["10.0.0.1"]));
+
+    assert_eq!(
+        test.index
+            .read()
+            .lookup_endpoint("10.0.0.1".parse().unwrap(), 8080.try_into().unwrap()),
+        None,
+    );
+}
+
+#[test]
+fn endpoint_in_multiple_services() {
+    let test = TestConfig::default();
+    for svc in ["svc-b", "svc-a"] {
+        test.index.write().apply(mk_headless_service("ns-0", svc));
+    }
+    test.index.write().apply(mk_endpoint_slice(
+        "ns-0",
+        "svc-b-abcde",
+        Some("svc-b"),
+        ["10.0.0.1"],
+    ));
+    test.index.write().apply(mk_endpoint_slice(
+        "ns-0",
+        "svc-a-abcde",
+        Some("svc-a"),
+        ["10.0.0.1"],
+    ));
+
+    // Ties are broken by name so that lookups are deterministic.
+    assert_eq!(
+        test.index
+            .read()
+            .lookup_endpoint("10.0.0.1".parse().unwrap(), 8080.try_into().unwrap()),
+        Some((service_ref("ns-0", "svc-a"), 80.try_into().unwrap())),
+    );
+}
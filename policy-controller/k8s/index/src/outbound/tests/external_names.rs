@@ -0,0 +1,154 @@
+use super::*;
+use crate::outbound::index::ServiceRef;
+use kubert::index::IndexNamespacedResource;
+use linkerd_policy_controller_core::outbound::{Backend, WeightedService};
+
+fn mk_external_name_service(
+    ns: impl ToString,
+    name: impl ToString,
+    external_name: &str,
+) -> k8s::Service {
+    let mut svc = mk_service(ns, name, None);
+    let spec = svc.spec.as_mut().unwrap();
+    spec.type_ = Some("ExternalName".to_string());
+    spec.external_name = Some(external_name.to_string());
+    spec.cluster_ip = None;
+    spec.cluster_ips = None;
+    svc
+}
+
+fn service_ref(ns: &str, name: &str) -> ServiceRef {
+    ServiceRef {
+        namespace: ns.to_string(),
+        name: name.to_string(),
+    }
+}
+
+#[test]
+fn external_name_lookup() {
+    let test = TestConfig::default();
+    test.index.write().apply(mk_external_name_service(
+        "ns-0",
+        "ext-0",
+        "API.example.com.",
+    ));
+    test.index
+        .write()
+        .apply(mk_external_name_service("ns-1", "ext-0", "api.example.com"));
+
+    // Names are matched case-insensitively, ignoring a trailing dot. Services
+    // in the client's namespace are preferred.
+    let lookup = |host: &str, ns: Option<&str>| test.index.read().lookup_external_name(host, ns);
+    assert_eq!(
+        lookup("api.example.com.", Some("ns-1")),
+        Some(service_ref("ns-1", "ext-0")),
+    );
+    assert_eq!(
+        lookup("api.example.com", Some("ns-2")),
+        Some(service_ref("ns-0", "ext-0")),
+    );
+    assert_eq!(
+        lookup("api.example.com", None),
+        Some(service_ref("ns-0", "ext-0")),
+    );
+    assert_eq!(lookup("www.example.com", Some("ns-0")), None);
+
+    // Names are removed when a Service is updated or deleted.
+    test.index
+        .write()
+        .apply(mk_service("ns-0", "ext-0", ["10.96.0.10"]));
+    <Index as IndexNamespacedResource<k8s::Service>>::delete(
+        &mut test.index.write(),
+        "ns-1".to_string(),
+        "ext-0".to_string(),
+    );
+    assert_eq!(lookup("api.example.com", Some("ns-0")), None);
+}
+
+#[test]
+fn external_name_parent_authority() {
+    let test = TestConfig::default();
+    test.index
+        .write()
+        .apply(mk_service("ns-0", "svc-0", ["10.96.0.10"]));
+    let mut rx = test
+        .index
+        .write()
+        .outbound_policy_rx(
+            "ns-0".to_string(),
+            "svc-0".to_string(),
+            443.try_into().unwrap(),
+        )
+        .expect("svc-0.ns-0 should exist");
+    assert_eq!(
+        rx.borrow_and_update().authority,
+        "svc-0.ns-0.svc.cluster.example.com:443"
+    );
+
+    // When the Service becomes an ExternalName Service, clients still
+    // discover it by its own name. The destination controller resolves the
+    // name that it aliases.
+    test.index
+        .write()
+        .apply(mk_external_name_service("ns-0", "svc-0", "api.example.com"));
+    assert_eq!(
+        rx.borrow_and_update().authority,
+        "svc-0.ns-0.svc.cluster.example.com:443"
+    );
+}
+
+#[test]
+fn external_name_backend() {
+    let test = TestConfig::default();
+    test.index
+        .write()
+        .apply(mk_service("ns-0", "svc-0", ["10.96.0.10"]));
+    test.index
+        .write()
+        .apply(mk_external_name_service("ns-0", "ext-0", "api.example.com"));
+    let mut rx = test
+        .index
+        .write()
+        .outbound_policy_rx(
+            "ns-0".to_string(),
+            "svc-0".to_string(),
+            443.try_into().unwrap(),
+        )
+        .expect("svc-0.ns-0 should exist");
+    assert!(rx.borrow_and_update().http_routes.is_empty());
+
+    // Traffic may be split between an in-cluster Service and an external
+    // name.
+    test.index.write().apply(mk_http_route(
+        "ns-0",
+        "route-0",
+        "svc-0",
+        443,
+        mk_http_rule([
+            mk_backend_ref("svc-0", 443, Some(90)),
+            mk_backend_ref("ext-0", 443, Some(10)),
+        ]),
+    ));
+    assert!(rx.has_changed().unwrap());
+    assert_eq!(
+        rx.borrow_and_update().http_routes["route-0"].rules[0].backends,
+        vec![
+            Backend::Service(WeightedService {
+                weight: 90,
+                authority: "svc-0.ns-0.svc.cluster.example.com:443".to_string(),
+                name: "svc-0".to_string(),
+                namespace: "ns-0".to_string(),
+                port: 443.try_into().unwrap(),
+                circuit_breaker: None,
+            }),
+            Backend::Service(WeightedService {
+                weight: 10,
+                authority: "ext-0.ns-0.svc.cluster.example.com:443".to_string(),
+                name: "ext-0".to_string(),
+                namespace: "ns-0".to_string(),
+                port: 443.try_into().unwrap(),
+                circuit_breaker: None,
+            }),
+        ],
+    );
+}
@@ -0,0 +1,169 @@
+use super::*;
+use crate::outbound::index::{ExternalWorkloadRef, ServiceRef};
+use k8s::ResourceExt;
+use kubert::index::IndexNamespacedResource;
+use linkerd_policy_controller_k8s_api::external_workload::{PortSpec, WorkloadIp};
+
+fn mk_external_workload(
+    ns: impl ToString,
+    name: impl ToString,
+    ips: impl IntoIterator<Item = &'static str>,
+) -> k8s::ExternalWorkload {
+    k8s::ExternalWorkload {
+        metadata: k8s::ObjectMeta {
+            namespace: Some(ns.to_string()),
+            name: Some(name.to_string()),
+            ..Default::default()
+        },
+        spec: k8s::ExternalWorkloadSpec {
+            workload_ips: Some(
+                ips.into_iter()
+                    .map(|ip| WorkloadIp { ip: ip.to_string() })
+                    .collect(),
+            ),
+            ..Default::default()
+        },
+    }
+}
+
+#[test]
+fn external_workload_ips() {
+    let test = TestConfig::default();
+    test.index.write().apply(mk_external_workload(
+        "ns-0",
+        "vm-0",
+        ["198.51.100.10", "2001:db8::10"],
+    ));
+
+    let vm_0 = ExternalWorkloadRef {
+        namespace: "ns-0".to_string(),
+        name: "vm-0".to_string(),
+    };
+    assert_eq!(
+        test.index
+            .read()
+            .lookup_external_workload("198.51.100.10".parse().unwrap()),
+        Some(vm_0.clone()),
+    );
+    assert_eq!(
+        test.index
+            .read()
+            .lookup_external_workload("2001:db8::10".parse().unwrap()),
+        Some(vm_0),
+    );
+
+    // Addresses are removed when the workload is updated or deleted.
+    test.index
+        .write()
+        .apply(mk_external_workload("ns-0", "vm-0", ["198.51.100.11"]));
+    assert_eq!(
+        test.index
+            .read()
+            .lookup_external_workload("198.51.100.10".parse().unwrap()),
+        None,
+    );
+    <Index as IndexNamespacedResource<k8s::ExternalWorkload>>::delete(
+        &mut test.index.write(),
+        "ns-0".to_string(),
+        "vm-0".to_string(),
+    );
+    assert_eq!(
+        test.index
+            .read()
+            .lookup_external_workload("198.51.100.11".parse().unwrap()),
+        None,
+    );
+}
+
+#[test]
+fn external_workload_endpoints() {
+    let test = TestConfig::default();
+
+    let mut workload = mk_external_workload("ns-0", "vm-0", ["198.51.100.10"]);
+    workload
+        .labels_mut()
+        .insert("app".to_string(), "app-0".to_string());
+    workload.spec.ports = Some(vec![PortSpec {
+        name: Some("http".to_string()),
+        port: 8080.try_into().unwrap(),
+        protocol: None,
+    }]);
+    test.index.write().apply(workload);
+
+    let vm_0 = ExternalWorkloadRef {
+        namespace: "ns-0".to_string(),
+        name: "vm-0".to_string(),
+    };
+    assert_eq!(
+        test.index
+            .read()
+            .lookup_external_workload_endpoint(&vm_0, 8080.try_into().unwrap()),
+        None,
+    );
+
+    // The workload is selected by a Service that targets its port by name.
+    test.index.write().apply(mk_selector_service(
+        "ns-0",
+        "svc-0",
+        ("app", "app-0"),
+        80,
+        k8s::IntOrString::String("http".to_string()),
+    ));
+    let svc_0 = ServiceRef {
+        namespace: "ns-0".to_string(),
+        name: "svc-0".to_string(),
+    };
+    assert_eq!(
+        test.index
+            .read()
+            .lookup_external_workload_endpoint(&vm_0, 8080.try_into().unwrap()),
+        Some((svc_0, 80.try_into().unwrap())),
+    );
+    assert_eq!(
+        test.index
+            .read()
+            .lookup_external_workload_endpoint(&vm_0, 9090.try_into().unwrap()),
+        None,
+    );
+
+    // Services in other namespaces and Services that don't select the
+    // workload are ignored.
+    test.index.write().apply(mk_selector_service(
+        "ns-1",
+        "svc-0",
+        ("app", "app-0"),
+        90,
+        k8s::IntOrString::Int(9090),
+    ));
+    test.index.write().apply(mk_selector_service(
+        "ns-0",
+        "svc-1",
+        ("app", "app-1"),
+        90,
+        k8s::IntOrString::Int(9090),
+    ));
+    assert_eq!(
+        test.index
+            .read()
+            .lookup_external_workload_endpoint(&vm_0, 9090.try_into().unwrap()),
+        None,
+    );
+}
+
+fn mk_selector_service(
+    ns: impl ToString,
+    name: impl ToString,
+    (key, value): (&str, &str),
+    port: i32,
+    target_port: k8s::IntOrString,
+) -> k8s::Service {
+    let mut svc = mk_service(ns, name, ["None"]);
+    let spec = svc.spec.as_mut().unwrap();
+    spec.selector = Some([(key.to_string(), value.to_string())].into_iter().collect());
+    spec.ports = Some(vec![k8s::ServicePort {
+        port,
+        target_port: Some(target_port),
+        ..Default::default()
+    }]);
+    svc
+}
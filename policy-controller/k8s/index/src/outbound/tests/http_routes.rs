@@ -0,0 +1,87 @@
+use super::*;
+use linkerd_policy_controller_core::http_route::RouteTimeouts;
+use std::time::Duration;
+
+fn mk_timeouts(
+    request: Option<&str>,
+    backend_request: Option<&str>,
+) -> k8s::policy::httproute::HttpRouteTimeouts {
+    k8s::policy::httproute::HttpRouteTimeouts {
+        request: request.map(|d| d.parse().unwrap()),
+        backend_request: backend_request.map(|d| d.parse().unwrap()),
+    }
+}
+
+#[test]
+fn route_timeouts() {
+    let test = TestConfig::default();
+    test.index
+        .write()
+        .apply(mk_service("ns-0", "svc-0", ["10.96.0.10"]));
+    let mut rx = test
+        .index
+        .write()
+        .outbound_policy_rx(
+            "ns-0".to_string(),
+            "svc-0".to_string(),
+            80.try_into().unwrap(),
+        )
+        .expect("svc-0.ns-0 should exist");
+    assert!(rx.borrow_and_update().http_routes.is_empty());
+
+    let mut rule = mk_http_rule([mk_backend_ref("svc-0", 80, None)]);
+    rule.timeouts = Some(mk_timeouts(Some("10s"), Some("1s")));
+    test.index
+        .write()
+        .apply(mk_http_route("ns-0", "route-0", "svc-0", 80, rule));
+    assert!(rx.has_changed().unwrap());
+    assert_eq!(
+        rx.borrow_and_update().http_routes["route-0"].rules[0].timeouts,
+        RouteTimeouts {
+            request: Some(Duration::from_secs(10)),
+            backend_request: Some(Duration::from_secs(1)),
+        },
+    );
+
+    // A zero-valued timeout disables the timeout.
+    let mut rule = mk_http_rule([mk_backend_ref("svc-0", 80, None)]);
+    rule.timeouts = Some(mk_timeouts(Some("0s"), Some("1s")));
+    test.index
+        .write()
+        .apply(mk_http_route("ns-0", "route-0", "svc-0", 80, rule));
+    assert!(rx.has_changed().unwrap());
+    assert_eq!(
+        rx.borrow_and_update().http_routes["route-0"].rules[0].timeouts,
+        RouteTimeouts {
+            request: None,
+            backend_request: Some(Duration::from_secs(1)),
+        },
+    );
+}
+
+#[test]
+fn invalid_route_timeouts() {
+    let test = TestConfig::default();
+    test.index
+        .write()
+        .apply(mk_service("ns-0", "svc-0", ["10.96.0.10"]));
+    let mut rx = test
+        .index
+        .write()
+        .outbound_policy_rx(
+            "ns-0".to_string(),
+            "svc-0".to_string(),
+            80.try_into().unwrap(),
+        )
+        .expect("svc-0.ns-0 should exist");
+    assert!(rx.borrow_and_update().http_routes.is_empty());
+
+    // The backend request timeout may not exceed the request timeout.
+    let mut rule = mk_http_rule([mk_backend_ref("svc-0", 80, None)]);
+    rule.timeouts = Some(mk_timeouts(Some("1s"), Some("10s")));
+    test.index
+        .write()
+        .apply(mk_http_route("ns-0", "route-0", "svc-0", 80, rule));
+    assert!(!rx.has_changed().unwrap());
+    assert!(rx.borrow().http_routes.is_empty());
+}
@@ -0,0 +1,174 @@
+use super::*;
+use kubert::index::IndexNamespacedResource;
+use linkerd_policy_controller_core::{
+    http_route::HostMatch,
+    outbound::{Backend, TcpRoute, TcpRouteRule, TlsRoute, WeightedService},
+};
+
+fn mk_tcp_route(
+    ns: impl ToString,
+    name: impl ToString,
+    service: &str,
+    port: u16,
+    backends: impl IntoIterator<Item = k8s::gateway::BackendRef>,
+) -> k8s::gateway::TcpRoute {
+    k8s::gateway::TcpRoute {
+        metadata: k8s::ObjectMeta {
+            namespace: Some(ns.to_string()),
+            name: Some(name.to_string()),
+            ..Default::default()
+        },
+        spec: k8s::gateway::TcpRouteSpec {
+            inner: k8s::gateway::CommonRouteSpec {
+                parent_refs: Some(vec![mk_service_parent_ref(service, port)]),
+            },
+            rules: vec![k8s::gateway::TcpRouteRule {
+                backend_refs: backends.into_iter().collect(),
+            }],
+        },
+        status: Some(k8s::gateway::TcpRouteStatus {
+            inner: mk_accepted_status(service, port),
+        }),
+    }
+}
+
+fn mk_tls_route(
+    ns: impl ToString,
+    name: impl ToString,
+    service: &str,
+    port: u16,
+    hostnames: impl IntoIterator<Item = &'static str>,
+    backends: impl IntoIterator<Item = k8s::gateway::BackendRef>,
+) -> k8s::gateway::TlsRoute {
+    k8s::gateway::TlsRoute {
+        metadata: k8s::ObjectMeta {
+            namespace: Some(ns.to_string()),
+            name: Some(name.to_string()),
+            ..Default::default()
+        },
+        spec: k8s::gateway::TlsRouteSpec {
+            inner: k8s::gateway::CommonRouteSpec {
+                parent_refs: Some(vec![mk_service_parent_ref(service, port)]),
+            },
+            hostnames: Some(hostnames.into_iter().map(ToString::to_string).collect()),
+            rules: vec![k8s::gateway::TlsRouteRule {
+                backend_refs: backends.into_iter().collect(),
+            }],
+        },
+        status: Some(k8s::gateway::TlsRouteStatus {
+            inner: mk_accepted_status(service, port),
+        }),
+    }
+}
+
+fn mk_backend(name: &str, port: u16, weight: u32) -> Backend {
+    Backend::Service(WeightedService {
+        weight,
+        authority: format!("{name}.ns-0.svc.cluster.example.com:{port}"),
+        name: name.to_string(),
+        namespace: "ns-0".to_string(),
+        port: port.try_into().unwrap(),
+        circuit_breaker: None,
+    })
+}
+
+#[test]
+fn tcp_route_traffic_split() {
+    let test = TestConfig::default();
+    for (name, ip) in [("db", "10.96.0.10"), ("db-canary", "10.96.0.11")] {
+        test.index.write().apply(mk_service("ns-0", name, [ip]));
+    }
+    let mut rx = test
+        .index
+        .write()
+        .outbound_policy_rx(
+            "ns-0".to_string(),
+            "db".to_string(),
+            5432.try_into().unwrap(),
+        )
+        .expect("db.ns-0 should exist");
+    assert!(rx.borrow_and_update().tcp_routes.is_empty());
+
+    test.index.write().apply(mk_tcp_route(
+        "ns-0",
+        "db-split",
+        "db",
+        5432,
+        [
+            mk_backend_ref("db", 5432, Some(90)),
+            mk_backend_ref("db-canary", 5432, Some(10)),
+            mk_backend_ref("db-missing", 5432, None),
+        ],
+    ));
+    assert!(rx.has_changed().unwrap());
+    assert_eq!(
+        rx.borrow_and_update().tcp_routes["db-split"],
+        TcpRoute {
+            rules: vec![TcpRouteRule {
+                backends: vec![
+                    mk_backend("db", 5432, 90),
+                    mk_backend("db-canary", 5432, 10),
+                    Backend::Invalid {
+                        weight: 1,
+                        message: "Service not found db-missing".to_string(),
+                    },
+                ],
+            }],
+            creation_timestamp: None,
+        },
+    );
+
+    <Index as IndexNamespacedResource<k8s::gateway::TcpRoute>>::delete(
+        &mut test.index.write(),
+        "ns-0".to_string(),
+        "db-split".to_string(),
+    );
+    assert!(rx.has_changed().unwrap());
+    assert!(rx.borrow_and_update().tcp_routes.is_empty());
+}
+
+#[test]
+fn tls_route_sni_match() {
+    let test = TestConfig::default();
+    for (name, ip) in [("broker", "10.96.0.10"), ("broker-canary", "10.96.0.11")] {
+        test.index.write().apply(mk_service("ns-0", name, [ip]));
+    }
+    let mut rx = test
+        .index
+        .write()
+        .outbound_policy_rx(
+            "ns-0".to_string(),
+            "broker".to_string(),
+            9093.try_into().unwrap(),
+        )
+        .expect("broker.ns-0 should exist");
+    assert!(rx.borrow_and_update().tls_routes.is_empty());
+
+    test.index.write().apply(mk_tls_route(
+        "ns-0",
+        "broker-canary",
+        "broker",
+        9093,
+        ["canary.broker.example.com"],
+        [mk_backend_ref("broker-canary", 9093, None)],
+    ));
+    assert!(rx.has_changed().unwrap());
+    assert_eq!(
+        rx.borrow_and_update().tls_routes["broker-canary"],
+        TlsRoute {
+            hostnames: vec![HostMatch::Exact("canary.broker.example.com".to_string())],
+            rules: vec![TcpRouteRule {
+                backends: vec![mk_backend("broker-canary", 9093, 1)],
+            }],
+            creation_timestamp: None,
+        },
+    );
+
+    <Index as IndexNamespacedResource<k8s::gateway::TlsRoute>>::delete(
+        &mut test.index.write(),
+        "ns-0".to_string(),
+        "broker-canary".to_string(),
+    );
+    assert!(rx.has_changed().unwrap());
+    assert!(rx.borrow_and_update().tls_routes.is_empty());
+}
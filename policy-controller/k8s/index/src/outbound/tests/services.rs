@@ -0,0 +1,86 @@
+use super::*;
+use crate::outbound::index::ServiceRef;
+use kubert::index::IndexNamespacedResource;
+
+fn service_ref(ns: &str, name: &str) -> ServiceRef {
+    ServiceRef {
+        namespace: ns.to_string(),
+        name: name.to_string(),
+    }
+}
+
+#[test]
+fn dual_stack_cluster_ips() {
+    let test = TestConfig::default();
+    test.index
+        .write()
+        .apply(mk_service("ns-0", "svc-0", ["10.96.0.10", "fd00:10:96::a"]));
+
+    let index = test.index.read();
+    assert_eq!(
+        index.lookup_service("10.96.0.10".parse().unwrap()),
+        Some(service_ref("ns-0", "svc-0")),
+    );
+    assert_eq!(
+        index.lookup_service("fd00:10:96::a".parse().unwrap()),
+        Some(service_ref("ns-0", "svc-0")),
+    );
+}
+
+#[test]
+fn stale_cluster_ips_removed_on_update() {
+    let test = TestConfig::default();
+    test.index
+        .write()
+        .apply(mk_service("ns-0", "svc-0", ["10.96.0.10", "fd00:10:96::a"]));
+    test.index
+        .write()
+        .apply(mk_service("ns-0", "svc-0", ["10.96.0.11"]));
+
+    let index = test.index.read();
+    assert_eq!(index.lookup_service("10.96.0.10".parse().unwrap()), None);
+    assert_eq!(index.lookup_service("fd00:10:96::a".parse().unwrap()), None);
+    assert_eq!(
+        index.lookup_service("10.96.0.11".parse().unwrap()),
+        Some(service_ref("ns-0", "svc-0")),
+    );
+}
+
+#[test]
+fn reassigned_cluster_ip_not_removed() {
+    let test = TestConfig::default();
+    test.index
+        .write()
+        .apply(mk_service("ns-0", "svc-0", ["10.96.0.10"]));
+    // The address is reassigned to another Service before the update to the
+    // original Service is observed.
+    test.index
+        .write()
+        .apply(mk_service("ns-0", "svc-1", ["10.96.0.10"]));
+    test.index
+        .write()
+        .apply(mk_service("ns-0", "svc-0", ["10.96.0.11"]));
+
+    let index = test.index.read();
+    assert_eq!(
+        index.lookup_service("10.96.0.10".parse().unwrap()),
+        Some(service_ref("ns-0", "svc-1")),
+    );
+}
+
+#[test]
+fn delete_removes_all_cluster_ips() {
+    let test = TestConfig::default();
+    test.index
+        .write()
+        .apply(mk_service("ns-0", "svc-0", ["10.96.0.10", "fd00:10:96::a"]));
+    <Index as IndexNamespacedResource<k8s::Service>>::delete(
+        &mut test.index.write(),
+        "ns-0".to_string(),
+        "svc-0".to_string(),
+    );
+
+    let index = test.index.read();
+    assert_eq!(index.lookup_service("10.96.0.10".parse().unwrap()), None);
+    assert_eq!(index.lookup_service("fd00:10:96::a".parse().unwrap()), None);
+}
@@ -0,0 +1,35 @@
+use crate::http_route::{BackendReference, ParentReference};
+use linkerd_policy_controller_k8s_api::gateway;
+
+pub(crate) fn make_parents(grpc_route: &gateway::GrpcRoute) -> Vec<ParentReference> {
+    let namespace = grpc_route
+        .metadata
+        .namespace
+        .as_deref()
+        .expect("GRPCRoute must have a namespace");
+    grpc_route
+        .spec
+        .inner
+        .parent_refs
+        .iter()
+        .flatten()
+        .map(|pr| ParentReference::from_parent_ref(pr, namespace))
+        .collect()
+}
+
+pub(crate) fn make_backends(grpc_route: &gateway::GrpcRoute) -> Vec<BackendReference> {
+    let namespace = grpc_route
+        .metadata
+        .namespace
+        .as_deref()
+        .expect("GRPCRoute must have a namespace");
+    grpc_route
+        .spec
+        .rules
+        .iter()
+        .flatten()
+        .flat_map(|rule| rule.backend_refs.iter().flatten())
+        .filter_map(|grpc_backend_ref| grpc_backend_ref.backend_ref.as_ref())
+        .map(|br| BackendReference::from_backend_ref(&br.inner, namespace))
+        .collect()
+}
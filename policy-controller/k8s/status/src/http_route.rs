@@ -1,11 +1,11 @@
 use crate::resource_id::ResourceId;
 use linkerd_policy_controller_k8s_api::{
     gateway,
-    policy::{self, Server},
+    policy::{self, EgressNetwork, Server},
     Service,
 };
 
-/// Represents an HTTPRoute's parent reference from its spec.
+/// Represents a route's parent reference from its spec.
 ///
 /// This is separate from the policy controller index's `InboundParentRef`
 /// because it does not validate that the parent reference is not in another
@@ -16,6 +16,7 @@ use linkerd_policy_controller_k8s_api::{
 pub enum ParentReference {
     Server(ResourceId),
     Service(ResourceId, Option<u16>),
+    EgressNetwork(ResourceId, Option<u16>),
     UnknownKind,
 }
 
@@ -59,10 +60,13 @@ pub(crate) fn make_backends(http_route: &policy::HttpRoute) -> Vec<BackendRefere
 }
 
 impl ParentReference {
-    fn from_parent_ref(parent_ref: &gateway::ParentReference, default_namespace: &str) -> Self {
+    pub(crate) fn from_parent_ref(
+        parent_ref: &gateway::ParentReference,
+        default_namespace: &str,
+    ) -> Self {
         if policy::httproute::parent_ref_targets_kind::<Server>(parent_ref) {
             // If the parent reference does not have a namespace, default to using
-            // the HTTPRoute's namespace.
+            // the route's namespace.
             let namespace = parent_ref.namespace.as_deref().unwrap_or(default_namespace);
             ParentReference::Server(ResourceId::new(
                 namespace.to_string(),
@@ -70,12 +74,18 @@ impl ParentReference {
             ))
         } else if policy::httproute::parent_ref_targets_kind::<Service>(parent_ref) {
             // If the parent reference does not have a namespace, default to using
-            // the HTTPRoute's namespace.
+            // the route's namespace.
             let namespace = parent_ref.namespace.as_deref().unwrap_or(default_namespace);
             ParentReference::Service(
                 ResourceId::new(namespace.to_string(), parent_ref.name.clone()),
                 parent_ref.port,
             )
+        } else if policy::httproute::parent_ref_targets_kind::<EgressNetwork>(parent_ref) {
+            let namespace = parent_ref.namespace.as_deref().unwrap_or(default_namespace);
+            ParentReference::EgressNetwork(
+                ResourceId::new(namespace.to_string(), parent_ref.name.clone()),
+                parent_ref.port,
+            )
         } else {
             ParentReference::UnknownKind
         }
@@ -83,7 +93,7 @@ impl ParentReference {
 }
 
 impl BackendReference {
-    fn from_backend_ref(
+    pub(crate) fn from_backend_ref(
         backend_ref: &gateway::BackendObjectReference,
         default_namespace: &str,
     ) -> Self {
@@ -157,6 +167,7 @@ mod test {
                                 port: None,
                             },
                         ]),
+                        timeouts: None,
                     },
                     policy::httproute::HttpRouteRule {
                         matches: None,
@@ -170,11 +181,13 @@ mod test {
                                 port: None,
                             },
                         ]),
+                        timeouts: None,
                     },
                     policy::httproute::HttpRouteRule {
                         matches: None,
                         filters: None,
                         backend_refs: None,
+                        timeouts: None,
                     },
                 ]),
             },
@@ -222,6 +235,7 @@ mod test {
                             port: None,
                         },
                     ]),
+                    timeouts: None,
                 }]),
             },
             status: None,
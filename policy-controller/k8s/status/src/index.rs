@@ -1,7 +1,9 @@
 use crate::{
+    grpc_route,
     http_route::{self, BackendReference, ParentReference},
     resource_id::ResourceId,
     service::Service,
+    tcp_route, tls_route,
 };
 use ahash::{AHashMap as HashMap, AHashSet as HashSet};
 use chrono::offset::Utc;
@@ -21,6 +23,7 @@ use tokio::{
 
 pub(crate) const POLICY_API_GROUP: &str = "policy.linkerd.io";
 const POLICY_API_VERSION: &str = "policy.linkerd.io/v1alpha1";
+const GATEWAY_API_VERSION: &str = "gateway.networking.k8s.io/v1alpha2";
 
 mod conditions {
     pub const RESOLVED_REFS: &str = "ResolvedRefs";
@@ -31,7 +34,9 @@ mod reasons {
     pub const RESOLVED_REFS: &str = "ResolvedRefs";
     pub const BACKEND_NOT_FOUND: &str = "BackendNotFound";
     pub const INVALID_KIND: &str = "InvalidKind";
+    pub const REF_NOT_PERMITTED: &str = "RefNotPermitted";
     pub const NO_MATCHING_PARENT: &str = "NoMatchingParent";
+    pub const ROUTE_CONFLICTED: &str = "RouteReasonConflicted";
 }
 
 mod cond_statuses {
@@ -61,11 +66,13 @@ pub struct Index {
     claims: Receiver<Arc<Claim>>,
     updates: UnboundedSender<Update>,
 
-    /// Maps HttpRoute ids to a list of their parent and backend refs,
+    /// Maps route ids to a list of their parent and backend refs,
     /// regardless of if those parents have accepted the route.
-    http_route_refs: HashMap<ResourceId, References>,
+    route_refs: HashMap<(RouteKind, ResourceId), References>,
     servers: HashSet<ResourceId>,
     services: HashMap<ResourceId, Service>,
+    egress_networks: HashSet<ResourceId>,
+    reference_grants: HashMap<ResourceId, gateway::ReferenceGrantSpec>,
 }
 
 #[derive(Clone, PartialEq)]
@@ -77,9 +84,19 @@ struct References {
 #[derive(Debug, PartialEq)]
 pub struct Update {
     pub id: ResourceId,
+    pub kind: RouteKind,
     pub patch: k8s::Patch<serde_json::Value>,
 }
 
+/// The kind of route resource to which an [`Update`] applies.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum RouteKind {
+    Http,
+    Grpc,
+    Tcp,
+    Tls,
+}
+
 impl Controller {
     pub fn new(
         claims: Receiver<Arc<Claim>>,
@@ -97,8 +114,8 @@ impl Controller {
     }
 
     /// Process updates received from the index; each update is a patch that
-    /// should be applied to update the status of an HTTPRoute. A patch should
-    /// only be applied if we are the holder of the write lease.
+    /// should be applied to update the status of an HTTPRoute or GRPCRoute. A
+    /// patch should only be applied if we are the holder of the write lease.
     pub async fn run(mut self) {
         let patch_params = k8s::PatchParams::apply(POLICY_API_GROUP);
 
@@ -119,10 +136,10 @@ impl Controller {
                 // If this policy controller is not the leader, it should
                 // process through the updates queue but not actually patch
                 // any resources.
-                Some(Update { id, patch}) = self.updates.recv(), if self.leader => {
-                    let api = k8s::Api::<k8s::policy::HttpRoute>::namespaced(self.client.clone(), &id.namespace);
+                Some(Update { id, kind, patch }) = self.updates.recv(), if self.leader => {
+                    let api = k8s::Api::<k8s::DynamicObject>::namespaced_with(self.client.clone(), &id.namespace, &kind.api_resource());
                     if let Err(error) = api.patch_status(&id.name, &patch_params, &patch).await {
-                        tracing::error!(namespace = %id.namespace, name = %id.name, %error, "Failed to patch HTTPRoute");
+                        tracing::error!(namespace = %id.namespace, name = %id.name, kind = kind.as_str(), %error, "Failed to patch route");
                     }
                 }
             }
@@ -140,18 +157,20 @@ impl Index {
             name: name.to_string(),
             claims,
             updates,
-            http_route_refs: HashMap::new(),
+            route_refs: HashMap::new(),
             servers: HashSet::new(),
             services: HashMap::new(),
+            egress_networks: HashSet::new(),
+            reference_grants: HashMap::new(),
         }))
     }
 
     /// When the write lease holder changes or a time duration has elapsed,
-    /// the index reconciles the statuses for all HTTPRoutes on the cluster.
+    /// the index reconciles the statuses for all routes on the cluster.
     ///
     /// This reconciliation loop ensures that if errors occur when the
     /// Controller applies patches or the write lease holder changes, all
-    /// HTTPRoutes have an up-to-date status.
+    /// routes have an up-to-date status.
     pub async fn run(index: Arc<RwLock<Self>>) {
         // Clone the claims watch out of the index. This will immediately
         // drop the read lock on the index so that it is not held for the
@@ -168,7 +187,7 @@ impl Index {
             }
 
             // The claimant has changed, or we should attempt to reconcile all
-            // HTTPRoutes to account for any errors. In either case, we should
+            // routes to account for any errors. In either case, we should
             // only proceed if we are the current leader.
             let claims = claims.borrow_and_update();
             let index = index.read();
@@ -182,8 +201,8 @@ impl Index {
 
     // If the route is new or its parentRefs and/or backendRefs have changed,
     // return true, so that a patch is generated; otherwise return false.
-    fn update_http_route(&mut self, id: ResourceId, references: &References) -> bool {
-        match self.http_route_refs.entry(id) {
+    fn update_route(&mut self, kind: RouteKind, id: ResourceId, references: &References) -> bool {
+        match self.route_refs.entry((kind, id)) {
             Entry::Vacant(entry) => {
                 entry.insert(references.clone());
             }
@@ -199,6 +218,7 @@ impl Index {
 
     fn parent_status(
         &self,
+        kind: RouteKind,
         parent_ref: &ParentReference,
         backend_condition: k8s::Condition,
     ) -> Option<gateway::RouteParentStatus> {
@@ -225,14 +245,19 @@ impl Index {
             }
             ParentReference::Service(service, port) => {
                 // service is a valid parent if it exists and it has a cluster_ip.
-                let condition = if self
+                let condition = if !self
                     .services
                     .get(service)
                     .map_or(false, |svc| svc.valid_parent_service())
                 {
-                    accepted()
-                } else {
                     no_matching_parent()
+                } else if kind == RouteKind::Http && self.has_grpc_route(service, *port) {
+                    // The proxy is configured with either the HTTPRoutes or
+                    // the GRPCRoutes of a Service port, and GRPCRoutes take
+                    // precedence.
+                    route_conflicted()
+                } else {
+                    accepted()
                 };
 
                 Some(gateway::RouteParentStatus {
@@ -248,11 +273,50 @@ impl Index {
                     conditions: vec![condition, backend_condition],
                 })
             }
+            ParentReference::EgressNetwork(egress, port) => {
+                let condition = if self.egress_networks.contains(egress) {
+                    accepted()
+                } else {
+                    no_matching_parent()
+                };
+
+                Some(gateway::RouteParentStatus {
+                    parent_ref: gateway::ParentReference {
+                        group: Some(POLICY_API_GROUP.to_string()),
+                        kind: Some("EgressNetwork".to_string()),
+                        namespace: Some(egress.namespace.clone()),
+                        name: egress.name.clone(),
+                        section_name: None,
+                        port: *port,
+                    },
+                    controller_name: POLICY_CONTROLLER_NAME.to_string(),
+                    conditions: vec![condition, backend_condition],
+                })
+            }
             ParentReference::UnknownKind => None,
         }
     }
 
-    fn backend_condition(&self, backend_refs: &[BackendReference]) -> k8s::Condition {
+    /// Returns true if a GRPCRoute attaches to the Service port.
+    fn has_grpc_route(&self, service: &ResourceId, port: Option<u16>) -> bool {
+        self.route_refs
+            .iter()
+            .filter(|((kind, _), _)| *kind == RouteKind::Grpc)
+            .flat_map(|(_, references)| references.parents.iter())
+            .any(|parent_ref| match parent_ref {
+                ParentReference::Service(s, p) => {
+                    s == service && (p.is_none() || port.is_none() || *p == port)
+                }
+                _ => false,
+            })
+    }
+
+    fn backend_condition(
+        &self,
+        kind: RouteKind,
+        namespace: &str,
+        backend_refs: &[BackendReference],
+    ) -> k8s::Condition {
         // If even one backend has a reference to an unknown / unsupported
         // reference, return invalid backend condition
         if backend_refs
@@ -262,6 +326,15 @@ impl Index {
             return invalid_backend_kind();
         }
 
+        // Backends in other namespaces must be permitted by a ReferenceGrant
+        // in the backend's namespace.
+        if backend_refs.iter().any(|backend_ref| match backend_ref {
+            BackendReference::Service(service) => !self.backend_permitted(kind, namespace, service),
+            _ => false,
+        }) {
+            return ref_not_permitted();
+        }
+
         // If all references have been resolved (i.e exist in our services cache),
         // return positive status, otherwise, one of them does not exist
         if backend_refs.iter().any(|backend_ref| match backend_ref {
@@ -274,36 +347,162 @@ impl Index {
         }
     }
 
-    fn make_http_route_patch(
+    fn backend_permitted(&self, kind: RouteKind, namespace: &str, service: &ResourceId) -> bool {
+        if service.namespace == namespace {
+            return true;
+        }
+        self.reference_grants
+            .iter()
+            .filter(|(id, _)| id.namespace == service.namespace)
+            .any(|(_, grant)| match kind {
+                RouteKind::Http => {
+                    grant.permits::<k8s::policy::HttpRoute, k8s::Service>(namespace, &service.name)
+                }
+                RouteKind::Grpc => {
+                    grant.permits::<gateway::GrpcRoute, k8s::Service>(namespace, &service.name)
+                }
+                RouteKind::Tcp => {
+                    grant.permits::<gateway::TcpRoute, k8s::Service>(namespace, &service.name)
+                }
+                RouteKind::Tls => {
+                    grant.permits::<gateway::TlsRoute, k8s::Service>(namespace, &service.name)
+                }
+            })
+    }
+
+    fn route_status(
         &self,
+        kind: RouteKind,
         id: &ResourceId,
         parents: &[ParentReference],
         backends: &[BackendReference],
-    ) -> k8s::Patch<serde_json::Value> {
-        let backend_condition = self.backend_condition(backends);
+    ) -> gateway::RouteStatus {
+        let backend_condition = self.backend_condition(kind, &id.namespace, backends);
         let parent_statuses = parents
             .iter()
-            .filter_map(|parent_ref| self.parent_status(parent_ref, backend_condition.clone()))
+            .filter_map(|parent_ref| {
+                self.parent_status(kind, parent_ref, backend_condition.clone())
+            })
             .collect();
-        let status = gateway::HttpRouteStatus {
-            inner: gateway::RouteStatus {
-                parents: parent_statuses,
-            },
-        };
-        make_patch(&id.name, status)
+        gateway::RouteStatus {
+            parents: parent_statuses,
+        }
+    }
+
+    fn make_route_patch(
+        &self,
+        kind: RouteKind,
+        id: &ResourceId,
+        references: &References,
+    ) -> k8s::Patch<serde_json::Value> {
+        let status = self.route_status(kind, id, &references.parents, &references.backends);
+        make_route_patch(kind, &id.name, status)
+    }
+
+    fn index_route(&mut self, kind: RouteKind, id: ResourceId, references: References) {
+        // Insert the references into the index; if the route is already in
+        // the index and it hasn't changed, skip creating a patch.
+        if !self.update_route(kind, id.clone(), &references) {
+            return;
+        }
+
+        // If we're not the leader, skip creating a patch and sending an
+        // update to the Controller.
+        if !self.claims.borrow().is_current_for(&self.name) {
+            tracing::debug!(%self.name, "Lease non-holder skipping controller update");
+            return;
+        }
+
+        // Create a patch for the route and send it to the Controller so that
+        // it is applied.
+        let patch = self.make_route_patch(kind, &id, &references);
+        if let Err(error) = self.updates.send(Update {
+            id: id.clone(),
+            kind,
+            patch,
+        }) {
+            tracing::error!(%id.namespace, %id.name, kind = kind.as_str(), %error, "Failed to send route patch")
+        }
     }
 
     fn reconcile(&self) {
-        for (id, references) in self.http_route_refs.iter() {
-            let patch = self.make_http_route_patch(id, &references.parents, &references.backends);
-            if let Err(error) = self.updates.send(Update {
-                id: id.clone(),
-                patch,
-            }) {
-                tracing::error!(%id.namespace, %id.name, %error, "Failed to send HTTPRoute patch")
+        for ((kind, id), references) in self.route_refs.iter() {
+            self.send_route_patch(*kind, id, references);
+        }
+    }
+
+    /// Reconciles the HTTPRoutes that share a Service parent with a GRPCRoute,
+    /// since their acceptance depends on the GRPCRoute.
+    fn reconcile_http_routes(&self, grpc_parents: &[ParentReference]) {
+        if !self.claims.borrow().is_current_for(&self.name) {
+            tracing::debug!(%self.name, "Lease non-holder skipping controller update");
+            return;
+        }
+
+        let services = grpc_parents
+            .iter()
+            .filter_map(|parent_ref| match parent_ref {
+                ParentReference::Service(service, _) => Some(service),
+                _ => None,
+            })
+            .collect::<HashSet<_>>();
+        if services.is_empty() {
+            return;
+        }
+
+        for ((kind, id), references) in self.route_refs.iter() {
+            let shares_parent = references
+                .parents
+                .iter()
+                .any(|parent_ref| match parent_ref {
+                    ParentReference::Service(service, _) => services.contains(service),
+                    _ => false,
+                });
+            if *kind == RouteKind::Http && shares_parent {
+                self.send_route_patch(*kind, id, references);
             }
         }
     }
+
+    fn send_route_patch(&self, kind: RouteKind, id: &ResourceId, references: &References) {
+        let patch = self.make_route_patch(kind, id, references);
+        if let Err(error) = self.updates.send(Update {
+            id: id.clone(),
+            kind,
+            patch,
+        }) {
+            tracing::error!(%id.namespace, %id.name, kind = kind.as_str(), %error, "Failed to send route patch")
+        }
+    }
+}
+
+// === impl RouteKind ===
+
+impl RouteKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Http => "HTTPRoute",
+            Self::Grpc => "GRPCRoute",
+            Self::Tcp => "TCPRoute",
+            Self::Tls => "TLSRoute",
+        }
+    }
+
+    fn api_version(&self) -> &'static str {
+        match self {
+            Self::Http => POLICY_API_VERSION,
+            Self::Grpc | Self::Tcp | Self::Tls => GATEWAY_API_VERSION,
+        }
+    }
+
+    fn api_resource(&self) -> k8s::ApiResource {
+        match self {
+            Self::Http => k8s::ApiResource::erase::<k8s::policy::HttpRoute>(&()),
+            Self::Grpc => k8s::ApiResource::erase::<gateway::GrpcRoute>(&()),
+            Self::Tcp => k8s::ApiResource::erase::<gateway::TcpRoute>(&()),
+            Self::Tls => k8s::ApiResource::erase::<gateway::TlsRoute>(&()),
+        }
+    }
 }
 
 impl kubert::index::IndexNamespacedResource<k8s::policy::HttpRoute> for Index {
@@ -320,38 +519,91 @@ impl kubert::index::IndexNamespacedResource<k8s::policy::HttpRoute> for Index {
         // Create the route backends
         let backends = http_route::make_backends(&resource);
 
-        // Construct references and insert into the index; if the HTTPRoute is
-        // already in the index and it hasn't changed, skip creating a patch.
-        let references = References { parents, backends };
-        if !self.update_http_route(id.clone(), &references) {
-            return;
-        }
+        self.index_route(RouteKind::Http, id, References { parents, backends });
+    }
 
-        // If we're not the leader, skip creating a patch and sending an
-        // update to the Controller.
-        if !self.claims.borrow().is_current_for(&self.name) {
-            tracing::debug!(%self.name, "Lease non-holder skipping controller update");
-            return;
+    fn delete(&mut self, namespace: String, name: String) {
+        let id = ResourceId::new(namespace, name);
+        self.route_refs.remove(&(RouteKind::Http, id));
+    }
+
+    // Since apply only reindexes a single HTTPRoute at a time, there's no need
+    // to handle resets specially.
+}
+
+impl kubert::index::IndexNamespacedResource<gateway::GrpcRoute> for Index {
+    fn apply(&mut self, resource: gateway::GrpcRoute) {
+        let namespace = resource
+            .namespace()
+            .expect("GRPCRoute must have a namespace");
+        let name = resource.name_unchecked();
+        let id = ResourceId::new(namespace, name);
+
+        let parents = grpc_route::make_parents(&resource);
+        let backends = grpc_route::make_backends(&resource);
+
+        // If the route's parents change, HTTPRoutes on both the previous and
+        // the current parents may need their status updated.
+        let mut grpc_parents = self
+            .route_refs
+            .get(&(RouteKind::Grpc, id.clone()))
+            .map(|references| references.parents.clone())
+            .unwrap_or_default();
+        let parents_changed = grpc_parents != parents;
+        grpc_parents.extend(parents.iter().cloned());
+
+        self.index_route(RouteKind::Grpc, id, References { parents, backends });
+        if parents_changed {
+            self.reconcile_http_routes(&grpc_parents);
         }
+    }
 
-        // Create a patch for the HTTPRoute and send it to the Controller so
-        // that it is applied.
-        let patch = self.make_http_route_patch(&id, &references.parents, &references.backends);
-        if let Err(error) = self.updates.send(Update {
-            id: id.clone(),
-            patch,
-        }) {
-            tracing::error!(%id.namespace, %id.name, %error, "Failed to send HTTPRoute patch")
+    fn delete(&mut self, namespace: String, name: String) {
+        let id = ResourceId::new(namespace, name);
+        if let Some(references) = self.route_refs.remove(&(RouteKind::Grpc, id)) {
+            self.reconcile_http_routes(&references.parents);
         }
     }
+}
+
+impl kubert::index::IndexNamespacedResource<gateway::TcpRoute> for Index {
+    fn apply(&mut self, resource: gateway::TcpRoute) {
+        let namespace = resource
+            .namespace()
+            .expect("TCPRoute must have a namespace");
+        let name = resource.name_unchecked();
+        let id = ResourceId::new(namespace, name);
+
+        let parents = tcp_route::make_parents(&resource);
+        let backends = tcp_route::make_backends(&resource);
+
+        self.index_route(RouteKind::Tcp, id, References { parents, backends });
+    }
 
     fn delete(&mut self, namespace: String, name: String) {
         let id = ResourceId::new(namespace, name);
-        self.http_route_refs.remove(&id);
+        self.route_refs.remove(&(RouteKind::Tcp, id));
     }
+}
 
-    // Since apply only reindexes a single HTTPRoute at a time, there's no need
-    // to handle resets specially.
+impl kubert::index::IndexNamespacedResource<gateway::TlsRoute> for Index {
+    fn apply(&mut self, resource: gateway::TlsRoute) {
+        let namespace = resource
+            .namespace()
+            .expect("TLSRoute must have a namespace");
+        let name = resource.name_unchecked();
+        let id = ResourceId::new(namespace, name);
+
+        let parents = tls_route::make_parents(&resource);
+        let backends = tls_route::make_backends(&resource);
+
+        self.index_route(RouteKind::Tls, id, References { parents, backends });
+    }
+
+    fn delete(&mut self, namespace: String, name: String) {
+        let id = ResourceId::new(namespace, name);
+        self.route_refs.remove(&(RouteKind::Tls, id));
+    }
 }
 
 impl kubert::index::IndexNamespacedResource<k8s::policy::Server> for Index {
@@ -420,13 +672,85 @@ impl kubert::index::IndexNamespacedResource<k8s::Service> for Index {
     // to handle resets specially.
 }
 
-pub(crate) fn make_patch(
+impl kubert::index::IndexNamespacedResource<k8s::policy::EgressNetwork> for Index {
+    fn apply(&mut self, resource: k8s::policy::EgressNetwork) {
+        let namespace = resource
+            .namespace()
+            .expect("EgressNetwork must have a namespace");
+        let name = resource.name_unchecked();
+        let id = ResourceId::new(namespace, name);
+
+        self.egress_networks.insert(id);
+
+        // If we're not the leader, skip reconciling the cluster.
+        if !self.claims.borrow().is_current_for(&self.name) {
+            tracing::debug!(%self.name, "Lease non-holder skipping controller update");
+            return;
+        }
+        self.reconcile();
+    }
+
+    fn delete(&mut self, namespace: String, name: String) {
+        let id = ResourceId::new(namespace, name);
+
+        self.egress_networks.remove(&id);
+
+        // If we're not the leader, skip reconciling the cluster.
+        if !self.claims.borrow().is_current_for(&self.name) {
+            tracing::debug!(%self.name, "Lease non-holder skipping controller update");
+            return;
+        }
+        self.reconcile();
+    }
+
+    // Since apply only reindexes a single EgressNetwork at a time, there's no
+    // need to handle resets specially.
+}
+
+impl kubert::index::IndexNamespacedResource<gateway::ReferenceGrant> for Index {
+    fn apply(&mut self, resource: gateway::ReferenceGrant) {
+        let namespace = resource
+            .namespace()
+            .expect("ReferenceGrant must have a namespace");
+        let name = resource.name_unchecked();
+        let id = ResourceId::new(namespace, name);
+
+        self.reference_grants.insert(id, resource.spec);
+
+        // If we're not the leader, skip reconciling the cluster.
+        if !self.claims.borrow().is_current_for(&self.name) {
+            tracing::debug!(%self.name, "Lease non-holder skipping controller update");
+            return;
+        }
+        self.reconcile();
+    }
+
+    fn delete(&mut self, namespace: String, name: String) {
+        let id = ResourceId::new(namespace, name);
+
+        self.reference_grants.remove(&id);
+
+        // If we're not the leader, skip reconciling the cluster.
+        if !self.claims.borrow().is_current_for(&self.name) {
+            tracing::debug!(%self.name, "Lease non-holder skipping controller update");
+            return;
+        }
+        self.reconcile();
+    }
+
+    // Since apply only reindexes a single ReferenceGrant at a time, there's
+    // no need to handle resets specially.
+}
+
+pub(crate) fn make_route_patch(
+    kind: RouteKind,
     name: &str,
-    status: gateway::HttpRouteStatus,
+    status: gateway::RouteStatus,
 ) -> k8s::Patch<serde_json::Value> {
+    // All route status types flatten the common route status.
     let value = serde_json::json!({
-        "apiVersion": POLICY_API_VERSION,
-            "kind": "HTTPRoute",
+        "apiVersion": kind.api_version(),
+            "kind": kind.as_str(),
             "name": name,
             "status": status,
     });
@@ -452,6 +776,17 @@ fn no_matching_parent() -> k8s::Condition {
     }
 }
 
+fn route_conflicted() -> k8s::Condition {
+    k8s::Condition {
+        last_transition_time: k8s::Time(now()),
+        message: "".to_string(),
+        observed_generation: None,
+        reason: reasons::ROUTE_CONFLICTED.to_string(),
+        status: cond_statuses::STATUS_FALSE.to_string(),
+        type_: conditions::ACCEPTED.to_string(),
+    }
+}
+
 fn accepted() -> k8s::Condition {
     k8s::Condition {
         last_transition_time: k8s::Time(now()),
@@ -485,6 +820,17 @@ fn backend_not_found() -> k8s::Condition {
     }
 }
 
+fn ref_not_permitted() -> k8s::Condition {
+    k8s::Condition {
+        last_transition_time: k8s::Time(now()),
+        message: "".to_string(),
+        observed_generation: None,
+        reason: reasons::REF_NOT_PERMITTED.to_string(),
+        status: cond_statuses::STATUS_FALSE.to_string(),
+        type_: conditions::RESOLVED_REFS.to_string(),
+    }
+}
+
 fn invalid_backend_kind() -> k8s::Condition {
     k8s::Condition {
         last_transition_time: k8s::Time(now()),
@@ -1,7 +1,10 @@
+mod grpc_route;
 mod http_route;
 mod index;
 mod resource_id;
 mod service;
+mod tcp_route;
+mod tls_route;
 
 #[cfg(test)]
 mod tests;
@@ -13,8 +13,10 @@ impl Service {
             .as_ref()
             .filter(|cip| !cip.eq_ignore_ascii_case("none"))
             .is_some();
+        // ExternalName Services have no cluster IP, but clients may still
+        // discover them by name.
         let external_name = self.type_.as_deref() == Some("ExternalName");
-        cluster_ip && !external_name
+        cluster_ip || external_name
     }
 }
 
@@ -0,0 +1,148 @@
+use crate::{
+    index::{self, RouteKind},
+    resource_id::ResourceId,
+    Index,
+};
+use kubert::index::IndexNamespacedResource;
+use linkerd_policy_controller_core::POLICY_CONTROLLER_NAME;
+use linkerd_policy_controller_k8s_api::{self as k8s, gateway};
+use std::sync::Arc;
+use tokio::sync::{mpsc, watch};
+
+#[test]
+fn grpc_route_accepted_after_service_create() {
+    let hostname = "test";
+    let claim = kubert::lease::Claim {
+        holder: "test".to_string(),
+        expiry: chrono::DateTime::<chrono::Utc>::MAX_UTC,
+    };
+    let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
+    let (updates_tx, mut updates_rx) = mpsc::unbounded_channel();
+    let index = Index::shared(hostname, claims_rx, updates_tx);
+
+    // Apply the route.
+    let grpc_route = make_route("ns-0", "route-foo", "svc-0");
+    index.write().apply(grpc_route);
+
+    // The first update will be that the GRPCRoute is not accepted because the
+    // Service has not been created yet.
+    let id = ResourceId::new("ns-0".to_string(), "route-foo".to_string());
+    let parent_status = make_parent_status("ns-0", "svc-0", "False", "NoMatchingParent");
+    let patch = index::make_route_patch(
+        RouteKind::Grpc,
+        "route-foo",
+        make_status(vec![parent_status]),
+    );
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(RouteKind::Grpc, update.kind);
+    assert_eq!(patch, update.patch);
+
+    // Apply the service.
+    index.write().apply(k8s::Service {
+        metadata: k8s::ObjectMeta {
+            namespace: Some("ns-0".to_string()),
+            name: Some("svc-0".to_string()),
+            ..Default::default()
+        },
+        spec: Some(k8s::ServiceSpec {
+            cluster_ip: Some("10.1.2.3".to_string()),
+            ..Default::default()
+        }),
+        status: None,
+    });
+
+    // The second update will be that the GRPCRoute is accepted because the
+    // Service has been created.
+    let parent_status = make_parent_status("ns-0", "svc-0", "True", "Accepted");
+    let patch = index::make_route_patch(
+        RouteKind::Grpc,
+        "route-foo",
+        make_status(vec![parent_status]),
+    );
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(RouteKind::Grpc, update.kind);
+    assert_eq!(patch, update.patch);
+    assert!(updates_rx.try_recv().is_err());
+}
+
+fn make_route(
+    namespace: impl ToString,
+    name: impl ToString,
+    service: impl ToString,
+) -> gateway::GrpcRoute {
+    gateway::GrpcRoute {
+        metadata: k8s::ObjectMeta {
+            namespace: Some(namespace.to_string()),
+            name: Some(name.to_string()),
+            ..Default::default()
+        },
+        spec: gateway::GrpcRouteSpec {
+            inner: gateway::CommonRouteSpec {
+                parent_refs: Some(vec![gateway::ParentReference {
+                    group: Some("core".to_string()),
+                    kind: Some("Service".to_string()),
+                    namespace: None,
+                    name: service.to_string(),
+                    section_name: None,
+                    port: Some(8080),
+                }]),
+            },
+            hostnames: None,
+            rules: Some(vec![gateway::GrpcRouteRule {
+                matches: Some(vec![gateway::GrpcRouteMatch {
+                    method: Some(gateway::GrpcMethodMatch::Exact {
+                        service: Some("io.linkerd.Foo".to_string()),
+                        method: None,
+                    }),
+                    headers: None,
+                }]),
+                filters: None,
+                backend_refs: None,
+            }]),
+        },
+        status: None,
+    }
+}
+
+fn make_parent_status(
+    namespace: impl ToString,
+    name: impl ToString,
+    status: impl ToString,
+    reason: impl ToString,
+) -> gateway::RouteParentStatus {
+    let accepted = k8s::Condition {
+        last_transition_time: k8s::Time(chrono::DateTime::<chrono::Utc>::MIN_UTC),
+        message: "".to_string(),
+        observed_generation: None,
+        reason: reason.to_string(),
+        status: status.to_string(),
+        type_: "Accepted".to_string(),
+    };
+    // A route without backends has no resolved references.
+    let resolved_refs = k8s::Condition {
+        last_transition_time: k8s::Time(chrono::DateTime::<chrono::Utc>::MIN_UTC),
+        message: "".to_string(),
+        observed_generation: None,
+        reason: "BackendNotFound".to_string(),
+        status: "False".to_string(),
+        type_: "ResolvedRefs".to_string(),
+    };
+    gateway::RouteParentStatus {
+        parent_ref: gateway::ParentReference {
+            group: Some("core".to_string()),
+            kind: Some("Service".to_string()),
+            namespace: Some(namespace.to_string()),
+            name: name.to_string(),
+            section_name: None,
+            port: Some(8080),
+        },
+        controller_name: POLICY_CONTROLLER_NAME.to_string(),
+        conditions: vec![accepted, resolved_refs],
+    }
+}
+
+fn make_status(parents: Vec<gateway::RouteParentStatus>) -> gateway::RouteStatus {
+    gateway::RouteStatus { parents }
+}
@@ -1,4 +1,8 @@
-use crate::{index, index::POLICY_API_GROUP, resource_id::ResourceId, Index};
+use crate::{
+    index::{self, RouteKind, POLICY_API_GROUP},
+    resource_id::ResourceId,
+    Index,
+};
 use kubert::index::IndexNamespacedResource;
 use linkerd_policy_controller_core::POLICY_CONTROLLER_NAME;
 use linkerd_policy_controller_k8s_api::{self as k8s, gateway, policy::server::Port};
@@ -25,7 +29,7 @@ fn http_route_accepted_after_server_create() {
     let parent_status =
         make_parent_status("ns-0", "srv-8080", "Accepted", "False", "NoMatchingParent");
     let status = make_status(vec![parent_status]);
-    let patch = index::make_patch("route-foo", status);
+    let patch = index::make_route_patch(RouteKind::Http, "route-foo", status);
 
     // The first update will be that the HTTPRoute is not accepted because the
     // Server has been created yet.
@@ -48,7 +52,7 @@ fn http_route_accepted_after_server_create() {
     let id = ResourceId::new("ns-0".to_string(), "route-foo".to_string());
     let parent_status = make_parent_status("ns-0", "srv-8080", "Accepted", "True", "Accepted");
     let status = make_status(vec![parent_status]);
-    let patch = index::make_patch("route-foo", status);
+    let patch = index::make_route_patch(RouteKind::Http, "route-foo", status);
 
     // The second update will be that the HTTPRoute is accepted because the
     // Server has been created.
@@ -89,7 +93,7 @@ fn http_route_rejected_after_server_delete() {
     let id = ResourceId::new("ns-0".to_string(), "route-foo".to_string());
     let parent_status = make_parent_status("ns-0", "srv-8080", "Accepted", "True", "Accepted");
     let status = make_status(vec![parent_status]);
-    let patch = index::make_patch("route-foo", status);
+    let patch = index::make_route_patch(RouteKind::Http, "route-foo", status);
 
     // The second update will be that the HTTPRoute is accepted because the
     // Server has been created.
@@ -111,7 +115,7 @@ fn http_route_rejected_after_server_delete() {
     let parent_status =
         make_parent_status("ns-0", "srv-8080", "Accepted", "False", "NoMatchingParent");
     let status = make_status(vec![parent_status]);
-    let patch = index::make_patch("route-foo", status);
+    let patch = index::make_route_patch(RouteKind::Http, "route-foo", status);
 
     // The third update will be that the HTTPRoute is not accepted because the
     // Server has been deleted.
@@ -121,6 +125,158 @@ fn http_route_rejected_after_server_delete() {
     assert!(updates_rx.try_recv().is_err());
 }
 
+#[test]
+fn http_route_cross_namespace_backend_requires_reference_grant() {
+    let hostname = "test";
+    let claim = kubert::lease::Claim {
+        holder: "test".to_string(),
+        expiry: chrono::DateTime::<chrono::Utc>::MAX_UTC,
+    };
+    let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
+    let (updates_tx, mut updates_rx) = mpsc::unbounded_channel();
+    let index = Index::shared(hostname, claims_rx, updates_tx);
+
+    index.write().apply(make_service("ns-0", "svc"));
+    index.write().apply(make_service("ns-1", "backend"));
+
+    // There should be no update since there are no HTTPRoutes yet.
+    assert!(updates_rx.try_recv().is_err());
+
+    let http_route = make_service_route("ns-0", "route-foo", "svc", ("ns-1", "backend"));
+    index.write().apply(http_route);
+
+    // The backend is in another namespace and no ReferenceGrant permits the
+    // reference.
+    let id = ResourceId::new("ns-0".to_string(), "route-foo".to_string());
+    let parent_status = make_service_parent_status(
+        "ns-0",
+        "svc",
+        vec![
+            make_condition("Accepted", "True", "Accepted"),
+            make_condition("ResolvedRefs", "False", "RefNotPermitted"),
+        ],
+    );
+    let patch = index::make_route_patch(
+        RouteKind::Http,
+        "route-foo",
+        make_status(vec![parent_status]),
+    );
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(patch, update.patch);
+
+    index.write().apply(gateway::ReferenceGrant {
+        metadata: k8s::ObjectMeta {
+            namespace: Some("ns-1".to_string()),
+            name: Some("grant".to_string()),
+            ..Default::default()
+        },
+        spec: gateway::ReferenceGrantSpec {
+            from: vec![gateway::ReferenceGrantFrom {
+                group: POLICY_API_GROUP.to_string(),
+                kind: "HTTPRoute".to_string(),
+                namespace: "ns-0".to_string(),
+            }],
+            to: vec![gateway::ReferenceGrantTo {
+                group: "".to_string(),
+                kind: "Service".to_string(),
+                name: None,
+            }],
+        },
+    });
+
+    // Once the ReferenceGrant exists, the backend is resolved.
+    let parent_status = make_service_parent_status(
+        "ns-0",
+        "svc",
+        vec![
+            make_condition("Accepted", "True", "Accepted"),
+            make_condition("ResolvedRefs", "True", "ResolvedRefs"),
+        ],
+    );
+    let patch = index::make_route_patch(
+        RouteKind::Http,
+        "route-foo",
+        make_status(vec![parent_status]),
+    );
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(patch, update.patch);
+    assert!(updates_rx.try_recv().is_err());
+}
+
+#[test]
+fn http_route_conflicts_with_grpc_route() {
+    let hostname = "test";
+    let claim = kubert::lease::Claim {
+        holder: "test".to_string(),
+        expiry: chrono::DateTime::<chrono::Utc>::MAX_UTC,
+    };
+    let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
+    let (updates_tx, mut updates_rx) = mpsc::unbounded_channel();
+    let index = Index::shared(hostname, claims_rx, updates_tx);
+
+    index.write().apply(make_service("ns-0", "svc"));
+    let http_route = make_service_route("ns-0", "route-foo", "svc", ("ns-0", "svc"));
+    index.write().apply(http_route);
+
+    let id = ResourceId::new("ns-0".to_string(), "route-foo".to_string());
+    let accepted = index::make_route_patch(
+        RouteKind::Http,
+        "route-foo",
+        make_status(vec![make_service_parent_status(
+            "ns-0",
+            "svc",
+            vec![
+                make_condition("Accepted", "True", "Accepted"),
+                make_condition("ResolvedRefs", "True", "ResolvedRefs"),
+            ],
+        )]),
+    );
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(accepted, update.patch);
+
+    // Once a GRPCRoute attaches to the same Service port, it takes precedence
+    // over the HTTPRoute.
+    index
+        .write()
+        .apply(make_grpc_route("ns-0", "grpc-foo", "svc"));
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(RouteKind::Grpc, update.kind);
+
+    let conflicted = index::make_route_patch(
+        RouteKind::Http,
+        "route-foo",
+        make_status(vec![make_service_parent_status(
+            "ns-0",
+            "svc",
+            vec![
+                make_condition("Accepted", "False", "RouteReasonConflicted"),
+                make_condition("ResolvedRefs", "True", "ResolvedRefs"),
+            ],
+        )]),
+    );
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(RouteKind::Http, update.kind);
+    assert_eq!(conflicted, update.patch);
+
+    // The HTTPRoute is accepted again once the GRPCRoute is deleted.
+    {
+        let mut index = index.write();
+        <index::Index as kubert::index::IndexNamespacedResource<gateway::GrpcRoute>>::delete(
+            &mut index,
+            "ns-0".to_string(),
+            "grpc-foo".to_string(),
+        );
+    }
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(accepted, update.patch);
+    assert!(updates_rx.try_recv().is_err());
+}
+
 fn make_server(
     namespace: impl ToString,
     name: impl ToString,
@@ -187,12 +343,126 @@ fn make_route(
                 }]),
                 filters: None,
                 backend_refs: None,
+                timeouts: None,
             }]),
         },
         status: None,
     }
 }
 
+fn make_service(namespace: impl ToString, name: impl ToString) -> k8s::Service {
+    k8s::Service {
+        metadata: k8s::ObjectMeta {
+            namespace: Some(namespace.to_string()),
+            name: Some(name.to_string()),
+            ..Default::default()
+        },
+        spec: Some(k8s::ServiceSpec {
+            cluster_ip: Some("10.0.0.1".to_string()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    }
+}
+
+fn make_service_route(
+    namespace: impl ToString,
+    name: impl ToString,
+    service: impl ToString,
+    (backend_namespace, backend): (&str, &str),
+) -> k8s::policy::HttpRoute {
+    use k8s::policy::httproute::*;
+
+    let mut route = make_route(namespace, name, "");
+    route.spec.inner.parent_refs = Some(vec![ParentReference {
+        group: Some("core".to_string()),
+        kind: Some("Service".to_string()),
+        namespace: None,
+        name: service.to_string(),
+        section_name: None,
+        port: Some(80),
+    }]);
+    for rule in route.spec.rules.iter_mut().flatten() {
+        rule.backend_refs = Some(vec![HttpBackendRef {
+            backend_ref: Some(gateway::BackendRef {
+                weight: None,
+                inner: BackendObjectReference {
+                    group: None,
+                    kind: None,
+                    namespace: Some(backend_namespace.to_string()),
+                    name: backend.to_string(),
+                    port: Some(80),
+                },
+            }),
+            filters: None,
+        }]);
+    }
+    route
+}
+
+fn make_grpc_route(
+    namespace: impl ToString,
+    name: impl ToString,
+    service: impl ToString,
+) -> gateway::GrpcRoute {
+    gateway::GrpcRoute {
+        metadata: k8s::ObjectMeta {
+            namespace: Some(namespace.to_string()),
+            name: Some(name.to_string()),
+            ..Default::default()
+        },
+        spec: gateway::GrpcRouteSpec {
+            inner: gateway::CommonRouteSpec {
+                parent_refs: Some(vec![gateway::ParentReference {
+                    group: Some("core".to_string()),
+                    kind: Some("Service".to_string()),
+                    namespace: None,
+                    name: service.to_string(),
+                    section_name: None,
+                    port: Some(80),
+                }]),
+            },
+            hostnames: None,
+            rules: None,
+        },
+        status: None,
+    }
+}
+
+fn make_condition(
+    type_: impl ToString,
+    status: impl ToString,
+    reason: impl ToString,
+) -> k8s::Condition {
+    k8s::Condition {
+        last_transition_time: k8s::Time(chrono::DateTime::<chrono::Utc>::MIN_UTC),
+        message: "".to_string(),
+        observed_generation: None,
+        reason: reason.to_string(),
+        status: status.to_string(),
+        type_: type_.to_string(),
+    }
+}
+
+fn make_service_parent_status(
+    namespace: impl ToString,
+    name: impl ToString,
+    conditions: Vec<k8s::Condition>,
+) -> gateway::RouteParentStatus {
+    gateway::RouteParentStatus {
+        parent_ref: gateway::ParentReference {
+            group: Some("core".to_string()),
+            kind: Some("Service".to_string()),
+            namespace: Some(namespace.to_string()),
+            name: name.to_string(),
+            section_name: None,
+            port: Some(80),
+        },
+        controller_name: POLICY_CONTROLLER_NAME.to_string(),
+        conditions,
+    }
+}
+
 fn make_parent_status(
     namespace: impl ToString,
     name: impl ToString,
@@ -222,8 +492,6 @@ fn make_parent_status(
     }
 }
 
-fn make_status(parents: Vec<gateway::RouteParentStatus>) -> gateway::HttpRouteStatus {
-    gateway::HttpRouteStatus {
-        inner: gateway::RouteStatus { parents },
-    }
+fn make_status(parents: Vec<gateway::RouteParentStatus>) -> gateway::RouteStatus {
+    gateway::RouteStatus { parents }
 }
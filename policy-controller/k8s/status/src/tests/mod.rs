@@ -1 +1,3 @@
+mod grpc_routes;
 mod http_routes;
+mod tcp_routes;
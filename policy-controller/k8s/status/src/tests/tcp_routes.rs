@@ -0,0 +1,148 @@
+use crate::{
+    index::{self, RouteKind},
+    resource_id::ResourceId,
+    Index,
+};
+use kubert::index::IndexNamespacedResource;
+use linkerd_policy_controller_core::POLICY_CONTROLLER_NAME;
+use linkerd_policy_controller_k8s_api::{self as k8s, gateway};
+use std::sync::Arc;
+use tokio::sync::{mpsc, watch};
+
+#[test]
+fn tcp_route_backends_resolved_after_service_create() {
+    let hostname = "test";
+    let claim = kubert::lease::Claim {
+        holder: "test".to_string(),
+        expiry: chrono::DateTime::<chrono::Utc>::MAX_UTC,
+    };
+    let (_claims_tx, claims_rx) = watch::channel(Arc::new(claim));
+    let (updates_tx, mut updates_rx) = mpsc::unbounded_channel();
+    let index = Index::shared(hostname, claims_rx, updates_tx);
+
+    // Apply the parent service before the route.
+    index.write().apply(make_service("ns-0", "svc-0"));
+    assert!(updates_rx.try_recv().is_err());
+
+    index
+        .write()
+        .apply(make_route("ns-0", "route-foo", "svc-0", "svc-1"));
+
+    // The route is accepted by its parent, but its backend does not exist.
+    let id = ResourceId::new("ns-0".to_string(), "route-foo".to_string());
+    let patch = index::make_route_patch(
+        RouteKind::Tcp,
+        "route-foo",
+        make_status("ns-0", "svc-0", "False", "BackendNotFound"),
+    );
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(RouteKind::Tcp, update.kind);
+    assert_eq!(patch, update.patch);
+
+    // Once the backend exists, its references are resolved.
+    index.write().apply(make_service("ns-0", "svc-1"));
+    let patch = index::make_route_patch(
+        RouteKind::Tcp,
+        "route-foo",
+        make_status("ns-0", "svc-0", "True", "ResolvedRefs"),
+    );
+    let update = updates_rx.try_recv().unwrap();
+    assert_eq!(id, update.id);
+    assert_eq!(RouteKind::Tcp, update.kind);
+    assert_eq!(patch, update.patch);
+    assert!(updates_rx.try_recv().is_err());
+}
+
+fn make_service(namespace: impl ToString, name: impl ToString) -> k8s::Service {
+    k8s::Service {
+        metadata: k8s::ObjectMeta {
+            namespace: Some(namespace.to_string()),
+            name: Some(name.to_string()),
+            ..Default::default()
+        },
+        spec: Some(k8s::ServiceSpec {
+            cluster_ip: Some("10.1.2.3".to_string()),
+            ..Default::default()
+        }),
+        status: None,
+    }
+}
+
+fn make_route(
+    namespace: impl ToString,
+    name: impl ToString,
+    parent: impl ToString,
+    backend: impl ToString,
+) -> gateway::TcpRoute {
+    gateway::TcpRoute {
+        metadata: k8s::ObjectMeta {
+            namespace: Some(namespace.to_string()),
+            name: Some(name.to_string()),
+            ..Default::default()
+        },
+        spec: gateway::TcpRouteSpec {
+            inner: gateway::CommonRouteSpec {
+                parent_refs: Some(vec![gateway::ParentReference {
+                    group: Some("core".to_string()),
+                    kind: Some("Service".to_string()),
+                    namespace: None,
+                    name: parent.to_string(),
+                    section_name: None,
+                    port: Some(5432),
+                }]),
+            },
+            rules: vec![gateway::TcpRouteRule {
+                backend_refs: vec![gateway::BackendRef {
+                    weight: None,
+                    inner: gateway::BackendObjectReference {
+                        group: None,
+                        kind: None,
+                        name: backend.to_string(),
+                        namespace: None,
+                        port: Some(5432),
+                    },
+                }],
+            }],
+        },
+        status: None,
+    }
+}
+
+fn make_status(
+    namespace: impl ToString,
+    name: impl ToString,
+    resolved: impl ToString,
+    reason: impl ToString,
+) -> gateway::RouteStatus {
+    let accepted = k8s::Condition {
+        last_transition_time: k8s::Time(chrono::DateTime::<chrono::Utc>::MIN_UTC),
+        message: "".to_string(),
+        observed_generation: None,
+        reason: "Accepted".to_string(),
+        status: "True".to_string(),
+        type_: "Accepted".to_string(),
+    };
+    let resolved_refs = k8s::Condition {
+        last_transition_time: k8s::Time(chrono::DateTime::<chrono::Utc>::MIN_UTC),
+        message: "".to_string(),
+        observed_generation: None,
+        reason: reason.to_string(),
+        status: resolved.to_string(),
+        type_: "ResolvedRefs".to_string(),
+    };
+    gateway::RouteStatus {
+        parents: vec![gateway::RouteParentStatus {
+            parent_ref: gateway::ParentReference {
+                group: Some("core".to_string()),
+                kind: Some("Service".to_string()),
+                namespace: Some(namespace.to_string()),
+                name: name.to_string(),
+                section_name: None,
+                port: Some(5432),
+            },
+            controller_name: POLICY_CONTROLLER_NAME.to_string(),
+            conditions: vec![accepted, resolved_refs],
+        }],
+    }
+}
@@ -0,0 +1,33 @@
+use crate::http_route::{BackendReference, ParentReference};
+use linkerd_policy_controller_k8s_api::gateway;
+
+pub(crate) fn make_parents(tls_route: &gateway::TlsRoute) -> Vec<ParentReference> {
+    let namespace = tls_route
+        .metadata
+        .namespace
+        .as_deref()
+        .expect("TLSRoute must have a namespace");
+    tls_route
+        .spec
+        .inner
+        .parent_refs
+        .iter()
+        .flatten()
+        .map(|pr| ParentReference::from_parent_ref(pr, namespace))
+        .collect()
+}
+
+pub(crate) fn make_backends(tls_route: &gateway::TlsRoute) -> Vec<BackendReference> {
+    let namespace = tls_route
+        .metadata
+        .namespace
+        .as_deref()
+        .expect("TLSRoute must have a namespace");
+    tls_route
+        .spec
+        .rules
+        .iter()
+        .flat_map(|rule| rule.backend_refs.iter())
+        .map(|br| BackendReference::from_backend_ref(&br.inner, namespace))
+        .collect()
+}
@@ -1,16 +1,21 @@
 use crate::k8s::{
+    gateway::{self, GrpcRoute, GrpcRouteSpec},
     labels,
     policy::{
-        httproute, AuthorizationPolicy, AuthorizationPolicySpec, HttpRoute, HttpRouteSpec,
-        LocalTargetRef, MeshTLSAuthentication, MeshTLSAuthenticationSpec, NamespacedTargetRef,
-        NetworkAuthentication, NetworkAuthenticationSpec, Server, ServerAuthorization,
+        httproute,
+        server::{Port, ProxyProtocol},
+        AuthorizationPolicy, AuthorizationPolicySpec, EgressNetwork, EgressNetworkSpec,
+        HttpLocalRateLimitPolicy, HttpRoute, HttpRouteSpec, LocalTargetRef, MeshTLSAuthentication,
+        MeshTLSAuthenticationSpec, NamespacedTargetRef, NetworkAuthentication,
+        NetworkAuthenticationSpec, RateLimitPolicySpec, Server, ServerAuthorization,
         ServerAuthorizationSpec, ServerSpec,
     },
+    ExternalWorkload, ExternalWorkloadSpec,
 };
 use anyhow::{anyhow, bail, Result};
 use futures::future;
 use hyper::{body::Buf, http, Body, Request, Response};
-use k8s_openapi::api::core::v1::{Namespace, ServiceAccount};
+use k8s_openapi::api::core::v1::{Namespace, Pod, Service, ServiceAccount};
 use kube::{core::DynamicObject, Resource, ResourceExt};
 use linkerd_policy_controller_core as core;
 use linkerd_policy_controller_k8s_index as index;
@@ -111,6 +116,14 @@ impl Admission {
             return self.admit_spec::<NetworkAuthenticationSpec>(req).await;
         }
 
+        if is_kind::<EgressNetwork>(&req) {
+            return self.admit_spec::<EgressNetworkSpec>(req).await;
+        }
+
+        if is_kind::<ExternalWorkload>(&req) {
+            return self.admit_spec::<ExternalWorkloadSpec>(req).await;
+        }
+
         if is_kind::<Server>(&req) {
             return self.admit_spec::<ServerSpec>(req).await;
         };
@@ -123,6 +136,18 @@ impl Admission {
             return self.admit_spec::<HttpRouteSpec>(req).await;
         }
 
+        if is_kind::<HttpLocalRateLimitPolicy>(&req) {
+            return self.admit_spec::<RateLimitPolicySpec>(req).await;
+        }
+
+        if is_kind::<GrpcRoute>(&req) {
+            return self.admit_spec::<GrpcRouteSpec>(req).await;
+        }
+
+        if is_kind::<Service>(&req) {
+            return admit_service(req);
+        }
+
         AdmissionResponse::invalid(format_args!(
             "unsupported resource type: {}.{}.{}",
             req.kind.group, req.kind.version, req.kind.kind
@@ -154,6 +179,22 @@ impl Admission {
     }
 }
 
+/// Services are not linkerd resources, so only the annotations that configure
+/// their outbound policy are validated.
+fn admit_service(req: AdmissionRequest) -> AdmissionResponse {
+    let rsp = AdmissionResponse::from(&req);
+    let Some(obj) = req.object else {
+        return rsp.deny("admission request missing 'object'");
+    };
+
+    if let Err(error) = index::outbound::validate_service_annotations(obj.annotations()) {
+        info!(%error, ns = ?obj.namespace(), name = %obj.name_any(), "Denied Service");
+        return rsp.deny(error);
+    }
+
+    rsp
+}
+
 fn is_kind<T>(req: &AdmissionRequest) -> bool
 where
     T: Resource,
@@ -205,6 +246,10 @@ fn validate_policy_target(ns: &str, tgt: &LocalTargetRef) -> Result<()> {
         return Ok(());
     }
 
+    if tgt.targets_kind::<GrpcRoute>() {
+        return Ok(());
+    }
+
     if tgt.targets_kind::<Namespace>() {
         if tgt.name != ns {
             bail!("cannot target another namespace: {}", tgt.name);
@@ -289,6 +334,29 @@ fn validate_identity_ref(id: &NamespacedTargetRef) -> Result<()> {
     bail!("invalid identity target kind: {}", id.canonical_kind());
 }
 
+#[async_trait::async_trait]
+impl Validate<RateLimitPolicySpec> for Admission {
+    async fn validate(self, ns: &str, _name: &str, spec: RateLimitPolicySpec) -> Result<()> {
+        // Confirm that the index will be able to read this spec.
+        index::ratelimit_policy::validate(&spec)?;
+
+        // The proxy API cannot express rate limits for gRPC servers.
+        let server = kube::Api::<Server>::namespaced(self.client.clone(), ns)
+            .get_opt(&spec.target_ref.name)
+            .await?;
+        if let Some(server) = server {
+            if server.spec.proxy_protocol == Some(ProxyProtocol::Grpc) {
+                bail!(
+                    "rate limits are not supported for gRPC Server {}",
+                    spec.target_ref.name
+                );
+            }
+        }
+
+        Ok(())
+    }
+}
+
 #[async_trait::async_trait]
 impl Validate<MeshTLSAuthenticationSpec> for Admission {
     async fn validate(self, _ns: &str, _name: &str, spec: MeshTLSAuthenticationSpec) -> Result<()> {
@@ -301,33 +369,160 @@ impl Validate<MeshTLSAuthenticationSpec> for Admission {
     }
 }
 
+#[async_trait::async_trait]
+impl Validate<ExternalWorkloadSpec> for Admission {
+    async fn validate(self, _ns: &str, _name: &str, spec: ExternalWorkloadSpec) -> Result<()> {
+        if spec.mesh_tls.identity.is_empty() {
+            bail!("meshTLS.identity must not be empty");
+        }
+        if spec.mesh_tls.server_name.is_empty() {
+            bail!("meshTLS.serverName must not be empty");
+        }
+
+        let ips = spec.workload_ips.unwrap_or_default();
+        if ips.is_empty() {
+            bail!("at least one workload IP must be specified");
+        }
+        for ip in ips.iter() {
+            if let Err(error) = ip.ip.parse::<std::net::IpAddr>() {
+                bail!("invalid workload IP {}: {error}", ip.ip);
+            }
+        }
+
+        let mut names = std::collections::HashSet::new();
+        let mut ports = std::collections::HashSet::new();
+        for port in spec.ports.iter().flatten() {
+            if !ports.insert(port.port) {
+                bail!("port {} is specified more than once", port.port);
+            }
+            if let Some(name) = port.name.as_deref() {
+                if !names.insert(name) {
+                    bail!("port name {name} is specified more than once");
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
 #[async_trait::async_trait]
 impl Validate<ServerSpec> for Admission {
-    /// Checks that `spec` doesn't select the same pod/ports as other existing Servers
-    //
-    // TODO(ver) this isn't rigorous about detecting servers that select the same port if one port
-    // specifies a numeric port and the other specifies the port's name.
+    /// Checks that `spec` doesn't select the same pod/ports as other existing Servers.
+    ///
+    /// Named ports are resolved against the pods that both Servers select, so a Server that
+    /// references a port by name conflicts with a Server that references the same port by number.
     async fn validate(self, ns: &str, name: &str, spec: ServerSpec) -> Result<()> {
+        if let Port::Set(ref ports) = spec.port {
+            match index::ports::parse_portset(ports) {
+                Ok(set) if set.is_empty() => bail!("port set must not be empty"),
+                Ok(_) => {}
+                Err(error) => bail!("invalid port set {ports}: {error}"),
+            }
+        }
+
+        // The proxy API cannot express rate limits for gRPC servers.
+        if spec.proxy_protocol == Some(ProxyProtocol::Grpc) {
+            let ratelimits =
+                kube::Api::<HttpLocalRateLimitPolicy>::namespaced(self.client.clone(), ns)
+                    .list(&kube::api::ListParams::default())
+                    .await?;
+            if let Some(ratelimit) = ratelimits.items.iter().find(|rl| {
+                rl.spec.target_ref.targets_kind::<Server>() && rl.spec.target_ref.name == name
+            }) {
+                bail!(
+                    "rate limits are not supported for gRPC Servers, but \
+                    HTTPLocalRateLimitPolicy {} targets this Server",
+                    ratelimit.name_unchecked()
+                );
+            }
+        }
+
         // Since we can't ensure that the local index is up-to-date with the API server (i.e.
         // updates may be delayed), we issue an API request to get the latest state of servers in
         // the namespace.
-        let servers = kube::Api::<Server>::namespaced(self.client, ns)
+        let servers = kube::Api::<Server>::namespaced(self.client.clone(), ns)
             .list(&kube::api::ListParams::default())
             .await?;
+
+        // The pods in the namespace, which are only listed if selectors can't be compared
+        // directly or if named ports must be resolved.
+        let mut pods = None;
         for server in servers.items.into_iter() {
             let server_name = server.name_unchecked();
-            if server_name != name
-                && server.spec.port == spec.port
-                && Self::overlaps(&server.spec.pod_selector, &spec.pod_selector)
-            {
-                let server_ns = server.namespace();
-                let server_ns = server_ns.as_deref().unwrap_or("default");
+            if server_name == name {
+                continue;
+            }
+
+            let ports_overlap = Self::ports_overlap(&server.spec.port, &spec.port);
+            let named =
+                matches!(server.spec.port, Port::Name(_)) || matches!(spec.port, Port::Name(_));
+            if !ports_overlap && !named {
+                continue;
+            }
+
+            let server_ns = server.namespace();
+            let server_ns = server_ns.as_deref().unwrap_or("default");
+            let selectors_overlap = server.spec.pod_selector.overlaps(&spec.pod_selector);
+            if selectors_overlap == Some(false) {
+                continue;
+            }
+            if ports_overlap && selectors_overlap == Some(true) {
                 bail!(
                     "Server spec '{server_ns}/{server_name}' already defines a policy \
                     for port {}, and selects pods that would be selected by this Server",
                     server.spec.port,
                 );
             }
+
+            // Otherwise, check the pods that both Servers select.
+            if pods.is_none() {
+                pods = Some(
+                    kube::Api::<Pod>::namespaced(self.client.clone(), ns)
+                        .list(&kube::api::ListParams::default())
+                        .await?
+                        .items,
+                );
+            }
+            for pod in pods.iter().flatten() {
+                let labels = labels::Labels::from(pod.metadata.labels.clone());
+                if !server.spec.pod_selector.matches(&labels) || !spec.pod_selector.matches(&labels)
+                {
+                    continue;
+                }
+
+                if ports_overlap {
+                    bail!(
+                        "Server spec '{server_ns}/{server_name}' already defines a policy \
+                        for port {}, and selects pods that would be selected by this Server",
+                        server.spec.port,
+                    );
+                }
+
+                let ports_by_name = pod
+                    .spec
+                    .as_ref()
+                    .map(index::inbound::tcp_ports_by_name)
+                    .unwrap_or_default();
+                let resolve = |port: &Port| {
+                    Self::port_numbers(port).unwrap_or_else(|| match port {
+                        Port::Name(name) => ports_by_name.get(name).cloned().unwrap_or_default(),
+                        _ => Default::default(),
+                    })
+                };
+                if let Some(port) = resolve(&server.spec.port)
+                    .intersection(&resolve(&spec.port))
+                    .next()
+                {
+                    bail!(
+                        "Server spec '{server_ns}/{server_name}' already defines a policy \
+                        for port {} (port {port} on pod {}), and selects pods that would be \
+                        selected by this Server",
+                        server.spec.port,
+                        pod.name_unchecked(),
+                    );
+                }
+            }
         }
 
         Ok(())
@@ -335,18 +530,24 @@ impl Validate<ServerSpec> for Admission {
 }
 
 impl Admission {
-    /// Detects whether two pod selectors can select the same pod
-    //
-    // TODO(ver) We can probably detect overlapping selectors more effectively. For
-    // example, if `left` selects pods with 'foo=bar' and `right` selects pods with
-    // 'foo', we should indicate the selectors overlap. It's a bit tricky to work
-    // through all of the cases though, so we'll just punt for now.
-    fn overlaps(left: &labels::Selector, right: &labels::Selector) -> bool {
-        if left.selects_all() || right.selects_all() {
-            return true;
-        }
-
-        left == right
+    /// Detects whether two server ports can refer to the same port. Port
+    /// numbers and port sets overlap if they share a port number; named ports
+    /// overlap if they have the same name.
+    fn ports_overlap(left: &Port, right: &Port) -> bool {
+        match (Self::port_numbers(left), Self::port_numbers(right)) {
+            (Some(left), Some(right)) => !left.is_disjoint(&right),
+            _ => left == right,
+        }
+    }
+
+    /// Returns the port numbers referenced by a server port, unless it's a
+    /// named port.
+    fn port_numbers(port: &Port) -> Option<index::ports::PortSet> {
+        match port {
+            Port::Number(port) => Some(Some(*port).into_iter().collect()),
+            Port::Set(ports) => Some(index::ports::parse_portset(ports).unwrap_or_default()),
+            Port::Name(_) => None,
+        }
     }
 }
 
@@ -379,6 +580,14 @@ impl Validate<NetworkAuthenticationSpec> for Admission {
     }
 }
 
+#[async_trait::async_trait]
+impl Validate<EgressNetworkSpec> for Admission {
+    async fn validate(self, _ns: &str, _name: &str, spec: EgressNetworkSpec) -> Result<()> {
+        // Confirm that the index will be able to read this spec.
+        index::outbound::egress_network::validate(spec)
+    }
+}
+
 #[async_trait::async_trait]
 impl Validate<ServerAuthorizationSpec> for Admission {
     async fn validate(self, _ns: &str, _name: &str, spec: ServerAuthorizationSpec) -> Result<()> {
@@ -424,7 +633,7 @@ impl Validate<ServerAuthorizationSpec> for Admission {
 
 #[async_trait::async_trait]
 impl Validate<HttpRouteSpec> for Admission {
-    async fn validate(self, _ns: &str, _name: &str, spec: HttpRouteSpec) -> Result<()> {
+    async fn validate(self, ns: &str, _name: &str, spec: HttpRouteSpec) -> Result<()> {
         use index::http_route;
 
         fn validate_match(
@@ -452,29 +661,131 @@ impl Validate<HttpRouteSpec> for Admission {
             Ok(())
         }
 
-        fn validate_filter(filter: httproute::HttpRouteFilter) -> Result<()> {
+        fn validate_filter(filter: httproute::HttpRouteFilter, inbound: bool) -> Result<()> {
             match filter {
                 httproute::HttpRouteFilter::RequestHeaderModifier {
                     request_header_modifier,
-                } => http_route::req_header_modifier(request_header_modifier).map(|_| ()),
+                } => http_route::header_modifier(request_header_modifier).map(|_| ()),
+                httproute::HttpRouteFilter::ResponseHeaderModifier { .. } if inbound => {
+                    bail!("ResponseHeaderModifier filter is not supported for Server parents")
+                }
+                httproute::HttpRouteFilter::ResponseHeaderModifier {
+                    response_header_modifier,
+                } => http_route::header_modifier(response_header_modifier).map(|_| ()),
                 httproute::HttpRouteFilter::RequestRedirect { request_redirect } => {
                     http_route::req_redirect(request_redirect).map(|_| ())
                 }
+                httproute::HttpRouteFilter::RequestFailureInjector {
+                    request_failure_injector,
+                } => http_route::failure_injector(request_failure_injector).map(|_| ()),
+            }
+        }
+
+        // EgressNetworks only apply to clients in their own namespace, so
+        // routes must be colocated with the EgressNetworks they attach to.
+        for parent in spec.inner.parent_refs.iter().flatten() {
+            if !httproute::parent_ref_targets_kind::<EgressNetwork>(parent) {
+                continue;
+            }
+            if parent
+                .namespace
+                .as_deref()
+                .map_or(false, |parent_ns| parent_ns != ns)
+            {
+                bail!(
+                    "EgressNetwork parent {} must be in the same namespace as the route",
+                    parent.name
+                );
+            }
+            if parent.port.is_none() {
+                bail!("EgressNetwork parent {} must specify a port", parent.name);
             }
         }
 
+        // Some filters can only be applied by the outbound proxy.
+        let inbound = spec
+            .inner
+            .parent_refs
+            .iter()
+            .flatten()
+            .any(httproute::parent_ref_targets_kind::<Server>);
+
         // Validate the rules in this spec.
         // This is essentially equivalent to the indexer's conversion function
         // from `HttpRouteSpec` to `InboundRouteBinding`, except that we don't
         // actually allocate stuff in order to return an `InboundRouteBinding`.
         for httproute::HttpRouteRule {
-            filters, matches, ..
+            filters,
+            matches,
+            timeouts,
+            ..
         } in spec.rules.into_iter().flatten()
         {
             for m in matches.into_iter().flatten() {
                 validate_match(m)?;
             }
 
+            for f in filters.into_iter().flatten() {
+                validate_filter(f, inbound)?;
+            }
+
+            if let Some(timeouts) = timeouts {
+                http_route::timeouts(timeouts)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Validate<GrpcRouteSpec> for Admission {
+    async fn validate(self, _ns: &str, _name: &str, spec: GrpcRouteSpec) -> Result<()> {
+        use index::{grpc_route, http_route};
+
+        fn validate_filter(filter: gateway::GrpcRouteFilter) -> Result<()> {
+            match filter {
+                gateway::GrpcRouteFilter::RequestHeaderModifier {
+                    request_header_modifier,
+                } => http_route::header_modifier(request_header_modifier).map(|_| ()),
+                gateway::GrpcRouteFilter::RequestMirror { .. } => {
+                    bail!("RequestMirror filter is not supported")
+                }
+                gateway::GrpcRouteFilter::ExtensionRef { .. } => {
+                    bail!("ExtensionRef filter is not supported")
+                }
+            }
+        }
+
+        // GRPCRoutes may be owned by other Gateway API implementations, so we
+        // only validate routes that attach to a parent that Linkerd manages.
+        let is_linkerd_parent = |parent: &gateway::ParentReference| {
+            httproute::parent_ref_targets_kind::<Server>(parent)
+                || httproute::parent_ref_targets_kind::<Service>(parent)
+        };
+        let mut parents = spec.inner.parent_refs.iter().flatten();
+        // Only HTTPRoutes are indexed for egress networks.
+        if let Some(parent) = parents
+            .clone()
+            .find(|parent| httproute::parent_ref_targets_kind::<EgressNetwork>(parent))
+        {
+            bail!(
+                "GRPCRoutes may not target EgressNetwork {}; use an HTTPRoute instead",
+                parent.name
+            );
+        }
+        if !parents.any(is_linkerd_parent) {
+            return Ok(());
+        }
+
+        for gateway::GrpcRouteRule {
+            matches, filters, ..
+        } in spec.rules.into_iter().flatten()
+        {
+            for m in matches.into_iter().flatten() {
+                grpc_route::try_match(m)?;
+            }
+
             for f in filters.into_iter().flatten() {
                 validate_filter(f)?;
             }
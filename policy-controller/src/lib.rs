@@ -5,16 +5,19 @@ pub mod index_list;
 pub use self::admission::Admission;
 use anyhow::Result;
 use linkerd_policy_controller_core::inbound::{
-    DiscoverInboundServer, InboundServer, InboundServerStream,
+    DiscoverInboundServer, InboundServer, InboundServerStream, WorkloadRef,
 };
 use linkerd_policy_controller_core::outbound::{
-    DiscoverOutboundPolicy, OutboundPolicy, OutboundPolicyStream,
+    DiscoverOutboundPolicy, EgressDst, OutboundDiscoverTarget, OutboundPolicy, OutboundPolicyStream,
 };
 pub use linkerd_policy_controller_core::IpNet;
 pub use linkerd_policy_controller_grpc as grpc;
 pub use linkerd_policy_controller_k8s_api as k8s;
 pub use linkerd_policy_controller_k8s_index::{inbound, outbound, ClusterInfo, DefaultPolicy};
-use std::{net::IpAddr, num::NonZeroU16};
+use std::{
+    net::{IpAddr, SocketAddr},
+    num::NonZeroU16,
+};
 
 #[derive(Clone, Debug)]
 pub struct InboundDiscover(inbound::SharedIndex);
@@ -26,21 +29,61 @@ impl InboundDiscover {
     pub fn new(index: inbound::SharedIndex) -> Self {
         Self(index)
     }
+
+    fn server_rx(
+        &self,
+        namespace: &str,
+        workload: &WorkloadRef,
+        port: NonZeroU16,
+    ) -> Result<tokio::sync::watch::Receiver<InboundServer>> {
+        match workload {
+            WorkloadRef::Pod(pod) => self.0.write().pod_server_rx(namespace, pod, port),
+            WorkloadRef::External(name) => self
+                .0
+                .write()
+                .external_workload_server_rx(namespace, name, port),
+        }
+    }
 }
 
 impl OutboundDiscover {
     pub fn new(index: outbound::SharedIndex) -> Self {
         Self(index)
     }
+
+    fn policy_rx(
+        &self,
+        target: OutboundDiscoverTarget,
+    ) -> Result<tokio::sync::watch::Receiver<OutboundPolicy>> {
+        match target {
+            OutboundDiscoverTarget::Service {
+                namespace,
+                name,
+                port,
+            }
+            | OutboundDiscoverTarget::Endpoint {
+                namespace,
+                name,
+                port,
+                ..
+            } => self.0.write().outbound_policy_rx(namespace, name, port),
+            OutboundDiscoverTarget::Egress {
+                namespace,
+                name,
+                port,
+                ..
+            } => self.0.write().egress_policy_rx(namespace, name, port),
+        }
+    }
 }
 
 #[async_trait::async_trait]
-impl DiscoverInboundServer<(String, String, NonZeroU16)> for InboundDiscover {
+impl DiscoverInboundServer<(String, WorkloadRef, NonZeroU16)> for InboundDiscover {
     async fn get_inbound_server(
         &self,
-        (namespace, pod, port): (String, String, NonZeroU16),
+        (namespace, workload, port): (String, WorkloadRef, NonZeroU16),
     ) -> Result<Option<InboundServer>> {
-        let rx = match self.0.write().pod_server_rx(&namespace, &pod, port) {
+        let rx = match self.server_rx(&namespace, &workload, port) {
             Ok(rx) => rx,
             Err(_) => return Ok(None),
         };
@@ -50,9 +93,9 @@ impl DiscoverInboundServer<(String, String, NonZeroU16)> for InboundDiscover {
 
     async fn watch_inbound_server(
         &self,
-        (namespace, pod, port): (String, String, NonZeroU16),
+        (namespace, workload, port): (String, WorkloadRef, NonZeroU16),
     ) -> Result<Option<InboundServerStream>> {
-        match self.0.write().pod_server_rx(&namespace, &pod, port) {
+        match self.server_rx(&namespace, &workload, port) {
             Ok(rx) => Ok(Some(Box::pin(tokio_stream::wrappers::WatchStream::new(rx)))),
             Err(_) => Ok(None),
         }
@@ -60,12 +103,12 @@ impl DiscoverInboundServer<(String, String, NonZeroU16)> for InboundDiscover {
 }
 
 #[async_trait::async_trait]
-impl DiscoverOutboundPolicy<(String, String, NonZeroU16)> for OutboundDiscover {
+impl DiscoverOutboundPolicy<OutboundDiscoverTarget> for OutboundDiscover {
     async fn get_outbound_policy(
         &self,
-        (namespace, service, port): (String, String, NonZeroU16),
+        target: OutboundDiscoverTarget,
     ) -> Result<Option<OutboundPolicy>> {
-        let rx = match self.0.write().outbound_policy_rx(namespace, service, port) {
+        let rx = match self.policy_rx(target) {
             Ok(rx) => rx,
             Err(error) => {
                 tracing::error!(%error, "failed to get outbound policy rx");
@@ -78,18 +121,89 @@ impl DiscoverOutboundPolicy<(String, String, NonZeroU16)> for OutboundDiscover {
 
     async fn watch_outbound_policy(
         &self,
-        (namespace, service, port): (String, String, NonZeroU16),
+        target: OutboundDiscoverTarget,
     ) -> Result<Option<OutboundPolicyStream>> {
-        match self.0.write().outbound_policy_rx(namespace, service, port) {
+        match self.policy_rx(target) {
             Ok(rx) => Ok(Some(Box::pin(tokio_stream::wrappers::WatchStream::new(rx)))),
             Err(_) => Ok(None),
         }
     }
 
-    fn lookup_ip(&self, addr: IpAddr, port: NonZeroU16) -> Option<(String, String, NonZeroU16)> {
-        self.0
-            .read()
-            .lookup_service(addr)
-            .map(|outbound::ServiceRef { namespace, name }| (namespace, name, port))
+    fn lookup_ip(
+        &self,
+        addr: IpAddr,
+        port: NonZeroU16,
+        source_namespace: Option<&str>,
+    ) -> Option<OutboundDiscoverTarget> {
+        let index = self.0.read();
+        if let Some(outbound::ServiceRef { namespace, name }) = index.lookup_service(addr) {
+            return Some(OutboundDiscoverTarget::Service {
+                namespace,
+                name,
+                port,
+            });
+        }
+
+        if let Some((outbound::ServiceRef { namespace, name }, service_port)) =
+            index.lookup_endpoint(addr, port)
+        {
+            return Some(OutboundDiscoverTarget::Endpoint {
+                namespace,
+                name,
+                port: service_port,
+                addr: SocketAddr::new(addr, port.get()),
+            });
+        }
+
+        // Meshed workloads outside of the cluster are resolved, like pods, to
+        // the Services that select them. They are never egress destinations.
+        if let Some(workload) = index.lookup_external_workload(addr) {
+            let (outbound::ServiceRef { namespace, name }, service_port) =
+                index.lookup_external_workload_endpoint(&workload, port)?;
+            return Some(OutboundDiscoverTarget::Endpoint {
+                namespace,
+                name,
+                port: service_port,
+                addr: SocketAddr::new(addr, port.get()),
+            });
+        }
+
+        // Only fall back to egress networks when the address does not belong
+        // to a Service.
+        let namespace = source_namespace?;
+        let name = index.lookup_egress_ip(addr, namespace)?;
+        Some(OutboundDiscoverTarget::Egress {
+            namespace: namespace.to_string(),
+            name,
+            port,
+            original_dst: EgressDst::Addr(SocketAddr::new(addr, port.get())),
+        })
+    }
+
+    fn lookup_hostname(
+        &self,
+        host: &str,
+        port: NonZeroU16,
+        source_namespace: Option<&str>,
+    ) -> Option<OutboundDiscoverTarget> {
+        let index = self.0.read();
+        if let Some(outbound::ServiceRef { namespace, name }) =
+            index.lookup_external_name(host, source_namespace)
+        {
+            return Some(OutboundDiscoverTarget::Service {
+                namespace,
+                name,
+                port,
+            });
+        }
+
+        let source_namespace = source_namespace?;
+        let name = index.lookup_egress_hostname(host, source_namespace)?;
+        Some(OutboundDiscoverTarget::Egress {
+            namespace: source_namespace.to_string(),
+            name,
+            port,
+            original_dst: EgressDst::Authority(format!("{host}:{port}")),
+        })
     }
 }
@@ -167,12 +167,27 @@ async fn main() -> Result<()> {
 
     // Spawn resource watches.
 
+    let namespaces = runtime.watch_all::<k8s::Namespace>(ListParams::default());
+    tokio::spawn(
+        kubert::index::cluster(inbound_index.clone(), namespaces)
+            .instrument(info_span!("namespaces")),
+    );
+
     let pods =
         runtime.watch_all::<k8s::Pod>(ListParams::default().labels("linkerd.io/control-plane-ns"));
     tokio::spawn(
         kubert::index::namespaced(inbound_index.clone(), pods).instrument(info_span!("pods")),
     );
 
+    let external_workloads = runtime.watch_all::<k8s::ExternalWorkload>(ListParams::default());
+    let external_workloads_indexes = IndexList::new(inbound_index.clone())
+        .push(outbound_index.clone())
+        .shared();
+    tokio::spawn(
+        kubert::index::namespaced(external_workloads_indexes, external_workloads)
+            .instrument(info_span!("externalworkloads")),
+    );
+
     let servers = runtime.watch_all::<k8s::policy::Server>(ListParams::default());
     let servers_indexes = IndexList::new(inbound_index.clone())
         .push(status_index.clone())
@@ -195,6 +210,13 @@ async fn main() -> Result<()> {
             .instrument(info_span!("authorizationpolicies")),
     );
 
+    let ratelimit_policies =
+        runtime.watch_all::<k8s::policy::HttpLocalRateLimitPolicy>(ListParams::default());
+    tokio::spawn(
+        kubert::index::namespaced(inbound_index.clone(), ratelimit_policies)
+            .instrument(info_span!("httplocalratelimitpolicies")),
+    );
+
     let mtls_authns =
         runtime.watch_all::<k8s::policy::MeshTLSAuthentication>(ListParams::default());
     tokio::spawn(
@@ -219,6 +241,34 @@ async fn main() -> Result<()> {
             .instrument(info_span!("httproutes")),
     );
 
+    let grpc_routes = runtime.watch_all::<k8s::gateway::GrpcRoute>(ListParams::default());
+    let grpc_routes_indexes = IndexList::new(inbound_index.clone())
+        .push(outbound_index.clone())
+        .push(status_index.clone())
+        .shared();
+    tokio::spawn(
+        kubert::index::namespaced(grpc_routes_indexes, grpc_routes)
+            .instrument(info_span!("grpcroutes")),
+    );
+
+    let tcp_routes = runtime.watch_all::<k8s::gateway::TcpRoute>(ListParams::default());
+    let tcp_routes_indexes = IndexList::new(outbound_index.clone())
+        .push(status_index.clone())
+        .shared();
+    tokio::spawn(
+        kubert::index::namespaced(tcp_routes_indexes, tcp_routes)
+            .instrument(info_span!("tcproutes")),
+    );
+
+    let tls_routes = runtime.watch_all::<k8s::gateway::TlsRoute>(ListParams::default());
+    let tls_routes_indexes = IndexList::new(outbound_index.clone())
+        .push(status_index.clone())
+        .shared();
+    tokio::spawn(
+        kubert::index::namespaced(tls_routes_indexes, tls_routes)
+            .instrument(info_span!("tlsroutes")),
+    );
+
     let services = runtime.watch_all::<k8s::Service>(ListParams::default());
     let services_indexes = IndexList::new(outbound_index.clone())
         .push(status_index.clone())
@@ -227,6 +277,34 @@ async fn main() -> Result<()> {
         kubert::index::namespaced(services_indexes, services).instrument(info_span!("services")),
     );
 
+    // EndpointSlices resolve traffic addressed directly to a Service's
+    // endpoints, e.g. the pods of a headless Service.
+    let endpoint_slices = runtime.watch_all::<k8s::EndpointSlice>(
+        ListParams::default().labels("kubernetes.io/service-name"),
+    );
+    tokio::spawn(
+        kubert::index::namespaced(outbound_index.clone(), endpoint_slices)
+            .instrument(info_span!("endpointslices")),
+    );
+
+    let egress_networks = runtime.watch_all::<k8s::policy::EgressNetwork>(ListParams::default());
+    let egress_networks_indexes = IndexList::new(outbound_index.clone())
+        .push(status_index.clone())
+        .shared();
+    tokio::spawn(
+        kubert::index::namespaced(egress_networks_indexes, egress_networks)
+            .instrument(info_span!("egressnetworks")),
+    );
+
+    let reference_grants = runtime.watch_all::<k8s::gateway::ReferenceGrant>(ListParams::default());
+    let reference_grants_indexes = IndexList::new(outbound_index.clone())
+        .push(status_index.clone())
+        .shared();
+    tokio::spawn(
+        kubert::index::namespaced(reference_grants_indexes, reference_grants)
+            .instrument(info_span!("referencegrants")),
+    );
+
     // Spawn the status Controller reconciliation.
     tokio::spawn(status::Index::run(status_index.clone()).instrument(info_span!("status::Index")));
 
@@ -0,0 +1,82 @@
+use linkerd_policy_controller_k8s_api::{
+    self as api,
+    policy::egress_network::{EgressNetwork, EgressNetworkSpec, Network, TrafficPolicy},
+};
+use linkerd_policy_test::admission;
+
+#[tokio::test(flavor = "current_thread")]
+async fn accepts_valid() {
+    admission::accepts(|ns| EgressNetwork {
+        metadata: api::ObjectMeta {
+            namespace: Some(ns),
+            name: Some("test".to_string()),
+            ..Default::default()
+        },
+        spec: EgressNetworkSpec {
+            hostnames: Some(vec![
+                "api.example.com".to_string(),
+                "*.googleapis.com".to_string(),
+            ]),
+            networks: Some(vec![Network {
+                cidr: "203.0.113.0/24".parse().unwrap(),
+                except: Some(vec!["203.0.113.1".parse().unwrap()]),
+            }]),
+            traffic_policy: TrafficPolicy::Deny,
+        },
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn rejects_empty() {
+    admission::rejects(|ns| EgressNetwork {
+        metadata: api::ObjectMeta {
+            namespace: Some(ns),
+            name: Some("test".to_string()),
+            ..Default::default()
+        },
+        spec: EgressNetworkSpec {
+            hostnames: None,
+            networks: None,
+            traffic_policy: TrafficPolicy::Allow,
+        },
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn rejects_invalid_wildcard() {
+    admission::rejects(|ns| EgressNetwork {
+        metadata: api::ObjectMeta {
+            namespace: Some(ns),
+            name: Some("test".to_string()),
+            ..Default::default()
+        },
+        spec: EgressNetworkSpec {
+            hostnames: Some(vec!["api.*.example.com".to_string()]),
+            networks: None,
+            traffic_policy: TrafficPolicy::Allow,
+        },
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn rejects_except_not_in_cidr() {
+    admission::rejects(|ns| EgressNetwork {
+        metadata: api::ObjectMeta {
+            namespace: Some(ns),
+            name: Some("test".to_string()),
+            ..Default::default()
+        },
+        spec: EgressNetworkSpec {
+            hostnames: None,
+            networks: Some(vec![Network {
+                cidr: "203.0.113.0/24".parse().unwrap(),
+                except: Some(vec!["198.51.100.0/24".parse().unwrap()]),
+            }]),
+            traffic_policy: TrafficPolicy::Allow,
+        },
+    })
+    .await;
+}
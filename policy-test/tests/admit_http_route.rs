@@ -39,6 +39,7 @@ async fn rejects_relative_path_match() {
                 }]),
                 filters: None,
                 backend_refs: None,
+                timeouts: None,
             }]),
         },
         status: None,
@@ -74,6 +75,7 @@ async fn rejects_relative_redirect_path() {
                     },
                 }]),
                 backend_refs: None,
+                timeouts: None,
             }]),
         },
         status: None,
@@ -81,6 +83,191 @@ async fn rejects_relative_redirect_path() {
     .await;
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn accepts_timeouts() {
+    admission::accepts(|ns| HttpRoute {
+        metadata: meta(&ns),
+        spec: HttpRouteSpec {
+            inner: CommonRouteSpec {
+                parent_refs: Some(vec![server_parent_ref(ns)]),
+            },
+            hostnames: None,
+            rules: Some(rules_with_timeouts("10s", "1s")),
+        },
+        status: None,
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn rejects_backend_request_timeout_exceeding_request_timeout() {
+    admission::rejects(|ns| HttpRoute {
+        metadata: meta(&ns),
+        spec: HttpRouteSpec {
+            inner: CommonRouteSpec {
+                parent_refs: Some(vec![server_parent_ref(ns)]),
+            },
+            hostnames: None,
+            rules: Some(rules_with_timeouts("1s", "10s")),
+        },
+        status: None,
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn accepts_response_header_modifier() {
+    admission::accepts(|ns| HttpRoute {
+        metadata: meta(&ns),
+        spec: HttpRouteSpec {
+            inner: CommonRouteSpec {
+                parent_refs: Some(vec![service_parent_ref(ns)]),
+            },
+            hostnames: None,
+            rules: Some(rules_with_response_header_modifier(
+                "strict-transport-security",
+                "max-age=31536000",
+            )),
+        },
+        status: None,
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn rejects_invalid_response_header_name() {
+    admission::rejects(|ns| HttpRoute {
+        metadata: meta(&ns),
+        spec: HttpRouteSpec {
+            inner: CommonRouteSpec {
+                parent_refs: Some(vec![service_parent_ref(ns)]),
+            },
+            hostnames: None,
+            rules: Some(rules_with_response_header_modifier(
+                "not a header",
+                "max-age=31536000",
+            )),
+        },
+        status: None,
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn rejects_response_header_modifier_on_server() {
+    admission::rejects(|ns| HttpRoute {
+        metadata: meta(&ns),
+        spec: HttpRouteSpec {
+            inner: CommonRouteSpec {
+                parent_refs: Some(vec![server_parent_ref(ns)]),
+            },
+            hostnames: None,
+            rules: Some(rules_with_response_header_modifier(
+                "strict-transport-security",
+                "max-age=31536000",
+            )),
+        },
+        status: None,
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn accepts_failure_injector() {
+    admission::accepts(|ns| HttpRoute {
+        metadata: meta(&ns),
+        spec: HttpRouteSpec {
+            inner: CommonRouteSpec {
+                parent_refs: Some(vec![server_parent_ref(ns)]),
+            },
+            hostnames: None,
+            rules: Some(rules_with_failure_injector(10, Some(100))),
+        },
+        status: None,
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn rejects_failure_injector_ratio_over_one() {
+    admission::rejects(|ns| HttpRoute {
+        metadata: meta(&ns),
+        spec: HttpRouteSpec {
+            inner: CommonRouteSpec {
+                parent_refs: Some(vec![server_parent_ref(ns)]),
+            },
+            hostnames: None,
+            rules: Some(rules_with_failure_injector(2, Some(1))),
+        },
+        status: None,
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn rejects_failure_injector_zero_denominator() {
+    admission::rejects(|ns| HttpRoute {
+        metadata: meta(&ns),
+        spec: HttpRouteSpec {
+            inner: CommonRouteSpec {
+                parent_refs: Some(vec![server_parent_ref(ns)]),
+            },
+            hostnames: None,
+            rules: Some(rules_with_failure_injector(0, Some(0))),
+        },
+        status: None,
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn accepts_egress_network_parent() {
+    admission::accepts(|ns| HttpRoute {
+        metadata: meta(ns),
+        spec: HttpRouteSpec {
+            inner: CommonRouteSpec {
+                parent_refs: Some(vec![egress_parent_ref(None, Some(443))]),
+            },
+            hostnames: None,
+            rules: Some(rules()),
+        },
+        status: None,
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn rejects_egress_network_parent_without_port() {
+    admission::rejects(|ns| HttpRoute {
+        metadata: meta(ns),
+        spec: HttpRouteSpec {
+            inner: CommonRouteSpec {
+                parent_refs: Some(vec![egress_parent_ref(None, None)]),
+            },
+            hostnames: None,
+            rules: Some(rules()),
+        },
+        status: None,
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn rejects_egress_network_parent_in_other_namespace() {
+    admission::rejects(|ns| HttpRoute {
+        metadata: meta(ns),
+        spec: HttpRouteSpec {
+            inner: CommonRouteSpec {
+                parent_refs: Some(vec![egress_parent_ref(Some("other-ns"), Some(443))]),
+            },
+            hostnames: None,
+            rules: Some(rules()),
+        },
+        status: None,
+    })
+    .await;
+}
+
 fn server_parent_ref(ns: impl ToString) -> ParentReference {
     ParentReference {
         group: Some("policy.linkerd.io".to_string()),
@@ -92,6 +279,28 @@ fn server_parent_ref(ns: impl ToString) -> ParentReference {
     }
 }
 
+fn service_parent_ref(ns: impl ToString) -> ParentReference {
+    ParentReference {
+        group: Some("core".to_string()),
+        kind: Some("Service".to_string()),
+        namespace: Some(ns.to_string()),
+        name: "my-svc".to_string(),
+        section_name: None,
+        port: Some(8080),
+    }
+}
+
+fn egress_parent_ref(ns: Option<&str>, port: Option<u16>) -> ParentReference {
+    ParentReference {
+        group: Some("policy.linkerd.io".to_string()),
+        kind: Some("EgressNetwork".to_string()),
+        namespace: ns.map(ToString::to_string),
+        name: "my-egress".to_string(),
+        section_name: None,
+        port,
+    }
+}
+
 fn meta(ns: impl ToString) -> api::ObjectMeta {
     api::ObjectMeta {
         namespace: Some(ns.to_string()),
@@ -110,5 +319,57 @@ fn rules() -> Vec<HttpRouteRule> {
         }]),
         filters: None,
         backend_refs: None,
+        timeouts: None,
     }]
 }
+
+fn rules_with_timeouts(request: &str, backend_request: &str) -> Vec<HttpRouteRule> {
+    rules()
+        .into_iter()
+        .map(|rule| HttpRouteRule {
+            timeouts: Some(HttpRouteTimeouts {
+                request: Some(request.parse().unwrap()),
+                backend_request: Some(backend_request.parse().unwrap()),
+            }),
+            ..rule
+        })
+        .collect()
+}
+
+fn rules_with_response_header_modifier(name: &str, value: &str) -> Vec<HttpRouteRule> {
+    rules()
+        .into_iter()
+        .map(|rule| HttpRouteRule {
+            filters: Some(vec![HttpRouteFilter::ResponseHeaderModifier {
+                response_header_modifier: HttpRequestHeaderFilter {
+                    set: Some(vec![HttpHeader {
+                        name: name.to_string(),
+                        value: value.to_string(),
+                    }]),
+                    add: None,
+                    remove: Some(vec!["x-internal-trace".to_string()]),
+                },
+            }]),
+            ..rule
+        })
+        .collect()
+}
+
+fn rules_with_failure_injector(numerator: u32, denominator: Option<u32>) -> Vec<HttpRouteRule> {
+    rules()
+        .into_iter()
+        .map(|rule| HttpRouteRule {
+            filters: Some(vec![HttpRouteFilter::RequestFailureInjector {
+                request_failure_injector: HttpRequestFailureInjectorFilter {
+                    status: Some(503),
+                    message: Some("injected failure".to_string()),
+                    ratio: Some(Ratio {
+                        numerator,
+                        denominator,
+                    }),
+                },
+            }]),
+            ..rule
+        })
+        .collect()
+}
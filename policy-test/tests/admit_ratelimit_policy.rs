@@ -0,0 +1,178 @@
+use linkerd_policy_controller_k8s_api::{
+    self as api,
+    policy::{
+        ratelimit_policy::{Limit, Override},
+        server::{Port, ProxyProtocol, Server, ServerSpec},
+        HttpLocalRateLimitPolicy, LocalTargetRef, NamespacedTargetRef, RateLimitPolicySpec,
+    },
+};
+use linkerd_policy_test::{admission, create, with_temp_ns};
+
+#[tokio::test(flavor = "current_thread")]
+async fn accepts_valid() {
+    admission::accepts(|ns| HttpLocalRateLimitPolicy {
+        metadata: api::ObjectMeta {
+            namespace: Some(ns),
+            name: Some("test".to_string()),
+            ..Default::default()
+        },
+        spec: RateLimitPolicySpec {
+            target_ref: server_target("api"),
+            total: Some(Limit {
+                requests_per_second: 100,
+            }),
+            identity: Some(Limit {
+                requests_per_second: 10,
+            }),
+            overrides: Some(vec![Override {
+                requests_per_second: 50,
+                client_refs: vec![NamespacedTargetRef {
+                    group: None,
+                    kind: "ServiceAccount".to_string(),
+                    name: "client".to_string(),
+                    namespace: None,
+                }],
+            }]),
+        },
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn rejects_identity_above_total() {
+    admission::rejects(|ns| HttpLocalRateLimitPolicy {
+        metadata: api::ObjectMeta {
+            namespace: Some(ns),
+            name: Some("test".to_string()),
+            ..Default::default()
+        },
+        spec: RateLimitPolicySpec {
+            target_ref: server_target("api"),
+            total: Some(Limit {
+                requests_per_second: 10,
+            }),
+            identity: Some(Limit {
+                requests_per_second: 100,
+            }),
+            overrides: None,
+        },
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn rejects_empty() {
+    admission::rejects(|ns| HttpLocalRateLimitPolicy {
+        metadata: api::ObjectMeta {
+            namespace: Some(ns),
+            name: Some("test".to_string()),
+            ..Default::default()
+        },
+        spec: RateLimitPolicySpec {
+            target_ref: server_target("api"),
+            total: None,
+            identity: None,
+            overrides: None,
+        },
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn rejects_namespace_target() {
+    admission::rejects(|ns| HttpLocalRateLimitPolicy {
+        metadata: api::ObjectMeta {
+            namespace: Some(ns.clone()),
+            name: Some("test".to_string()),
+            ..Default::default()
+        },
+        spec: RateLimitPolicySpec {
+            target_ref: LocalTargetRef {
+                group: None,
+                kind: "Namespace".to_string(),
+                name: ns,
+            },
+            total: Some(Limit {
+                requests_per_second: 100,
+            }),
+            identity: None,
+            overrides: None,
+        },
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn rejects_http_route_target() {
+    admission::rejects(|ns| HttpLocalRateLimitPolicy {
+        metadata: api::ObjectMeta {
+            namespace: Some(ns),
+            name: Some("test".to_string()),
+            ..Default::default()
+        },
+        spec: RateLimitPolicySpec {
+            target_ref: LocalTargetRef {
+                group: Some("policy.linkerd.io".to_string()),
+                kind: "HTTPRoute".to_string(),
+                name: "route".to_string(),
+            },
+            total: Some(Limit {
+                requests_per_second: 100,
+            }),
+            identity: None,
+            overrides: None,
+        },
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn rejects_grpc_server_target() {
+    with_temp_ns(|client, ns| async move {
+        create(
+            &client,
+            Server {
+                metadata: api::ObjectMeta {
+                    namespace: Some(ns.clone()),
+                    name: Some("api".to_string()),
+                    ..Default::default()
+                },
+                spec: ServerSpec {
+                    pod_selector: api::labels::Selector::default(),
+                    port: Port::Number(8080.try_into().unwrap()),
+                    proxy_protocol: Some(ProxyProtocol::Grpc),
+                },
+            },
+        )
+        .await;
+
+        let api = kube::Api::namespaced(client, &ns);
+        let ratelimit = HttpLocalRateLimitPolicy {
+            metadata: api::ObjectMeta {
+                namespace: Some(ns),
+                name: Some("test".to_string()),
+                ..Default::default()
+            },
+            spec: RateLimitPolicySpec {
+                target_ref: server_target("api"),
+                total: Some(Limit {
+                    requests_per_second: 100,
+                }),
+                identity: None,
+                overrides: None,
+            },
+        };
+        api.create(&kube::api::PostParams::default(), &ratelimit)
+            .await
+            .expect_err("resource must not apply");
+    })
+    .await;
+}
+
+fn server_target(name: &str) -> LocalTargetRef {
+    LocalTargetRef {
+        group: Some("policy.linkerd.io".to_string()),
+        kind: "Server".to_string(),
+        name: name.to_string(),
+    }
+}
@@ -1,8 +1,12 @@
 use linkerd_policy_controller_k8s_api::{
     self as api, labels,
-    policy::server::{Port, ProxyProtocol, Server, ServerSpec},
+    policy::{
+        ratelimit_policy::Limit,
+        server::{Port, ProxyProtocol, Server, ServerSpec},
+        HttpLocalRateLimitPolicy, LocalTargetRef, RateLimitPolicySpec,
+    },
 };
-use linkerd_policy_test::{admission, with_temp_ns};
+use linkerd_policy_test::{admission, create, web, with_temp_ns};
 
 #[tokio::test(flavor = "current_thread")]
 async fn accepts_valid() {
@@ -178,3 +182,269 @@ async fn rejects_invalid_proxy_protocol() {
     })
     .await;
 }
+
+#[tokio::test(flavor = "current_thread")]
+async fn rejects_rate_limited_grpc_server() {
+    with_temp_ns(|client, ns| async move {
+        create(
+            &client,
+            HttpLocalRateLimitPolicy {
+                metadata: api::ObjectMeta {
+                    namespace: Some(ns.clone()),
+                    name: Some("rl".to_string()),
+                    ..Default::default()
+                },
+                spec: RateLimitPolicySpec {
+                    target_ref: LocalTargetRef {
+                        group: Some("policy.linkerd.io".to_string()),
+                        kind: "Server".to_string(),
+                        name: "test".to_string(),
+                    },
+                    total: Some(Limit {
+                        requests_per_second: 100,
+                    }),
+                    identity: None,
+                    overrides: None,
+                },
+            },
+        )
+        .await;
+
+        let api = kube::Api::namespaced(client, &ns);
+        let server = Server {
+            metadata: api::ObjectMeta {
+                namespace: Some(ns),
+                name: Some("test".to_string()),
+                ..Default::default()
+            },
+            spec: ServerSpec {
+                pod_selector: api::labels::Selector::default(),
+                port: Port::Number(8080.try_into().unwrap()),
+                proxy_protocol: Some(ProxyProtocol::Grpc),
+            },
+        };
+        api.create(&kube::api::PostParams::default(), &server)
+            .await
+            .expect_err("resource must not apply");
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn accepts_port_set() {
+    admission::accepts(|ns| Server {
+        metadata: api::ObjectMeta {
+            namespace: Some(ns),
+            name: Some("test".to_string()),
+            ..Default::default()
+        },
+        spec: ServerSpec {
+            pod_selector: api::labels::Selector::default(),
+            port: Port::Set("8080,9000-9100".to_string()),
+            proxy_protocol: None,
+        },
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn rejects_invalid_port_set() {
+    admission::rejects(|ns| Server {
+        metadata: api::ObjectMeta {
+            namespace: Some(ns),
+            name: Some("test".to_string()),
+            ..Default::default()
+        },
+        spec: ServerSpec {
+            pod_selector: api::labels::Selector::default(),
+            port: Port::Set("9100-9000".to_string()),
+            proxy_protocol: None,
+        },
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn rejects_empty_port_set() {
+    admission::rejects(|ns| Server {
+        metadata: api::ObjectMeta {
+            namespace: Some(ns),
+            name: Some("test".to_string()),
+            ..Default::default()
+        },
+        spec: ServerSpec {
+            pod_selector: api::labels::Selector::default(),
+            port: Port::Set(String::new()),
+            proxy_protocol: None,
+        },
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn rejects_overlapping_port_sets() {
+    with_temp_ns(|client, ns| async move {
+        let api = kube::Api::namespaced(client, &ns);
+
+        let test0 = Server {
+            metadata: api::ObjectMeta {
+                namespace: Some(ns.clone()),
+                name: Some("test0".to_string()),
+                ..Default::default()
+            },
+            spec: ServerSpec {
+                pod_selector: api::labels::Selector::from_iter(Some(("app", "test"))),
+                port: Port::Set("9000-9100".to_string()),
+                proxy_protocol: None,
+            },
+        };
+        api.create(&kube::api::PostParams::default(), &test0)
+            .await
+            .expect("resource must apply");
+
+        let test1 = Server {
+            metadata: api::ObjectMeta {
+                namespace: Some(ns),
+                name: Some("test1".to_string()),
+                ..Default::default()
+            },
+            spec: ServerSpec {
+                pod_selector: api::labels::Selector::from_iter(Some(("app", "test"))),
+                port: Port::Number(9050.try_into().unwrap()),
+                proxy_protocol: None,
+            },
+        };
+        api.create(&kube::api::PostParams::default(), &test1)
+            .await
+            .expect_err("resource must not apply");
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn rejects_overlapping_pod_selectors() {
+    with_temp_ns(|client, ns| async move {
+        let api = kube::Api::namespaced(client, &ns);
+
+        let test0 = Server {
+            metadata: api::ObjectMeta {
+                namespace: Some(ns.clone()),
+                name: Some("test0".to_string()),
+                ..Default::default()
+            },
+            spec: ServerSpec {
+                pod_selector: api::labels::Selector::from_iter(Some(("app", "test"))),
+                port: Port::Number(80.try_into().unwrap()),
+                proxy_protocol: None,
+            },
+        };
+        api.create(&kube::api::PostParams::default(), &test0)
+            .await
+            .expect("resource must apply");
+
+        let test1 = Server {
+            metadata: api::ObjectMeta {
+                namespace: Some(ns),
+                name: Some("test1".to_string()),
+                ..Default::default()
+            },
+            spec: ServerSpec {
+                pod_selector: serde_json::from_value(serde_json::json!({
+                    "matchExpressions": [
+                        { "key": "app", "operator": "In", "values": ["test", "other"] },
+                    ],
+                }))
+                .unwrap(),
+                port: Port::Number(80.try_into().unwrap()),
+                proxy_protocol: None,
+            },
+        };
+        let error = api
+            .create(&kube::api::PostParams::default(), &test1)
+            .await
+            .expect_err("resource must not apply");
+        assert!(
+            error.to_string().contains("test0"),
+            "error must name the conflicting Server: {error}"
+        );
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn accepts_disjoint_pod_selectors() {
+    with_temp_ns(|client, ns| async move {
+        let api = kube::Api::namespaced(client, &ns);
+
+        let test0 = Server {
+            metadata: api::ObjectMeta {
+                namespace: Some(ns.clone()),
+                name: Some("test0".to_string()),
+                ..Default::default()
+            },
+            spec: ServerSpec {
+                pod_selector: api::labels::Selector::from_iter(Some(("app", "test"))),
+                port: Port::Number(80.try_into().unwrap()),
+                proxy_protocol: None,
+            },
+        };
+        api.create(&kube::api::PostParams::default(), &test0)
+            .await
+            .expect("resource must apply");
+
+        let test1 = Server {
+            metadata: api::ObjectMeta {
+                namespace: Some(ns),
+                name: Some("test1".to_string()),
+                ..Default::default()
+            },
+            spec: ServerSpec {
+                pod_selector: serde_json::from_value(serde_json::json!({
+                    "matchExpressions": [
+                        { "key": "app", "operator": "NotIn", "values": ["test"] },
+                    ],
+                }))
+                .unwrap(),
+                port: Port::Number(80.try_into().unwrap()),
+                proxy_protocol: None,
+            },
+        };
+        api.create(&kube::api::PostParams::default(), &test1)
+            .await
+            .expect("resource must apply");
+    })
+    .await;
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn rejects_numeric_port_that_conflicts_with_named_port() {
+    with_temp_ns(|client, ns| async move {
+        // The web pod names its container port 8080 `http`, which the web
+        // Server selects by name.
+        create(&client, web::pod(&ns)).await;
+        create(&client, web::server(&ns)).await;
+
+        let api = kube::Api::namespaced(client, &ns);
+        let test = Server {
+            metadata: api::ObjectMeta {
+                namespace: Some(ns),
+                name: Some("test".to_string()),
+                ..Default::default()
+            },
+            spec: ServerSpec {
+                pod_selector: api::labels::Selector::from_iter(Some(("app", "web"))),
+                port: Port::Number(8080.try_into().unwrap()),
+                proxy_protocol: None,
+            },
+        };
+        let error = api
+            .create(&kube::api::PostParams::default(), &test)
+            .await
+            .expect_err("resource must not apply");
+        assert!(
+            error.to_string().contains("web"),
+            "error must name the conflicting Server: {error}"
+        );
+    })
+    .await;
+}
@@ -705,6 +705,7 @@ fn mk_http_route(
                 }]),
                 filters: None,
                 backend_refs,
+                timeouts: None,
             }]),
         },
         status: None,
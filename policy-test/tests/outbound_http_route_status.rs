@@ -143,9 +143,9 @@ async fn external_name() {
             "test-service",
         )
         .expect("must have at least one 'Accepted' condition set for parent");
-        // Parent with ExternalName should not match.
-        assert_eq!(cond.status, "False");
-        assert_eq!(cond.reason, "NoMatchingParent");
+        // Parent with ExternalName should be accepted.
+        assert_eq!(cond.status, "True");
+        assert_eq!(cond.reason, "Accepted");
     })
     .await;
 }